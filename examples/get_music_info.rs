@@ -1,12 +1,23 @@
-use bili_player::fetch::{network::fetch_video_data, verify::fetch_and_verify_audio_url};
+use bili_player::fetch::{
+    network::{BILIBILI_API_BASE, fetch_video_data},
+    quality::AudioPreference,
+    verify::fetch_and_verify_audio_url,
+};
 
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     let client = reqwest::Client::new();
-    let video_data = fetch_video_data(&client, "BV1r7411p7R4").await?;
+    let video_data = fetch_video_data(&client, BILIBILI_API_BASE, "BV1r7411p7R4").await?;
     println!("Title: {:?}", video_data);
-    let audio_url =
-        fetch_and_verify_audio_url(&client, &video_data.bvid, &video_data.cid.to_string()).await?;
-    println!("Audio URL: {}", audio_url);
+    let audio_stream = fetch_and_verify_audio_url(
+        &client,
+        BILIBILI_API_BASE,
+        &video_data.bvid,
+        &video_data.cid.to_string(),
+        AudioPreference::Highest,
+    )
+    .await?;
+    println!("Audio quality: {}", audio_stream.quality);
+    println!("Audio URL: {}", audio_stream.base_url);
     Ok(())
 }
@@ -0,0 +1,2 @@
+-- Add down migration script here
+DELETE FROM musics WHERE bvid IN ('BV1oZqqBZEGZ', 'BV1Xa411R7uJ', 'BV1w34y1K7dv', 'BV1r7411p7R4');
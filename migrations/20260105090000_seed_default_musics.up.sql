@@ -0,0 +1,7 @@
+-- Add up migration script here
+-- 初始化默认的播放列表，避免首次启动时音乐库为空
+INSERT OR IGNORE INTO musics (bvid, song_name, cid, author) VALUES
+    ('BV1oZqqBZEGZ', '西楼别序', '34856567673', '夕照影音'),
+    ('BV1Xa411R7uJ', '长大成人', '806047205', '忆江南音乐'),
+    ('BV1w34y1K7dv', '一花一世界', '1287078024', '荃球音悦台'),
+    ('BV1r7411p7R4', '青花瓷', '321818216', 'zyl2012_音乐无限');
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP TABLE IF EXISTS playlist_items;
+DROP TABLE IF EXISTS playlists;
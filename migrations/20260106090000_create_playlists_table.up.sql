@@ -0,0 +1,58 @@
+-- Add up migration script here
+-- 歌单表
+CREATE TABLE playlists (
+    -- 主键，自增ID
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- 歌单名称，唯一
+    name TEXT NOT NULL UNIQUE,
+
+    -- 是否为当前播放的歌单，同一时间只有一个
+    is_active BOOLEAN NOT NULL DEFAULT 0,
+
+    -- 记录创建时间，自动填充
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+-- 歌单中的歌曲
+CREATE TABLE playlist_items (
+    -- 主键，自增ID
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- 所属歌单
+    playlist_id INTEGER NOT NULL REFERENCES playlists(id) ON DELETE CASCADE,
+
+    -- 对应 musics 表中的歌曲
+    music_id INTEGER NOT NULL REFERENCES musics(id) ON DELETE CASCADE,
+
+    -- 在歌单中的顺序，从小到大播放
+    position INTEGER NOT NULL,
+
+    -- 记录创建时间，自动填充
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 同一首歌在一个歌单中只出现一次
+    UNIQUE (playlist_id, music_id)
+);
+
+-- 按顺序读取歌单中的歌曲
+CREATE INDEX idx_playlist_items_position ON playlist_items(playlist_id, position);
+
+-- 查询歌曲所在的歌单
+CREATE INDEX idx_playlist_items_music ON playlist_items(music_id);
+
+CREATE TRIGGER update_playlists_timestamp
+AFTER UPDATE ON playlists
+BEGIN
+    UPDATE playlists
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
+
+-- 创建默认歌单，包含音乐库中已有的歌曲
+INSERT INTO playlists (name, is_active) VALUES ('默认', 1);
+INSERT INTO playlist_items (playlist_id, music_id, position)
+SELECT (SELECT id FROM playlists WHERE name = '默认'), id, id FROM musics ORDER BY id;
@@ -0,0 +1,28 @@
+-- Add up migration script here
+-- 播放会话表，只保存一行，用于重启后恢复播放
+-- 当前播放的歌单由 playlists.is_active 记录
+CREATE TABLE player_session (
+    -- 固定为 1，保证只有一行
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+
+    -- 当前播放的歌曲在歌单中的索引
+    current_index INTEGER NOT NULL DEFAULT 0,
+
+    -- 当前播放的歌曲，歌单变化后用于重新定位索引
+    current_bvid TEXT,
+
+    -- 在当前歌曲中的播放位置 (秒)
+    position REAL NOT NULL DEFAULT 0,
+
+    -- 播放模式：normal、shuffle、repeat
+    play_mode TEXT NOT NULL DEFAULT 'normal',
+
+    -- 音量百分比 (0-200)
+    volume INTEGER NOT NULL DEFAULT 100,
+
+    -- 是否静音
+    muted BOOLEAN NOT NULL DEFAULT 0,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
@@ -0,0 +1,41 @@
+-- Add down migration script here
+-- 恢复以 bvid 作为唯一标识，同一视频只保留第一P
+ALTER TABLE player_session DROP COLUMN current_cid;
+
+CREATE TABLE playlist_items_backup AS SELECT * FROM playlist_items;
+
+CREATE TABLE musics_old (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    bvid VARCHAR(255) NOT NULL UNIQUE,
+    song_name TEXT NOT NULL,
+    cid VARCHAR(255) NOT NULL,
+    author TEXT NOT NULL,
+    is_liked BOOLEAN NOT NULL DEFAULT 0,
+    is_deleted BOOLEAN NOT NULL DEFAULT 0,
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+INSERT OR IGNORE INTO musics_old (id, bvid, song_name, cid, author, is_liked, is_deleted, created_at, updated_at)
+SELECT id, bvid, song_name, cid, author, is_liked, is_deleted, created_at, updated_at FROM musics ORDER BY bvid, page;
+
+DROP TABLE musics;
+ALTER TABLE musics_old RENAME TO musics;
+
+INSERT INTO playlist_items
+SELECT * FROM playlist_items_backup WHERE music_id IN (SELECT id FROM musics);
+DROP TABLE playlist_items_backup;
+
+CREATE INDEX idx_musics_liked_deleted ON musics(is_liked, is_deleted, created_at DESC);
+CREATE INDEX idx_musics_search ON musics(song_name, author, is_deleted);
+CREATE INDEX idx_musics_created_at ON musics(created_at DESC);
+CREATE INDEX idx_musics_author ON musics(author, is_deleted);
+CREATE INDEX idx_musics_deleted ON musics(is_deleted, created_at DESC);
+
+CREATE TRIGGER update_musics_timestamp
+AFTER UPDATE ON musics
+BEGIN
+    UPDATE musics
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
@@ -0,0 +1,68 @@
+-- Add up migration script here
+-- 多P视频的每一P都是独立的歌曲，音乐库改为以 (bvid, cid) 作为唯一标识
+-- SQLite 不能删除列上的 UNIQUE 约束，需要重建 musics 表
+
+-- 删除 musics 时会级联删除歌单条目，先备份
+CREATE TABLE playlist_items_backup AS SELECT * FROM playlist_items;
+
+CREATE TABLE musics_new (
+    -- 主键，自增ID
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- B站视频ID
+    bvid VARCHAR(255) NOT NULL,
+
+    -- 歌曲名称，支持全文搜索
+    song_name TEXT NOT NULL,
+
+    -- 视频CID，用于音频流获取，多P视频每一P的 cid 不同
+    cid VARCHAR(255) NOT NULL,
+
+    -- 作者/UP主名称
+    author TEXT NOT NULL,
+
+    -- 是否喜欢标记，1=喜欢，0=未标记
+    is_liked BOOLEAN NOT NULL DEFAULT 0,
+
+    -- 软删除标记，1=已删除，0=未删除
+    is_deleted BOOLEAN NOT NULL DEFAULT 0,
+
+    -- 记录创建时间，自动填充
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 分P序号，从 1 开始
+    page INTEGER NOT NULL DEFAULT 1,
+
+    -- 同一个视频的同一P只保存一次
+    UNIQUE (bvid, cid)
+);
+
+INSERT INTO musics_new (id, bvid, song_name, cid, author, is_liked, is_deleted, created_at, updated_at)
+SELECT id, bvid, song_name, cid, author, is_liked, is_deleted, created_at, updated_at FROM musics;
+
+DROP TABLE musics;
+ALTER TABLE musics_new RENAME TO musics;
+
+INSERT INTO playlist_items SELECT * FROM playlist_items_backup;
+DROP TABLE playlist_items_backup;
+
+-- 重建索引和触发器
+CREATE INDEX idx_musics_liked_deleted ON musics(is_liked, is_deleted, created_at DESC);
+CREATE INDEX idx_musics_search ON musics(song_name, author, is_deleted);
+CREATE INDEX idx_musics_created_at ON musics(created_at DESC);
+CREATE INDEX idx_musics_author ON musics(author, is_deleted);
+CREATE INDEX idx_musics_deleted ON musics(is_deleted, created_at DESC);
+
+CREATE TRIGGER update_musics_timestamp
+AFTER UPDATE ON musics
+BEGIN
+    UPDATE musics
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
+
+-- 播放会话同时记录 cid，用于定位多P视频中的某一P
+ALTER TABLE player_session ADD COLUMN current_cid TEXT;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+DROP INDEX IF EXISTS idx_playlists_favorite_media_id;
+ALTER TABLE playlists DROP COLUMN favorite_media_id;
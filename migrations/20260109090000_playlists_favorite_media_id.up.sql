@@ -0,0 +1,6 @@
+-- Add up migration script here
+-- 从 B 站收藏夹导入的歌单记录收藏夹 id，重复导入时用于找到同一个歌单
+ALTER TABLE playlists ADD COLUMN favorite_media_id INTEGER;
+
+CREATE UNIQUE INDEX IF NOT EXISTS idx_playlists_favorite_media_id
+    ON playlists(favorite_media_id) WHERE favorite_media_id IS NOT NULL;
@@ -0,0 +1,5 @@
+-- Add down migration script here
+DROP TRIGGER IF EXISTS musics_fts_after_update;
+DROP TRIGGER IF EXISTS musics_fts_after_delete;
+DROP TRIGGER IF EXISTS musics_fts_after_insert;
+DROP TABLE IF EXISTS musics_fts;
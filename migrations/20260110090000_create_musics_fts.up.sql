@@ -0,0 +1,37 @@
+-- Add up migration script here
+-- 歌曲名称和作者的全文索引，trigram 分词支持中文等没有空格分隔的标题按子串搜索
+-- 外部内容表，数据仍保存在 musics 中，由下面的触发器保持同步
+CREATE VIRTUAL TABLE musics_fts USING fts5(
+    song_name,
+    author,
+    content = 'musics',
+    content_rowid = 'id',
+    tokenize = 'trigram'
+);
+
+-- 为已有的歌曲建立索引
+INSERT INTO musics_fts(musics_fts) VALUES ('rebuild');
+
+CREATE TRIGGER musics_fts_after_insert
+AFTER INSERT ON musics
+BEGIN
+    INSERT INTO musics_fts(rowid, song_name, author)
+    VALUES (NEW.id, NEW.song_name, NEW.author);
+END;
+
+CREATE TRIGGER musics_fts_after_delete
+AFTER DELETE ON musics
+BEGIN
+    INSERT INTO musics_fts(musics_fts, rowid, song_name, author)
+    VALUES ('delete', OLD.id, OLD.song_name, OLD.author);
+END;
+
+-- 只在名称或作者变化时更新索引，软删除和收藏不影响
+CREATE TRIGGER musics_fts_after_update
+AFTER UPDATE OF song_name, author ON musics
+BEGIN
+    INSERT INTO musics_fts(musics_fts, rowid, song_name, author)
+    VALUES ('delete', OLD.id, OLD.song_name, OLD.author);
+    INSERT INTO musics_fts(rowid, song_name, author)
+    VALUES (NEW.id, NEW.song_name, NEW.author);
+END;
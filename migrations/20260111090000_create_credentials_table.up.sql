@@ -0,0 +1,18 @@
+-- Add up migration script here
+-- B 站登录凭据，只保存一行
+CREATE TABLE credentials (
+    -- 固定为 1，保证只有一行
+    id INTEGER PRIMARY KEY CHECK (id = 1),
+
+    -- cookie 中的 SESSDATA
+    sessdata TEXT NOT NULL,
+
+    -- cookie 中的 bili_jct (CSRF token)
+    bili_jct TEXT,
+
+    -- cookie 中的 DedeUserID，即登录用户的 mid
+    dede_user_id TEXT,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
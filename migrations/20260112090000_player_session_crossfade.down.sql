@@ -0,0 +1,2 @@
+-- Add down migration script here
+ALTER TABLE player_session DROP COLUMN crossfade_seconds;
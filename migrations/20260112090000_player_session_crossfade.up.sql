@@ -0,0 +1,3 @@
+-- Add up migration script here
+-- 切换歌曲时的淡入淡出时长 (秒)，0 表示无缝衔接
+ALTER TABLE player_session ADD COLUMN crossfade_seconds INTEGER NOT NULL DEFAULT 0;
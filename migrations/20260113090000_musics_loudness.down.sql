@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE musics DROP COLUMN loudness_peak;
+ALTER TABLE musics DROP COLUMN loudness_lufs;
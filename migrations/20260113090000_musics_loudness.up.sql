@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- 歌曲的 EBU R128 整体响度 (LUFS) 和采样峰值，未测量时为 NULL
+ALTER TABLE musics ADD COLUMN loudness_lufs REAL;
+ALTER TABLE musics ADD COLUMN loudness_peak REAL;
@@ -0,0 +1,36 @@
+-- Add up migration script here
+-- 均衡器预设
+CREATE TABLE equalizer_presets (
+    -- 主键，自增ID
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+
+    -- 预设名称，唯一
+    name TEXT NOT NULL UNIQUE,
+
+    -- 10 个频段的增益 (dB)，逗号分隔，从低频到高频
+    gains TEXT NOT NULL,
+
+    -- 是否为内置预设，内置预设不能修改和删除
+    is_builtin BOOLEAN NOT NULL DEFAULT 0,
+
+    -- 记录创建时间，自动填充
+    created_at DATETIME DEFAULT CURRENT_TIMESTAMP,
+
+    -- 最后更新时间
+    updated_at DATETIME DEFAULT CURRENT_TIMESTAMP
+);
+
+CREATE TRIGGER update_equalizer_presets_timestamp
+AFTER UPDATE ON equalizer_presets
+BEGIN
+    UPDATE equalizer_presets
+    SET updated_at = CURRENT_TIMESTAMP
+    WHERE id = NEW.id;
+END;
+
+-- 内置预设
+INSERT INTO equalizer_presets (name, gains, is_builtin) VALUES
+    ('flat', '0,0,0,0,0,0,0,0,0,0', 1),
+    ('bass-boost', '7,6,5,3,1,0,0,0,0,0', 1),
+    ('vocal', '-3,-3,-2,0,2,4,4,3,1,0', 1),
+    ('classical', '0,0,0,0,0,0,-4,-4,-4,-6', 1);
@@ -0,0 +1,3 @@
+-- Add down migration script here
+ALTER TABLE player_session DROP COLUMN equalizer_preset;
+ALTER TABLE player_session DROP COLUMN equalizer_gains;
@@ -0,0 +1,5 @@
+-- Add up migration script here
+-- 均衡器各频段的增益 (dB)，逗号分隔，空字符串表示所有频段为 0
+ALTER TABLE player_session ADD COLUMN equalizer_gains TEXT NOT NULL DEFAULT '';
+-- 使用的均衡器预设名称，调整过频段后为 NULL
+ALTER TABLE player_session ADD COLUMN equalizer_preset TEXT;
@@ -0,0 +1,3 @@
+-- Add down migration script here
+CREATE INDEX idx_musics_search ON musics(song_name, author, is_deleted);
+CREATE INDEX idx_musics_author ON musics(author, is_deleted);
@@ -0,0 +1,4 @@
+-- Add up migration script here
+-- 标题和作者改为通过 musics_fts 做子串匹配，LIKE '%...%' 用不到这两个索引
+DROP INDEX IF EXISTS idx_musics_search;
+DROP INDEX IF EXISTS idx_musics_author;
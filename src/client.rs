@@ -1,8 +1,25 @@
-use bili_player::pb::{
-    NextRequest, PauseRequest, PlayBvidRequest, PlayRequest, PreviousRequest, SetModelRequest,
-    StopRequest, player_service_client::PlayerServiceClient,
+use bili_player::{
+    fetch::auth::render_qr,
+    pb::{
+        AddPlaylistRequest, AddToPlaylistRequest, ApplyEqualizerPresetRequest,
+        CreatePlaylistRequest, DeleteEqualizerPresetRequest, DeletePlaylistRequest, DeletedRequest,
+        EqualizerResponse, FindMusicRequest, GetEqualizerRequest, GetStateRequest,
+        ImportFavoriteRequest, ImportSeasonRequest, ImportUploaderRequest, ImportVideosResponse,
+        ListPlaylistsRequest, LoginStatusRequest, LogoutRequest, NextRequest, PauseRequest,
+        PlayBvidRequest, PlayMode, PlayRequest, PlaybackState, PlaylistAction, PreviousRequest,
+        QrLoginPollRequest, QrLoginStartRequest, QrLoginState, RemoveFromPlaylistRequest,
+        RenamePlaylistRequest, RestoreRequest, SaveEqualizerPresetRequest, SearchRequest,
+        SeekRequest, SetCredentialsRequest, SetCrossfadeRequest, SetEqualizerBandRequest,
+        SetModelRequest, SetRateRequest, SetVolumeRequest, ShowPlayListRequest, StopRequest,
+        SubscribeEventsRequest, SwitchPlaylistRequest, VideoFilter,
+        player_service_client::PlayerServiceClient, set_volume_request::Action,
+        subscribe_events_response::Event,
+    },
+    player::{command::SeekTarget, state::format_seconds},
+    utils::east8,
 };
 use clap::{Parser, Subcommand};
+use sqlx::types::chrono::DateTime;
 #[derive(Debug, Parser)]
 #[command(
     name = "bpc",
@@ -43,25 +60,250 @@ enum Commands {
     #[command(about = "从播放列表中删除歌曲")]
     Delete(DeleteCommand),
 
+    #[command(about = "恢复已删除的歌曲")]
+    Restore(RestoreCommand),
+
     #[command(about = "显示播放列表")]
-    Playlist,
+    Playlist(PlaylistCommand),
+
+    #[command(about = "显示当前播放状态")]
+    Status,
+
+    #[command(about = "跳转到指定位置，如 1:23、+10、-5")]
+    Seek(SeekCommand),
+
+    #[command(about = "设置音量或静音")]
+    Volume(VolumeCommand),
+
+    #[command(about = "设置切换歌曲时的淡入淡出时长")]
+    Crossfade(CrossfadeCommand),
+
+    #[command(about = "设置播放速度，如 0.75、1.5")]
+    Rate(RateCommand),
+
+    #[command(subcommand, about = "设置均衡器和管理均衡器预设")]
+    Eq(EqCommand),
+
+    #[command(about = "持续显示播放器事件")]
+    Watch,
+
+    #[command(subcommand, about = "管理歌单")]
+    Playlists(PlaylistsCommand),
+
+    #[command(about = "导入 B 站收藏夹为歌单，重复导入时只添加新的视频")]
+    ImportFav(ImportFavCommand),
+
+    #[command(about = "导入合集中的视频到当前歌单")]
+    ImportSeason(ImportSeasonCommand),
+
+    #[command(about = "导入 UP 主的投稿到当前歌单")]
+    ImportUp(ImportUpCommand),
+
+    #[command(about = "搜索 B 站视频，可以直接添加或播放其中一个结果")]
+    Search(SearchCommand),
+
+    #[command(
+        subcommand,
+        about = "登录 B 站账号，登录后可以播放会员音质和私密收藏夹"
+    )]
+    Login(LoginCommand),
+}
+
+#[derive(Debug, Subcommand)]
+enum LoginCommand {
+    #[command(about = "查看登录状态")]
+    Status,
+
+    #[command(about = "在终端显示二维码，使用 B 站手机客户端扫码登录")]
+    Qr,
+
+    #[command(about = "从文件导入浏览器复制的 cookie，需要包含 SESSDATA")]
+    Cookie {
+        #[arg(help = "cookie 文件路径")]
+        file: std::path::PathBuf,
+    },
+
+    #[command(about = "退出登录")]
+    Logout,
+}
+
+#[derive(Debug, Subcommand)]
+enum EqCommand {
+    #[command(about = "显示当前各频段的增益和所有预设")]
+    List,
+
+    #[command(about = "应用预设")]
+    Apply {
+        #[arg(help = "预设名称，如 flat、bass-boost、vocal、classical")]
+        name: String,
+    },
+
+    #[command(about = "调整一个频段的增益")]
+    Set {
+        #[arg(help = "频段序号 (0-9)，从低频到高频")]
+        band: u32,
+        #[arg(allow_negative_numbers = true, help = "增益 (-24 到 12 dB)")]
+        gain: f64,
+    },
+
+    #[command(about = "保存自定义预设，同名时覆盖")]
+    Save {
+        #[arg(help = "预设名称")]
+        name: String,
+        #[arg(
+            allow_negative_numbers = true,
+            num_args = 10,
+            value_name = "GAIN",
+            help = "10 个频段的增益 (dB)，不指定时保存当前的设置"
+        )]
+        gains: Vec<f64>,
+    },
+
+    #[command(about = "删除自定义预设")]
+    Delete {
+        #[arg(help = "预设名称")]
+        name: String,
+    },
+}
+
+#[derive(Debug, Subcommand)]
+enum PlaylistsCommand {
+    #[command(about = "列出所有歌单")]
+    List,
+
+    #[command(about = "创建歌单")]
+    Create {
+        #[arg(help = "歌单名称")]
+        name: String,
+    },
+
+    #[command(about = "重命名歌单")]
+    Rename {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(help = "新的歌单名称")]
+        new_name: String,
+    },
+
+    #[command(about = "删除歌单，歌曲仍保留在音乐库中")]
+    Delete {
+        #[arg(help = "歌单名称")]
+        name: String,
+    },
+
+    #[command(about = "切换当前播放的歌单")]
+    Switch {
+        #[arg(help = "歌单名称")]
+        name: String,
+    },
+
+    #[command(about = "添加歌曲到歌单，音乐库中没有时自动导入")]
+    Add {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(short = 'b', long = "bvid", help = "要添加的 bvid")]
+        bvid: String,
+        #[arg(
+            short = 'p',
+            long = "pages",
+            help = "要添加的分P，如 3、2-5、all，默认第一P"
+        )]
+        pages: Option<String>,
+    },
+
+    #[command(about = "从歌单中移除歌曲")]
+    Remove {
+        #[arg(help = "歌单名称")]
+        name: String,
+        #[arg(short = 'b', long = "bvid", help = "要移除的 bvid")]
+        bvid: String,
+        #[arg(short = 'c', long = "cid", help = "只移除指定的分P，默认移除所有分P")]
+        cid: Option<String>,
+    },
 }
 
 #[derive(Debug, Parser)]
 struct PlayCommand {
     #[arg(short = 'b', long = "bvid", help = "要播放的 bvid")]
     bvid: Option<String>,
+    #[arg(
+        short = 'c',
+        long = "cid",
+        requires = "bvid",
+        help = "多P视频的分P cid"
+    )]
+    cid: Option<String>,
 }
 
 #[derive(Debug, Parser)]
 struct AddCommand {
     #[arg(short = 'b', long = "bvid", help = "要导入的 bvid")]
     bvid: Option<String>,
+    #[arg(short = 'n', long = "name", help = "自定义歌曲名称，默认使用视频标题")]
+    song_name: Option<String>,
+    #[arg(
+        short = 'p',
+        long = "pages",
+        help = "要导入的分P，如 3、2-5、all，默认只导入第一P"
+    )]
+    pages: Option<String>,
 }
 #[derive(Debug, Parser)]
 struct DeleteCommand {
     #[arg(short = 'b', long = "bvid", help = "按 bvid 删除")]
     bvid: String,
+    #[arg(short = 'c', long = "cid", help = "只删除指定的分P，默认删除所有分P")]
+    cid: Option<String>,
+}
+#[derive(Debug, Parser)]
+struct RestoreCommand {
+    #[arg(short = 'b', long = "bvid", help = "按 bvid 恢复")]
+    bvid: String,
+    #[arg(short = 'c', long = "cid", help = "只恢复指定的分P，默认恢复所有分P")]
+    cid: Option<String>,
+}
+#[derive(Debug, Parser)]
+struct PlaylistCommand {
+    #[arg(short = 'p', long = "page", help = "页码，默认显示当前歌曲所在的页")]
+    page: Option<u32>,
+    #[arg(short = 's', long = "size", help = "每页显示的数量")]
+    page_size: Option<u32>,
+}
+#[derive(Debug, Parser)]
+struct SeekCommand {
+    #[arg(
+        allow_hyphen_values = true,
+        help = "目标位置 (1:23) 或相对偏移 (+10 / -5)"
+    )]
+    target: SeekTarget,
+}
+#[derive(Debug, Parser)]
+#[command(group(clap::ArgGroup::new("action").required(true)))]
+struct VolumeCommand {
+    #[arg(group = "action", help = "音量百分比 (0-200)")]
+    level: Option<u32>,
+    #[arg(short = 'u', long = "up", group = "action", num_args = 0..=1, default_missing_value = "10", help = "增大音量，默认 10")]
+    up: Option<u32>,
+    #[arg(short = 'd', long = "down", group = "action", num_args = 0..=1, default_missing_value = "10", help = "减小音量，默认 10")]
+    down: Option<u32>,
+    #[arg(short = 'm', long = "mute", group = "action", action = clap::ArgAction::SetTrue, help = "静音")]
+    mute: bool,
+    #[arg(long = "unmute", group = "action", action = clap::ArgAction::SetTrue, help = "取消静音，恢复之前的音量")]
+    unmute: bool,
+}
+#[derive(Debug, Parser)]
+struct CrossfadeCommand {
+    #[arg(help = "淡入淡出时长 (0-12 秒)，0 表示关闭")]
+    seconds: u32,
+}
+#[derive(Debug, Parser)]
+struct RateCommand {
+    #[arg(help = "播放速度 (0.5-2.0)，1 为正常速度")]
+    rate: f64,
+    #[arg(short = 'p', long = "preserve-pitch", action = clap::ArgAction::SetTrue, help = "变速时保持音调")]
+    preserve_pitch: bool,
+    #[arg(short = 'k', long = "keep", action = clap::ArgAction::SetTrue, help = "切换歌曲时保持速度，默认恢复为正常速度")]
+    keep: bool,
 }
 #[derive(Debug, Parser)]
 struct ModeCommand {
@@ -82,7 +324,79 @@ struct FindCommand {
     title: Option<String>,
     #[arg(short = 'o', long = "owner", help = "按作者查找")]
     owner: Option<String>,
+    #[arg(short = 'd', long = "deleted", action = clap::ArgAction::SetTrue, help = "包含已删除的歌曲")]
+    include_deleted: bool,
+    #[arg(short = 'l', long = "limit", help = "最多显示的数量，默认 50")]
+    limit: Option<u32>,
+}
+#[derive(Debug, Parser)]
+struct ImportFavCommand {
+    #[arg(help = "收藏夹 id (media_id)")]
+    media_id: i64,
+    #[arg(short = 'n', long = "name", help = "歌单名称，默认使用收藏夹标题")]
+    name: Option<String>,
 }
+#[derive(Debug, clap::Args)]
+struct FilterArgs {
+    #[arg(short = 'k', long = "keyword", help = "只导入标题包含关键字的视频")]
+    keyword: Option<String>,
+    #[arg(long = "min-duration", help = "最短时长 (秒)，用来跳过非音乐的短视频")]
+    min_duration: Option<u32>,
+    #[arg(
+        long = "max-duration",
+        help = "最长时长 (秒)，用来跳过直播回放等长视频"
+    )]
+    max_duration: Option<u32>,
+}
+impl From<FilterArgs> for VideoFilter {
+    fn from(args: FilterArgs) -> Self {
+        VideoFilter {
+            keyword: args.keyword.unwrap_or_default(),
+            min_duration: args.min_duration.unwrap_or_default(),
+            max_duration: args.max_duration.unwrap_or_default(),
+        }
+    }
+}
+#[derive(Debug, Parser)]
+struct ImportSeasonCommand {
+    #[arg(help = "合集所属 UP 主的 mid")]
+    mid: i64,
+    #[arg(help = "合集 id (season_id)")]
+    season_id: i64,
+    #[command(flatten)]
+    filter: FilterArgs,
+}
+#[derive(Debug, Parser)]
+struct ImportUpCommand {
+    #[arg(help = "UP 主的 mid")]
+    mid: i64,
+    #[arg(
+        long = "max-pages",
+        default_value_t = 0,
+        help = "最多请求的页数，每页 50 个，默认全部"
+    )]
+    max_pages: u32,
+    #[command(flatten)]
+    filter: FilterArgs,
+}
+#[derive(Debug, Parser)]
+struct SearchCommand {
+    #[arg(required = true, num_args = 1.., help = "搜索关键字")]
+    keywords: Vec<String>,
+    #[arg(short = 'p', long = "page", default_value_t = 1, help = "页码")]
+    page: u32,
+    #[arg(
+        short = 'a',
+        long = "add",
+        conflicts_with = "play",
+        help = "添加第 N 个结果到播放列表"
+    )]
+    add: Option<usize>,
+    #[arg(short = 'P', long = "play", help = "添加并播放第 N 个结果")]
+    play: Option<usize>,
+}
+// 二维码登录时查询扫码状态的间隔
+const QR_POLL_INTERVAL: std::time::Duration = std::time::Duration::from_secs(2);
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // 解析命令
@@ -94,7 +408,10 @@ async fn main() -> anyhow::Result<()> {
         // 播放，如果有传入 bvid，则播放 bvid 的歌曲，否则播放当前歌曲
         Commands::Play(play_cmd) => {
             if let Some(bvid) = play_cmd.bvid {
-                let request = tonic::Request::new(PlayBvidRequest { bvid });
+                let request = tonic::Request::new(PlayBvidRequest {
+                    bvid,
+                    cid: play_cmd.cid.unwrap_or_default(),
+                });
                 let response = client.play_bvid(request).await?.into_inner();
                 if response.success {
                     eprintln!("{}", response.message);
@@ -153,10 +470,609 @@ async fn main() -> anyhow::Result<()> {
                 eprintln!("{}", response.message);
             };
         }
-        Commands::Add(_add_cmd) => {}
-        Commands::Delete(_delete_cmd) => {}
-        Commands::Find(_find_cmd) => {}
-        Commands::Playlist => {}
+        // 添加歌曲到音乐库和播放列表
+        Commands::Add(add_cmd) => {
+            let Some(bvid) = add_cmd.bvid else {
+                eprintln!("请使用 -b 指定要添加的 bvid");
+                return Ok(());
+            };
+            let request = tonic::Request::new(AddPlaylistRequest {
+                bvid,
+                song_name: add_cmd.song_name.unwrap_or_default(),
+                pages: add_cmd.pages.unwrap_or_default(),
+            });
+            match client.add_playlist(request).await {
+                Ok(response) => {
+                    let response = response.into_inner();
+                    for music in &response.musics {
+                        eprintln!(
+                            "{} - {} ({} / {})",
+                            music.title, music.owner, music.bvid, music.cid
+                        );
+                    }
+                    eprintln!("{}", response.message);
+                }
+                Err(status) => eprintln!("添加失败: {}", status.message()),
+            }
+        }
+        // 从音乐库和播放列表中删除歌曲
+        Commands::Delete(delete_cmd) => {
+            let request = tonic::Request::new(DeletedRequest {
+                bvid: delete_cmd.bvid,
+                cid: delete_cmd.cid.unwrap_or_default(),
+            });
+            match client.deleted(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("删除失败: {}", status.message()),
+            }
+        }
+        // 恢复已删除的歌曲
+        Commands::Restore(restore_cmd) => {
+            let request = tonic::Request::new(RestoreRequest {
+                bvid: restore_cmd.bvid,
+                cid: restore_cmd.cid.unwrap_or_default(),
+            });
+            match client.restore(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("恢复失败: {}", status.message()),
+            }
+        }
+        Commands::Find(find_cmd) => {
+            let request = tonic::Request::new(FindMusicRequest {
+                bvid: find_cmd.bvid.unwrap_or_default(),
+                cid: find_cmd.cid.unwrap_or_default(),
+                title: find_cmd.title.unwrap_or_default(),
+                owner: find_cmd.owner.unwrap_or_default(),
+                include_deleted: find_cmd.include_deleted,
+                limit: find_cmd.limit.unwrap_or_default(),
+            });
+            let response = match client.find_music(request).await {
+                Ok(response) => response.into_inner(),
+                Err(status) => {
+                    eprintln!("查找失败: {}", status.message());
+                    return Ok(());
+                }
+            };
+            // ▶ 表示在当前歌单中，✗ 表示已删除
+            for found in &response.musics {
+                let marker = match (found.is_deleted, found.in_active_playlist) {
+                    (true, _) => "✗",
+                    (false, true) => "▶",
+                    (false, false) => " ",
+                };
+                let music = found.music.clone().unwrap_or_default();
+                eprintln!(
+                    " {} {:<14} {:<12} {} - {}",
+                    marker, music.bvid, music.cid, music.title, music.owner
+                );
+            }
+            eprintln!("{}", response.message);
+        }
+        // 分页显示播放列表
+        Commands::Playlist(playlist_cmd) => {
+            let request = tonic::Request::new(ShowPlayListRequest {
+                page: playlist_cmd.page.unwrap_or_default() as i32,
+                page_size: playlist_cmd.page_size.unwrap_or_default() as i32,
+            });
+            let response = client.show_play_list(request).await?.into_inner();
+            eprintln!("   {:>4}  {:<14}  歌曲 - UP主", "序号", "bvid");
+            for entry in &response.entries {
+                let marker = if entry.is_current { "▶" } else { " " };
+                let music = entry.music.clone().unwrap_or_default();
+                eprintln!(
+                    "{}  {:>4}  {:<14}  {} - {}",
+                    marker,
+                    entry.index + 1,
+                    music.bvid,
+                    music.title,
+                    music.owner
+                );
+            }
+            let mut navigation = Vec::new();
+            if response.has_previous {
+                navigation.push(format!("上一页: -p {}", response.current - 1));
+            }
+            if response.has_next {
+                navigation.push(format!("下一页: -p {}", response.current + 1));
+            }
+            eprintln!(
+                "第 {}/{} 页，共 {} 首  {}",
+                response.current,
+                response.total_pages,
+                response.total,
+                navigation.join("  ")
+            );
+        }
+        // 跳转播放位置
+        Commands::Seek(seek_cmd) => {
+            let request = tonic::Request::new(SeekRequest::from(seek_cmd.target));
+            match client.seek(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("跳转失败: {}", status.message()),
+            }
+        }
+        // 设置音量
+        Commands::Volume(volume_cmd) => {
+            let action = if let Some(level) = volume_cmd.level {
+                Action::Level(level)
+            } else if let Some(step) = volume_cmd.up {
+                Action::Delta(step as i32)
+            } else if let Some(step) = volume_cmd.down {
+                Action::Delta(-(step as i32))
+            } else {
+                Action::Mute(volume_cmd.mute && !volume_cmd.unmute)
+            };
+            let request = tonic::Request::new(SetVolumeRequest {
+                action: Some(action),
+            });
+            match client.set_volume(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("设置音量失败: {}", status.message()),
+            }
+        }
+        // 设置淡入淡出时长
+        Commands::Crossfade(crossfade_cmd) => {
+            let request = tonic::Request::new(SetCrossfadeRequest {
+                seconds: crossfade_cmd.seconds,
+            });
+            match client.set_crossfade(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("设置淡入淡出失败: {}", status.message()),
+            }
+        }
+        // 设置播放速度
+        Commands::Rate(rate_cmd) => {
+            let request = tonic::Request::new(SetRateRequest {
+                rate: rate_cmd.rate,
+                preserve_pitch: rate_cmd.preserve_pitch,
+                keep: rate_cmd.keep,
+            });
+            match client.set_rate(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                Err(status) => eprintln!("设置播放速度失败: {}", status.message()),
+            }
+        }
+        // 均衡器
+        Commands::Eq(eq_cmd) => match eq_cmd {
+            EqCommand::List => {
+                let request = tonic::Request::new(GetEqualizerRequest {});
+                print_equalizer(client.get_equalizer(request).await, "获取均衡器失败", true);
+            }
+            EqCommand::Apply { name } => {
+                let request = tonic::Request::new(ApplyEqualizerPresetRequest { name });
+                print_equalizer(
+                    client.apply_equalizer_preset(request).await,
+                    "应用均衡器预设失败",
+                    false,
+                );
+            }
+            EqCommand::Set { band, gain } => {
+                let request = tonic::Request::new(SetEqualizerBandRequest { band, gain });
+                print_equalizer(
+                    client.set_equalizer_band(request).await,
+                    "设置均衡器失败",
+                    false,
+                );
+            }
+            EqCommand::Save { name, gains } => {
+                let request = tonic::Request::new(SaveEqualizerPresetRequest { name, gains });
+                print_equalizer(
+                    client.save_equalizer_preset(request).await,
+                    "保存均衡器预设失败",
+                    false,
+                );
+            }
+            EqCommand::Delete { name } => {
+                let request = tonic::Request::new(DeleteEqualizerPresetRequest { name });
+                print_equalizer(
+                    client.delete_equalizer_preset(request).await,
+                    "删除均衡器预设失败",
+                    false,
+                );
+            }
+        },
+        // 订阅播放器事件，直到服务端断开
+        Commands::Watch => {
+            let request = tonic::Request::new(SubscribeEventsRequest {});
+            let mut stream = client.subscribe_events(request).await?.into_inner();
+            while let Some(event) = stream.message().await? {
+                let time = DateTime::from_timestamp_millis(event.timestamp)
+                    .map(|t| t.with_timezone(&east8().unwrap()).format("%T").to_string())
+                    .unwrap_or_default();
+                let Some(event) = event.event else {
+                    continue;
+                };
+                let info = match event {
+                    Event::TrackChanged(e) => {
+                        let music = e.music.unwrap_or_default();
+                        format!(
+                            "正在播放: 第{}首 {} - {}{}",
+                            e.index + 1,
+                            music.title,
+                            music.owner,
+                            e.quality
+                                .map(|quality| format!(" [{}]", quality.label))
+                                .unwrap_or_default()
+                        )
+                    }
+                    Event::StateChanged(e) => match e.buffering_percent {
+                        Some(percent) if e.state() == PlaybackState::Buffering => {
+                            format!("播放状态: {} {}%", state_name(e.state()), percent)
+                        }
+                        _ => format!("播放状态: {}", state_name(e.state())),
+                    },
+                    Event::VolumeChanged(e) => {
+                        if e.muted {
+                            format!("音量: {}% (静音)", e.volume)
+                        } else {
+                            format!("音量: {}%", e.volume)
+                        }
+                    }
+                    Event::ModeChanged(e) => format!("播放模式: {}", mode_name(e.play_mode())),
+                    Event::PlaylistChanged(e) => {
+                        let action = match e.action() {
+                            PlaylistAction::Added => "添加",
+                            PlaylistAction::Removed => "删除",
+                            PlaylistAction::Restored => "恢复",
+                        };
+                        let music = e.music.unwrap_or_default();
+                        format!(
+                            "播放列表{}: {} - {} (共{}首)",
+                            action, music.title, music.owner, e.playlist_len
+                        )
+                    }
+                    Event::PlaylistSwitched(e) => {
+                        format!("切换歌单: {} (共{}首)", e.name, e.playlist_len)
+                    }
+                    Event::Error(e) => format!("错误: {}", e.message),
+                };
+                eprintln!("[{}] {}", time, info);
+            }
+        }
+        // 管理歌单
+        Commands::Playlists(playlists_cmd) => match playlists_cmd {
+            PlaylistsCommand::List => {
+                let request = tonic::Request::new(ListPlaylistsRequest {});
+                let response = client.list_playlists(request).await?.into_inner();
+                eprintln!("   {:<20}  歌曲数", "歌单");
+                for playlist in &response.playlists {
+                    let marker = if playlist.is_active { "▶" } else { " " };
+                    eprintln!(
+                        "{}  {:<20}  {}",
+                        marker, playlist.name, playlist.track_count
+                    );
+                }
+            }
+            PlaylistsCommand::Create { name } => {
+                let request = tonic::Request::new(CreatePlaylistRequest { name });
+                match client.create_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("创建歌单失败: {}", status.message()),
+                }
+            }
+            PlaylistsCommand::Rename { name, new_name } => {
+                let request = tonic::Request::new(RenamePlaylistRequest { name, new_name });
+                match client.rename_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("重命名歌单失败: {}", status.message()),
+                }
+            }
+            PlaylistsCommand::Delete { name } => {
+                let request = tonic::Request::new(DeletePlaylistRequest { name });
+                match client.delete_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("删除歌单失败: {}", status.message()),
+                }
+            }
+            PlaylistsCommand::Switch { name } => {
+                let request = tonic::Request::new(SwitchPlaylistRequest { name });
+                match client.switch_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("切换歌单失败: {}", status.message()),
+                }
+            }
+            PlaylistsCommand::Add { name, bvid, pages } => {
+                let request = tonic::Request::new(AddToPlaylistRequest {
+                    name,
+                    bvid,
+                    pages: pages.unwrap_or_default(),
+                });
+                match client.add_to_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("添加到歌单失败: {}", status.message()),
+                }
+            }
+            PlaylistsCommand::Remove { name, bvid, cid } => {
+                let request = tonic::Request::new(RemoveFromPlaylistRequest {
+                    name,
+                    bvid,
+                    cid: cid.unwrap_or_default(),
+                });
+                match client.remove_from_playlist(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("从歌单移除失败: {}", status.message()),
+                }
+            }
+        },
+        // 导入收藏夹，列出新增、失效和收藏夹中已移除的歌曲
+        Commands::ImportFav(import_fav_cmd) => {
+            let request = tonic::Request::new(ImportFavoriteRequest {
+                media_id: import_fav_cmd.media_id,
+                playlist_name: import_fav_cmd.name.unwrap_or_default(),
+            });
+            match client.import_favorite(request).await {
+                Ok(response) => {
+                    let response = response.into_inner();
+                    for music in &response.added {
+                        eprintln!("新增: {} - {} ({})", music.title, music.owner, music.bvid);
+                    }
+                    for music in &response.removed_upstream {
+                        eprintln!(
+                            "收藏夹中已移除: {} - {} ({})",
+                            music.title, music.owner, music.bvid
+                        );
+                    }
+                    for item in &response.unavailable {
+                        eprintln!("已失效: {item}");
+                    }
+                    for item in &response.failed {
+                        eprintln!("导入失败: {item}");
+                    }
+                    eprintln!("{}", response.message);
+                }
+                Err(status) => eprintln!("导入收藏夹失败: {}", status.message()),
+            }
+        }
+        // 导入合集或 UP 主投稿
+        Commands::ImportSeason(import_season_cmd) => {
+            let request = tonic::Request::new(ImportSeasonRequest {
+                mid: import_season_cmd.mid,
+                season_id: import_season_cmd.season_id,
+                filter: Some(import_season_cmd.filter.into()),
+            });
+            print_import_videos(client.import_season(request).await);
+        }
+        Commands::ImportUp(import_up_cmd) => {
+            let request = tonic::Request::new(ImportUploaderRequest {
+                mid: import_up_cmd.mid,
+                filter: Some(import_up_cmd.filter.into()),
+                max_pages: import_up_cmd.max_pages,
+            });
+            print_import_videos(client.import_uploader(request).await);
+        }
+        // 搜索视频，按序号添加或播放
+        Commands::Search(search_cmd) => {
+            let request = tonic::Request::new(SearchRequest {
+                keyword: search_cmd.keywords.join(" "),
+                page: search_cmd.page,
+            });
+            let response = match client.search(request).await {
+                Ok(response) => response.into_inner(),
+                Err(status) => {
+                    eprintln!("搜索失败: {}", status.message());
+                    return Ok(());
+                }
+            };
+            eprintln!(
+                " {:>3}  {:<12}  {:>5}  {:>8}  标题 - UP主",
+                "序号", "bvid", "时长", "播放"
+            );
+            for (index, result) in response.results.iter().enumerate() {
+                eprintln!(
+                    " {:>3}  {:<12}  {:>5}  {:>8}  {} - {}",
+                    index + 1,
+                    result.bvid,
+                    format_seconds(Some(result.duration as f64)),
+                    result.play,
+                    result.title,
+                    result.author
+                );
+            }
+            eprintln!("{}", response.message);
+            let Some(number) = search_cmd.add.or(search_cmd.play) else {
+                return Ok(());
+            };
+            let Some(result) = number.checked_sub(1).and_then(|i| response.results.get(i)) else {
+                eprintln!(
+                    "序号 {number} 超出范围，本页共 {} 个结果",
+                    response.results.len()
+                );
+                return Ok(());
+            };
+            let request = tonic::Request::new(AddPlaylistRequest {
+                bvid: result.bvid.clone(),
+                song_name: String::new(),
+                pages: String::new(),
+            });
+            match client.add_playlist(request).await {
+                Ok(response) => eprintln!("{}", response.into_inner().message),
+                // 已在音乐库中时仍然可以直接播放
+                Err(status) if status.code() == tonic::Code::AlreadyExists => {
+                    eprintln!("{}", status.message())
+                }
+                Err(status) => {
+                    eprintln!("添加失败: {}", status.message());
+                    return Ok(());
+                }
+            }
+            if search_cmd.play.is_some() {
+                let request = tonic::Request::new(PlayBvidRequest {
+                    bvid: result.bvid.clone(),
+                    cid: String::new(),
+                });
+                eprintln!("{}", client.play_bvid(request).await?.into_inner().message);
+            }
+        }
+        // 登录
+        Commands::Login(login_cmd) => match login_cmd {
+            LoginCommand::Status => {
+                let request = tonic::Request::new(LoginStatusRequest {});
+                match client.get_login_status(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("查询登录状态失败: {}", status.message()),
+                }
+            }
+            LoginCommand::Qr => {
+                let request = tonic::Request::new(QrLoginStartRequest {});
+                let qr_login = client.qr_login_start(request).await?.into_inner();
+                eprintln!("{}", render_qr(&qr_login.url)?);
+                eprintln!("请使用 B 站手机客户端扫码登录");
+                let mut scanned = false;
+                loop {
+                    tokio::time::sleep(QR_POLL_INTERVAL).await;
+                    let request = tonic::Request::new(QrLoginPollRequest {
+                        qrcode_key: qr_login.qrcode_key.clone(),
+                    });
+                    let response = match client.qr_login_poll(request).await {
+                        Ok(response) => response.into_inner(),
+                        Err(status) => {
+                            eprintln!("登录失败: {}", status.message());
+                            break;
+                        }
+                    };
+                    match response.state() {
+                        QrLoginState::QrWaiting => {}
+                        QrLoginState::QrScanned => {
+                            if !scanned {
+                                eprintln!("已扫码，请在手机上确认登录");
+                                scanned = true;
+                            }
+                        }
+                        QrLoginState::QrExpired => {
+                            eprintln!("二维码已过期，请重新登录");
+                            break;
+                        }
+                        QrLoginState::QrConfirmed => {
+                            if let Some(status) = response.status {
+                                eprintln!("{}", status.message);
+                            }
+                            break;
+                        }
+                    }
+                }
+            }
+            LoginCommand::Cookie { file } => {
+                let cookie = std::fs::read_to_string(&file)?;
+                let request = tonic::Request::new(SetCredentialsRequest { cookie });
+                match client.set_credentials(request).await {
+                    Ok(response) => eprintln!("{}", response.into_inner().message),
+                    Err(status) => eprintln!("登录失败: {}", status.message()),
+                }
+            }
+            LoginCommand::Logout => {
+                let request = tonic::Request::new(LogoutRequest {});
+                eprintln!("{}", client.logout(request).await?.into_inner().message);
+            }
+        },
+        // 显示当前播放状态
+        Commands::Status => {
+            let request = tonic::Request::new(GetStateRequest {});
+            let response = client.get_state(request).await?.into_inner();
+            let state = state_name(response.state());
+            let play_mode = mode_name(response.play_mode());
+            match &response.current_music {
+                Some(music) => eprintln!(
+                    "当前歌曲: {} - {} ({})",
+                    music.title, music.owner, music.bvid
+                ),
+                None => eprintln!("当前歌曲: 无"),
+            }
+            eprintln!("播放状态: {}", state);
+            eprintln!(
+                "播放进度: {}/{}",
+                format_seconds(response.position),
+                format_seconds(response.duration)
+            );
+            eprintln!("播放模式: {}", play_mode);
+            eprintln!(
+                "播放列表: 第{}首/共{}首",
+                response.current_index.map(|i| i + 1).unwrap_or_default(),
+                response.playlist_len
+            );
+            if response.muted {
+                eprintln!("音量: {}% (静音)", response.volume);
+            } else {
+                eprintln!("音量: {}%", response.volume);
+            }
+            if let Some(quality) = &response.quality {
+                eprintln!("音质: {} ({})", quality.label, quality.codecs);
+            }
+        }
     }
     Ok(())
 }
+
+/// 打印批量导入的结果
+fn print_import_videos(result: Result<tonic::Response<ImportVideosResponse>, tonic::Status>) {
+    match result {
+        Ok(response) => {
+            let response = response.into_inner();
+            for music in &response.added {
+                eprintln!("新增: {} - {} ({})", music.title, music.owner, music.bvid);
+            }
+            for item in &response.failed {
+                eprintln!("导入失败: {item}");
+            }
+            eprintln!("{}", response.message);
+        }
+        Err(status) => eprintln!("批量导入失败: {}", status.message()),
+    }
+}
+/// 打印均衡器各频段的增益，show_presets 为 true 时同时列出所有预设
+fn print_equalizer(
+    result: Result<tonic::Response<EqualizerResponse>, tonic::Status>,
+    error: &str,
+    show_presets: bool,
+) {
+    let response = match result {
+        Ok(response) => response.into_inner(),
+        Err(status) => {
+            eprintln!("{}: {}", error, status.message());
+            return;
+        }
+    };
+    eprintln!("{}", response.message);
+    for (index, band) in response.bands.iter().enumerate() {
+        let frequency = if band.frequency >= 1000 {
+            format!("{:.1}kHz", band.frequency as f64 / 1000.0)
+        } else {
+            format!("{}Hz", band.frequency)
+        };
+        eprintln!("  {}  {:>8}  {:+.1} dB", index, frequency, band.gain);
+    }
+    if !show_presets {
+        return;
+    }
+    eprintln!("预设:");
+    for preset in &response.presets {
+        let marker = if preset.name == response.preset {
+            "▶"
+        } else {
+            " "
+        };
+        let gains: Vec<String> = preset.gains.iter().map(|gain| format!("{gain}")).collect();
+        eprintln!(
+            "{}  {:<16}  {}{}",
+            marker,
+            preset.name,
+            gains.join(","),
+            if preset.builtin { "  (内置)" } else { "" }
+        );
+    }
+}
+fn state_name(state: PlaybackState) -> &'static str {
+    match state {
+        PlaybackState::Playing => "播放中",
+        PlaybackState::Paused => "已暂停",
+        PlaybackState::Stopped => "已停止",
+        PlaybackState::Buffering => "缓冲中",
+    }
+}
+
+fn mode_name(play_mode: PlayMode) -> &'static str {
+    match play_mode {
+        PlayMode::Normal => "顺序播放",
+        PlayMode::Shuffle => "随机播放",
+        PlayMode::Repeat => "单曲循环",
+    }
+}
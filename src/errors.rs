@@ -13,6 +13,9 @@ pub enum ApplicationError {
     #[error("Set Volume error: {0}")]
     VolumeError(String),
 
+    #[error("Invalid argument: {0}")]
+    InvalidArgumentError(String),
+
     #[error("I/O error: {0}")]
     IoError(String),
 
@@ -48,6 +51,18 @@ pub enum ApplicationError {
 
     #[error("GStreamer state error: {0}")]
     StateError(String),
+
+    #[error("Database error: {0}")]
+    DatabaseError(String),
+
+    #[error("Not found: {0}")]
+    NotFoundError(String),
+
+    #[error("Already exists: {0}")]
+    AlreadyExistsError(String),
+
+    #[error("Login required: {0}")]
+    AuthError(String),
 }
 impl From<std::string::String> for ApplicationError {
     fn from(error: std::string::String) -> Self {
@@ -85,8 +100,39 @@ impl From<JoinError> for ApplicationError {
     }
 }
 
+impl From<sqlx::Error> for ApplicationError {
+    fn from(error: sqlx::Error) -> Self {
+        ApplicationError::DatabaseError(error.to_string())
+    }
+}
+
+impl From<sqlx::migrate::MigrateError> for ApplicationError {
+    fn from(error: sqlx::migrate::MigrateError) -> Self {
+        ApplicationError::DatabaseError(error.to_string())
+    }
+}
+
 impl<T> From<SendError<T>> for ApplicationError {
     fn from(error: SendError<T>) -> Self {
         ApplicationError::SendError(error.to_string())
     }
 }
+
+impl From<ApplicationError> for tonic::Status {
+    fn from(error: ApplicationError) -> Self {
+        match error {
+            ApplicationError::NotFoundError(_) => tonic::Status::not_found(error.to_string()),
+            ApplicationError::AlreadyExistsError(_) => {
+                tonic::Status::already_exists(error.to_string())
+            }
+            ApplicationError::VolumeError(_) | ApplicationError::InvalidArgumentError(_) => {
+                tonic::Status::invalid_argument(error.to_string())
+            }
+            ApplicationError::AuthError(_) => tonic::Status::unauthenticated(error.to_string()),
+            ApplicationError::NetworkError(_) | ApplicationError::FetchError(_) => {
+                tonic::Status::unavailable(error.to_string())
+            }
+            _ => tonic::Status::internal(error.to_string()),
+        }
+    }
+}
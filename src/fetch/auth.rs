@@ -0,0 +1,311 @@
+use std::{
+    path::Path,
+    str::FromStr,
+    sync::{Arc, RwLock},
+};
+
+use qrcode::{QrCode, render::unicode};
+use reqwest::{Client, Url, cookie::Jar};
+use serde::Deserialize;
+
+use crate::{errors::ApplicationError, fetch::network::fetch_api_data};
+
+// 登录接口地址，测试时可以替换为本地的模拟服务
+pub const BILIBILI_PASSPORT_BASE: &str = "https://passport.bilibili.com";
+
+const LOGIN_STATUS_API_PATH: &str = "/x/web-interface/nav";
+const QR_GENERATE_API_PATH: &str = "/x/passport-login/web/qrcode/generate";
+const QR_POLL_API_PATH: &str = "/x/passport-login/web/qrcode/poll";
+// 二维码登录轮询结果
+const QR_CONFIRMED_CODE: i64 = 0;
+const QR_EXPIRED_CODE: i64 = 86038;
+const QR_SCANNED_CODE: i64 = 86090;
+const QR_WAITING_CODE: i64 = 86101;
+
+/// B 站登录凭据，来自 cookie 中的 SESSDATA、bili_jct 和 DedeUserID
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct Credentials {
+    pub sessdata: String,
+    // CSRF token，修改类的接口需要
+    pub bili_jct: Option<String>,
+    // 登录用户的 mid
+    pub dede_user_id: Option<String>,
+}
+
+impl Credentials {
+    /// 从文件中读取凭据，文件内容为浏览器复制的 cookie 或每行一个 key=value
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ApplicationError> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path).map_err(|e| {
+            ApplicationError::IoError(format!("读取登录凭据 {} 失败: {e}", path.display()))
+        })?;
+        content.parse()
+    }
+
+    // 凭据对应的 cookie，值为空时清除
+    fn cookies(&self) -> Vec<(&'static str, Option<&str>)> {
+        vec![
+            ("SESSDATA", Some(self.sessdata.as_str())),
+            ("bili_jct", self.bili_jct.as_deref()),
+            ("DedeUserID", self.dede_user_id.as_deref()),
+        ]
+    }
+}
+
+impl FromStr for Credentials {
+    type Err = ApplicationError;
+
+    /// 解析 "SESSDATA=xxx; bili_jct=yyy" 形式的 cookie，也支持换行分隔和 Cookie: 前缀
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let s = s
+            .strip_prefix("Cookie:")
+            .or_else(|| s.strip_prefix("cookie:"))
+            .unwrap_or(s);
+        let mut sessdata = None;
+        let mut bili_jct = None;
+        let mut dede_user_id = None;
+        for pair in s.split([';', '\n']) {
+            let Some((key, value)) = pair.split_once('=') else {
+                continue;
+            };
+            let value = value.trim();
+            if value.is_empty() {
+                continue;
+            }
+            match key.trim() {
+                "SESSDATA" => sessdata = Some(value.to_string()),
+                "bili_jct" => bili_jct = Some(value.to_string()),
+                "DedeUserID" => dede_user_id = Some(value.to_string()),
+                _ => {}
+            }
+        }
+        let sessdata = sessdata.ok_or_else(|| {
+            ApplicationError::DataParsingError("登录凭据中没有 SESSDATA".to_string())
+        })?;
+        Ok(Credentials {
+            sessdata,
+            bili_jct,
+            dede_user_id,
+        })
+    }
+}
+
+/// 读取环境变量 BILI_COOKIE_FILE 指定的凭据文件路径，未设置时返回 None
+pub fn credentials_file() -> Option<String> {
+    dotenvy::dotenv().ok();
+    std::env::var("BILI_COOKIE_FILE")
+        .ok()
+        .filter(|path| !path.trim().is_empty())
+}
+
+/// 登录状态，所有请求共用一个 cookie jar，登录或退出后立即对所有请求生效
+#[derive(Clone, Debug)]
+pub struct AuthSession {
+    jar: Arc<Jar>,
+    // cookie 所属的接口地址
+    cookie_url: Url,
+    credentials: Arc<RwLock<Option<Credentials>>>,
+}
+
+impl AuthSession {
+    /// 创建未登录的会话
+    ///
+    /// # 参数
+    /// - api_base: 接口地址，一般为 BILIBILI_API_BASE
+    pub fn new(api_base: &str) -> Result<Self, ApplicationError> {
+        let cookie_url = Url::parse(api_base)
+            .map_err(|e| ApplicationError::DataParsingError(format!("{api_base}: {e}")))?;
+        Ok(Self {
+            jar: Arc::new(Jar::default()),
+            cookie_url,
+            credentials: Arc::new(RwLock::new(None)),
+        })
+    }
+
+    /// 创建带 cookie 的 client，登录后的请求都会带上凭据
+    pub fn client(&self) -> Result<Client, ApplicationError> {
+        Client::builder()
+            .cookie_provider(Arc::clone(&self.jar))
+            .build()
+            .map_err(|e| ApplicationError::InitError(format!("Failed to build client: {e}")))
+    }
+
+    /// 使用凭据登录，覆盖之前的凭据
+    pub fn login(&self, credentials: Credentials) {
+        for (name, value) in credentials.cookies() {
+            self.set_cookie(name, value);
+        }
+        if let Ok(mut current) = self.credentials.write() {
+            *current = Some(credentials);
+        }
+    }
+
+    /// 退出登录，清除所有凭据
+    pub fn logout(&self) {
+        for name in ["SESSDATA", "bili_jct", "DedeUserID"] {
+            self.set_cookie(name, None);
+        }
+        if let Ok(mut current) = self.credentials.write() {
+            *current = None;
+        }
+    }
+
+    /// 当前使用的凭据
+    pub fn credentials(&self) -> Option<Credentials> {
+        self.credentials
+            .read()
+            .ok()
+            .and_then(|credentials| credentials.clone())
+    }
+
+    // 设置 cookie，值为 None 时让 cookie 立即过期
+    fn set_cookie(&self, name: &str, value: Option<&str>) {
+        let cookie = match value {
+            Some(value) => format!("{name}={value}; Path=/"),
+            None => format!("{name}=; Path=/; Max-Age=0"),
+        };
+        self.jar.add_cookie_str(&cookie, &self.cookie_url);
+    }
+}
+
+/// 当前的登录状态
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct LoginStatus {
+    #[serde(rename = "isLogin", default)]
+    pub is_login: bool,
+    #[serde(default)]
+    pub mid: i64,
+    #[serde(default)]
+    pub uname: String,
+}
+
+impl From<LoginStatus> for crate::pb::LoginStatusResponse {
+    fn from(status: LoginStatus) -> Self {
+        let message = if status.is_login {
+            format!("已登录: {} ({})", status.uname, status.mid)
+        } else {
+            "未登录或登录已过期".to_string()
+        };
+        crate::pb::LoginStatusResponse {
+            logged_in: status.is_login,
+            mid: status.mid,
+            uname: status.uname,
+            message,
+        }
+    }
+}
+
+/// 查询登录状态，未登录或登录已过期时 is_login 为 false
+pub async fn fetch_login_status(
+    client: &Client,
+    api_base: &str,
+) -> Result<LoginStatus, ApplicationError> {
+    match fetch_api_data(
+        client,
+        api_base,
+        LOGIN_STATUS_API_PATH,
+        &[],
+        "login status",
+        "nav",
+    )
+    .await
+    {
+        Ok(status) => Ok(status),
+        Err(ApplicationError::AuthError(_)) => Ok(LoginStatus::default()),
+        Err(e) => Err(e),
+    }
+}
+
+/// 二维码登录的链接和轮询用的 key
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct QrLogin {
+    pub url: String,
+    pub qrcode_key: String,
+}
+
+/// 二维码登录的状态
+#[derive(Debug, Clone, PartialEq)]
+pub enum QrLoginState {
+    Waiting,                // 未扫码
+    Scanned,                // 已扫码，等待在手机上确认
+    Expired,                // 二维码已过期
+    Confirmed(Credentials), // 已确认登录
+}
+
+#[derive(Debug, Deserialize)]
+struct QrPollData {
+    #[serde(default)]
+    url: String,
+    code: i64,
+    #[serde(default)]
+    message: String,
+}
+
+/// 申请登录二维码
+///
+/// # 参数
+/// - passport_base: 登录接口地址，一般为 BILIBILI_PASSPORT_BASE
+pub async fn start_qr_login(
+    client: &Client,
+    passport_base: &str,
+) -> Result<QrLogin, ApplicationError> {
+    fetch_api_data(
+        client,
+        passport_base,
+        QR_GENERATE_API_PATH,
+        &[],
+        "login qrcode",
+        "qrcode",
+    )
+    .await
+}
+
+/// 查询二维码的扫码状态，确认登录后从返回的链接中取出凭据
+pub async fn poll_qr_login(
+    client: &Client,
+    passport_base: &str,
+    qrcode_key: &str,
+) -> Result<QrLoginState, ApplicationError> {
+    let query = [("qrcode_key", qrcode_key.to_string())];
+    let data: QrPollData = fetch_api_data(
+        client,
+        passport_base,
+        QR_POLL_API_PATH,
+        &query,
+        "login state",
+        qrcode_key,
+    )
+    .await?;
+    match data.code {
+        QR_CONFIRMED_CODE => credentials_from_url(&data.url).map(QrLoginState::Confirmed),
+        QR_EXPIRED_CODE => Ok(QrLoginState::Expired),
+        QR_SCANNED_CODE => Ok(QrLoginState::Scanned),
+        QR_WAITING_CODE => Ok(QrLoginState::Waiting),
+        code => Err(ApplicationError::FetchError(format!(
+            "二维码登录失败: {} ({code})",
+            data.message
+        ))),
+    }
+}
+
+// 登录成功后的跳转链接中带有 SESSDATA、bili_jct 和 DedeUserID 参数
+// 参数值保持编码后的形式，和浏览器中保存的 cookie 一致
+fn credentials_from_url(url: &str) -> Result<Credentials, ApplicationError> {
+    let url = Url::parse(url)
+        .map_err(|e| ApplicationError::DataParsingError(format!("登录链接无效: {e}")))?;
+    url.query().unwrap_or_default().replace('&', "; ").parse()
+}
+
+/// 把文本渲染为终端中显示的二维码
+pub fn render_qr(text: &str) -> Result<String, ApplicationError> {
+    let code = QrCode::new(text.as_bytes())
+        .map_err(|e| ApplicationError::DataParsingError(format!("生成二维码失败: {e}")))?;
+    // 终端一般是深色背景，反色后手机更容易识别
+    Ok(code
+        .render::<unicode::Dense1x2>()
+        .dark_color(unicode::Dense1x2::Light)
+        .light_color(unicode::Dense1x2::Dark)
+        .quiet_zone(true)
+        .build())
+}
@@ -1,2 +1,5 @@
+pub mod auth;
 pub mod network;
+pub mod quality;
 pub mod verify;
+pub mod wbi;
@@ -1,56 +1,774 @@
-use reqwest::Client;
+use reqwest::{Client, header::USER_AGENT};
+use serde::de::DeserializeOwned;
 use serde_json::Value;
 
-use crate::errors::ApplicationError;
+use crate::{
+    errors::ApplicationError,
+    fetch::{
+        quality::{AudioKind, AudioPreference, AudioQuality, AudioStream},
+        wbi::{build_url, invalidate_wbi_keys, requires_wbi},
+    },
+};
 
-const BASE_FETCH_AUDIO_API_URL: &str = "https://api.bilibili.com/x/player/playurl?fnval=16";
-const BASE_FETCH_VIDEO_API_URL: &str = "https://api.bilibili.com/x/web-interface/view";
+// B 站接口地址，测试时可以替换为本地的模拟服务
+pub const BILIBILI_API_BASE: &str = "https://api.bilibili.com";
 
-/// 获取音频URL
-pub async fn fetch_audio_url(
+const FETCH_AUDIO_API_PATH: &str = "/x/player/playurl";
+// 16 DASH、256 杜比音频、4048 为所有格式
+const FETCH_AUDIO_FNVAL: u32 = 4048;
+const FETCH_VIDEO_API_PATH: &str = "/x/web-interface/wbi/view";
+const FETCH_FAVORITE_API_PATH: &str = "/x/v3/fav/resource/list";
+const FETCH_SEASON_API_PATH: &str = "/x/polymer/web-space/seasons_archives_list";
+const FETCH_UPLOADER_API_PATH: &str = "/x/space/wbi/arc/search";
+const SEARCH_API_PATH: &str = "/x/web-interface/wbi/search/type";
+// 接口返回这个 code 时说明未登录或登录已过期
+pub(crate) const ACCOUNT_NOT_LOGIN_CODE: i64 = -101;
+// WBI 签名无效或过期时返回的 code
+const WBI_REJECTED_CODES: [i64; 2] = [-352, -403];
+// 搜索等接口会拒绝没有浏览器 UA 的请求
+pub(crate) const BROWSER_USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/120.0 Safari/537.36";
+// 收藏夹每页数量，接口最大支持 20
+pub const FAVORITE_PAGE_SIZE: u32 = 20;
+// 合集每页数量，接口最大支持 100
+pub const SEASON_PAGE_SIZE: u32 = 100;
+// UP 主投稿每页数量，接口最大支持 50
+pub const UPLOADER_PAGE_SIZE: u32 = 50;
+
+#[derive(serde::Deserialize, Debug)]
+struct DashAudio {
+    id: i64,
+    // 接口同时返回 baseUrl 和 base_url，只取其中一个，否则会出现重复字段
+    #[serde(rename = "baseUrl")]
+    base_url: String,
+    #[serde(rename = "backupUrl", default)]
+    backup_url: Option<Vec<String>>,
+    #[serde(default)]
+    bandwidth: u64,
+    #[serde(default)]
+    codecs: String,
+}
+
+impl DashAudio {
+    fn into_stream(self, kind: AudioKind) -> AudioStream {
+        AudioStream {
+            quality: AudioQuality {
+                id: self.id,
+                kind,
+                bandwidth: self.bandwidth,
+                codecs: self.codecs,
+            },
+            base_url: self.base_url,
+            backup_urls: self.backup_url.unwrap_or_default(),
+        }
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct DashDolby {
+    #[serde(default)]
+    audio: Option<Vec<DashAudio>>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct DashFlac {
+    #[serde(default)]
+    audio: Option<DashAudio>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct Dash {
+    #[serde(default)]
+    audio: Option<Vec<DashAudio>>,
+    // 没有杜比或无损音质时为 null
+    #[serde(default)]
+    dolby: Option<DashDolby>,
+    #[serde(default)]
+    flac: Option<DashFlac>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct PlayUrlData {
+    #[serde(default)]
+    dash: Option<Dash>,
+}
+
+/// 请求视频的所有 DASH 音频流，包括杜比全景声和 Hi-Res 无损
+pub async fn fetch_audio_streams(
     client: &Client,
+    api_base: &str,
     bvid: &str,
     cid: &str,
-) -> Result<String, ApplicationError> {
-    let url = format!("{}&bvid={}&cid={}", BASE_FETCH_AUDIO_API_URL, bvid, cid);
-    tracing::info!("Fetching audio URL...");
-    let response = client.get(&url).send().await?;
-    let json: Value = response.json().await?;
-    json["data"]["dash"]["audio"][0]["baseUrl"]
-        .as_str()
-        .map(|s| s.to_string())
-        .ok_or_else(|| ApplicationError::DataParsingError("解析音频URL失败".to_string()))
+) -> Result<Vec<AudioStream>, ApplicationError> {
+    let query = [
+        ("bvid", bvid.to_string()),
+        ("cid", cid.to_string()),
+        // 请求 DASH 格式，并包含杜比和无损音频
+        ("fnval", FETCH_AUDIO_FNVAL.to_string()),
+        ("fourk", "1".to_string()),
+    ];
+    tracing::info!("Fetching audio streams...");
+    let data: PlayUrlData = fetch_api_data(
+        client,
+        api_base,
+        FETCH_AUDIO_API_PATH,
+        &query,
+        "audio url",
+        bvid,
+    )
+    .await?;
+    let dash = data
+        .dash
+        .ok_or_else(|| ApplicationError::DataParsingError("解析音频URL失败".to_string()))?;
+    let mut streams: Vec<AudioStream> = dash
+        .audio
+        .unwrap_or_default()
+        .into_iter()
+        .map(|audio| audio.into_stream(AudioKind::Normal))
+        .collect();
+    if let Some(audios) = dash.dolby.and_then(|dolby| dolby.audio) {
+        streams.extend(
+            audios
+                .into_iter()
+                .map(|audio| audio.into_stream(AudioKind::Dolby)),
+        );
+    }
+    if let Some(audio) = dash.flac.and_then(|flac| flac.audio) {
+        streams.push(audio.into_stream(AudioKind::Flac));
+    }
+    Ok(streams)
 }
 
-#[derive(serde::Deserialize, Debug)]
+/// 请求音频流，并按偏好选择其中一个
+pub async fn fetch_audio_stream(
+    client: &Client,
+    api_base: &str,
+    bvid: &str,
+    cid: &str,
+    preference: AudioPreference,
+) -> Result<AudioStream, ApplicationError> {
+    let streams = fetch_audio_streams(client, api_base, bvid, cid).await?;
+    let stream = preference
+        .select(&streams)
+        .cloned()
+        .ok_or_else(|| ApplicationError::DataParsingError("解析音频URL失败".to_string()))?;
+    tracing::info!(
+        "Selected audio quality {} from {} streams (preference: {})",
+        stream.quality,
+        streams.len(),
+        preference
+    );
+    Ok(stream)
+}
+
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
 pub struct Owner {
     pub name: String,
 }
 
+/// 视频的一个分P
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct VideoPage {
+    pub cid: i64,
+    pub page: u32,
+    // 分P标题
+    pub part: String,
+    // 时长 (秒)
+    #[serde(default)]
+    pub duration: u64,
+}
+
 #[derive(serde::Deserialize, Debug)]
 pub struct VideoData {
     pub bvid: String,
     pub title: String,
+    // 第一P的 cid
     pub cid: i64,
     pub owner: Owner,
+    // 所有分P，单P视频只有一项
+    #[serde(default)]
+    pub pages: Vec<VideoPage>,
+}
+
+impl VideoData {
+    /// 获取所有分P，接口没有返回分P信息时以顶层 cid 作为第一P
+    pub fn parts(&self) -> Vec<VideoPage> {
+        if self.pages.is_empty() {
+            vec![VideoPage {
+                cid: self.cid,
+                page: 1,
+                part: self.title.clone(),
+                duration: 0,
+            }]
+        } else {
+            self.pages.clone()
+        }
+    }
 }
 
 #[derive(serde::Deserialize, Debug)]
 struct ApiResponse<T> {
-    data: T,
+    code: i64,
+    #[serde(default)]
+    message: String,
+    data: Option<T>,
 }
-/// 请求视频信息，获取相关数据
-pub async fn fetch_video_data(client: &Client, bvid: &str) -> Result<VideoData, ApplicationError> {
-    let url = format!("{}?bvid={}", BASE_FETCH_VIDEO_API_URL, bvid);
+/// 请求 B 站接口并取出 data 字段
+///
+/// 请求地址由 wbi::build_url 构造，/wbi/ 下的接口会自动签名，签名被拒绝时刷新密钥后重试一次
+///
+/// # 参数
+/// - api_base: 接口地址，一般为 BILIBILI_API_BASE
+/// - name: 请求的名称，用于错误信息
+/// - target: 请求的对象，如 bvid，code 不为 0 时用于错误信息
+pub(crate) async fn fetch_api_data<T: DeserializeOwned>(
+    client: &Client,
+    api_base: &str,
+    path: &str,
+    query: &[(&str, String)],
+    name: &str,
+    target: &str,
+) -> Result<T, ApplicationError> {
+    let mut api_response: ApiResponse<T> = request_api(client, api_base, path, query, name).await?;
+    if requires_wbi(path) && WBI_REJECTED_CODES.contains(&api_response.code) {
+        tracing::warn!(
+            "WBI signature rejected for {name} ({}), refreshing keys",
+            api_response.code
+        );
+        invalidate_wbi_keys(api_base).await;
+        api_response = request_api(client, api_base, path, query, name).await?;
+    }
+    // code 不为 0 时说明资源不存在或请求被拒绝，-101 表示未登录或登录已过期
+    match api_response.data {
+        Some(data) if api_response.code == 0 => Ok(data),
+        _ if api_response.code == ACCOUNT_NOT_LOGIN_CODE => Err(ApplicationError::AuthError(
+            format!("{target}: {} ({})", api_response.message, api_response.code),
+        )),
+        _ => Err(ApplicationError::NotFoundError(format!(
+            "{target}: {} ({})",
+            api_response.message, api_response.code
+        ))),
+    }
+}
+
+// 发送一次请求并解析返回的 JSON
+async fn request_api<T: DeserializeOwned>(
+    client: &Client,
+    api_base: &str,
+    path: &str,
+    query: &[(&str, String)],
+    name: &str,
+) -> Result<ApiResponse<T>, ApplicationError> {
+    let url = build_url(client, api_base, path, query).await?;
     let response = client
-        .get(&url)
+        .get(url)
+        .header(USER_AGENT, BROWSER_USER_AGENT)
+        .header("Referer", "https://www.bilibili.com")
         .send()
         .await
-        .map_err(|e| ApplicationError::FetchError(format!("Fetch video data failed:{e}")))?;
-    let mut api_response: ApiResponse<VideoData> = response
+        .map_err(|e| ApplicationError::FetchError(format!("Fetch {name} failed:{e}")))?;
+    response
         .json()
         .await
-        .map_err(|e| ApplicationError::FetchError(format!("Fetch video data failed:{e}")))?;
-    api_response.data.bvid = bvid.to_string();
-    Ok(api_response.data)
+        .map_err(|e| ApplicationError::FetchError(format!("Fetch {name} failed:{e}")))
+}
+
+/// 请求视频信息，获取相关数据
+///
+/// # 参数
+/// - api_base: 接口地址，一般为 BILIBILI_API_BASE
+pub async fn fetch_video_data(
+    client: &Client,
+    api_base: &str,
+    bvid: &str,
+) -> Result<VideoData, ApplicationError> {
+    let query = [("bvid", bvid.to_string())];
+    let mut data: VideoData = fetch_api_data(
+        client,
+        api_base,
+        FETCH_VIDEO_API_PATH,
+        &query,
+        "video data",
+        bvid,
+    )
+    .await?;
+    data.bvid = bvid.to_string();
+    Ok(data)
+}
+
+/// 收藏夹信息
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct FavoriteInfo {
+    pub id: i64,
+    pub title: String,
+    #[serde(default)]
+    pub media_count: u32,
+    pub upper: Owner,
+}
+
+/// 收藏夹中的一项
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct FavoriteMedia {
+    pub id: i64,
+    // 2 为视频，12 为音频，21 为合集
+    #[serde(rename = "type")]
+    pub media_type: i64,
+    pub title: String,
+    #[serde(default)]
+    pub bvid: String,
+    pub upper: Owner,
+    // 时长 (秒)
+    #[serde(default)]
+    pub duration: u64,
+    // 不为 0 时表示视频已失效
+    #[serde(default)]
+    pub attr: i64,
+}
+
+impl FavoriteMedia {
+    /// 是否为可以播放的视频
+    pub fn is_available(&self) -> bool {
+        self.media_type == 2 && self.attr == 0 && !self.bvid.is_empty()
+    }
+}
+
+/// 收藏夹的一页
+#[derive(serde::Deserialize, Debug)]
+pub struct FavoritePage {
+    pub info: FavoriteInfo,
+    // 收藏夹为空时接口返回 null
+    #[serde(default)]
+    pub medias: Option<Vec<FavoriteMedia>>,
+    #[serde(default)]
+    pub has_more: bool,
+}
+
+/// 完整的收藏夹
+#[derive(Debug, Clone)]
+pub struct FavoriteFolder {
+    pub info: FavoriteInfo,
+    pub medias: Vec<FavoriteMedia>,
+}
+
+/// 请求收藏夹的一页
+///
+/// # 参数
+/// - media_id: 收藏夹 id
+/// - page: 页码，从 1 开始
+pub async fn fetch_favorite_page(
+    client: &Client,
+    api_base: &str,
+    media_id: i64,
+    page: u32,
+) -> Result<FavoritePage, ApplicationError> {
+    let query = [
+        ("media_id", media_id.to_string()),
+        ("pn", page.to_string()),
+        ("ps", FAVORITE_PAGE_SIZE.to_string()),
+        ("platform", "web".to_string()),
+    ];
+    fetch_api_data(
+        client,
+        api_base,
+        FETCH_FAVORITE_API_PATH,
+        &query,
+        "favorite folder",
+        &media_id.to_string(),
+    )
+    .await
+}
+
+/// 逐页请求收藏夹中的所有内容
+pub async fn fetch_favorite_folder(
+    client: &Client,
+    api_base: &str,
+    media_id: i64,
+) -> Result<FavoriteFolder, ApplicationError> {
+    let mut page = 1;
+    let first = fetch_favorite_page(client, api_base, media_id, page).await?;
+    let info = first.info;
+    let mut medias = first.medias.unwrap_or_default();
+    let mut has_more = first.has_more;
+    while has_more {
+        page += 1;
+        let next = fetch_favorite_page(client, api_base, media_id, page).await?;
+        let next_medias = next.medias.unwrap_or_default();
+        // 防止接口异常时无限请求
+        has_more = next.has_more && !next_medias.is_empty();
+        medias.extend(next_medias);
+    }
+    tracing::info!(
+        "Fetched {} items from favorite folder {} ({})",
+        medias.len(),
+        info.title,
+        media_id
+    );
+    Ok(FavoriteFolder { info, medias })
+}
+
+/// 合集或 UP 主投稿列表中的一个视频
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideoSummary {
+    pub bvid: String,
+    pub title: String,
+    pub author: String,
+    // 时长 (秒)
+    pub duration: u64,
+}
+
+/// 批量导入时过滤视频的条件，用来跳过非音乐的投稿
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct VideoFilter {
+    // 标题中包含的关键字，不区分大小写
+    pub keyword: Option<String>,
+    // 最短时长 (秒)
+    pub min_duration: Option<u64>,
+    // 最长时长 (秒)
+    pub max_duration: Option<u64>,
+}
+
+impl VideoFilter {
+    /// 视频是否满足过滤条件
+    pub fn matches(&self, video: &VideoSummary) -> bool {
+        let keyword_matched = self
+            .keyword
+            .as_deref()
+            .map(str::trim)
+            .filter(|keyword| !keyword.is_empty())
+            .is_none_or(|keyword| video.title.to_lowercase().contains(&keyword.to_lowercase()));
+        keyword_matched
+            && self.min_duration.is_none_or(|min| video.duration >= min)
+            && self.max_duration.is_none_or(|max| video.duration <= max)
+    }
+}
+
+impl From<crate::pb::VideoFilter> for VideoFilter {
+    fn from(filter: crate::pb::VideoFilter) -> Self {
+        // 0 和空字符串表示不限制
+        VideoFilter {
+            keyword: Some(filter.keyword).filter(|keyword| !keyword.trim().is_empty()),
+            min_duration: Some(filter.min_duration as u64).filter(|min| *min > 0),
+            max_duration: Some(filter.max_duration as u64).filter(|max| *max > 0),
+        }
+    }
+}
+
+/// 合集信息
+#[derive(serde::Deserialize, Debug, Clone, PartialEq)]
+pub struct SeasonMeta {
+    pub season_id: i64,
+    pub name: String,
+    #[serde(default)]
+    pub total: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct SeasonArchive {
+    bvid: String,
+    title: String,
+    #[serde(default)]
+    duration: u64,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct SeasonPageInfo {
+    page_num: u32,
+    page_size: u32,
+    total: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct SeasonPage {
+    meta: SeasonMeta,
+    // 合集为空时接口返回 null
+    #[serde(default)]
+    archives: Option<Vec<SeasonArchive>>,
+    page: SeasonPageInfo,
+}
+
+/// 逐页请求合集中的视频，只返回满足过滤条件的视频
+///
+/// # 参数
+/// - mid: 合集所属 UP 主的 mid
+/// - season_id: 合集 id
+pub async fn fetch_season_videos(
+    client: &Client,
+    api_base: &str,
+    mid: i64,
+    season_id: i64,
+    filter: &VideoFilter,
+) -> Result<(SeasonMeta, Vec<VideoSummary>), ApplicationError> {
+    let mut page_num = 1;
+    let mut videos = Vec::new();
+    loop {
+        let query = [
+            ("mid", mid.to_string()),
+            ("season_id", season_id.to_string()),
+            ("page_num", page_num.to_string()),
+            ("page_size", SEASON_PAGE_SIZE.to_string()),
+        ];
+        let page: SeasonPage = fetch_api_data(
+            client,
+            api_base,
+            FETCH_SEASON_API_PATH,
+            &query,
+            "season",
+            &season_id.to_string(),
+        )
+        .await?;
+        let archives = page.archives.unwrap_or_default();
+        let is_last =
+            archives.is_empty() || page.page.page_num * page.page.page_size >= page.page.total;
+        videos.extend(
+            archives
+                .into_iter()
+                .map(|archive| VideoSummary {
+                    bvid: archive.bvid,
+                    title: archive.title,
+                    author: String::new(),
+                    duration: archive.duration,
+                })
+                .filter(|video| filter.matches(video)),
+        );
+        if is_last {
+            tracing::info!(
+                "Fetched {} videos from season {} ({})",
+                videos.len(),
+                page.meta.name,
+                season_id
+            );
+            return Ok((page.meta, videos));
+        }
+        page_num += 1;
+    }
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct UploaderVideo {
+    bvid: String,
+    title: String,
+    #[serde(default)]
+    author: String,
+    // 时长，格式为 3:45 或 1:02:03
+    #[serde(default)]
+    length: String,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct UploaderVideoList {
+    #[serde(default)]
+    vlist: Vec<UploaderVideo>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct UploaderPageInfo {
+    pn: u32,
+    ps: u32,
+    count: u32,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct UploaderPage {
+    list: UploaderVideoList,
+    page: UploaderPageInfo,
+}
+
+/// 逐页请求 UP 主的投稿，只返回满足过滤条件的视频
+///
+/// # 参数
+/// - mid: UP 主的 mid
+/// - max_pages: 最多请求的页数，为 0 时请求所有投稿
+pub async fn fetch_uploader_videos(
+    client: &Client,
+    api_base: &str,
+    mid: i64,
+    filter: &VideoFilter,
+    max_pages: u32,
+) -> Result<Vec<VideoSummary>, ApplicationError> {
+    let keyword = filter.keyword.as_deref().unwrap_or_default().trim();
+    let mut pn = 1;
+    let mut videos = Vec::new();
+    loop {
+        let query = [
+            ("mid", mid.to_string()),
+            ("pn", pn.to_string()),
+            ("ps", UPLOADER_PAGE_SIZE.to_string()),
+            // 关键字先交给接口过滤，减少请求的页数
+            ("keyword", keyword.to_string()),
+            ("order", "pubdate".to_string()),
+        ];
+        let page: UploaderPage = fetch_api_data(
+            client,
+            api_base,
+            FETCH_UPLOADER_API_PATH,
+            &query,
+            "uploader videos",
+            &mid.to_string(),
+        )
+        .await?;
+        let is_last = page.list.vlist.is_empty()
+            || page.page.pn * page.page.ps >= page.page.count
+            || (max_pages > 0 && pn >= max_pages);
+        videos.extend(
+            page.list
+                .vlist
+                .into_iter()
+                .map(|video| VideoSummary {
+                    duration: parse_length(&video.length),
+                    bvid: video.bvid,
+                    title: video.title,
+                    author: video.author,
+                })
+                .filter(|video| filter.matches(video)),
+        );
+        if is_last {
+            tracing::info!("Fetched {} videos from uploader {}", videos.len(), mid);
+            return Ok(videos);
+        }
+        pn += 1;
+    }
+}
+
+/// 解析 3:45、1:02:03 形式的时长，无法解析时返回 0
+pub fn parse_length(length: &str) -> u64 {
+    length
+        .trim()
+        .split(':')
+        .try_fold(0u64, |seconds, part| {
+            part.trim()
+                .parse::<u64>()
+                .ok()
+                .map(|value| seconds * 60 + value)
+        })
+        .unwrap_or_default()
+}
+
+/// 搜索结果中的一个视频
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchResult {
+    pub bvid: String,
+    pub title: String,
+    // UP 主名称
+    pub author: String,
+    // 时长 (秒)
+    pub duration: u64,
+    // 播放次数
+    pub play: u64,
+}
+
+impl From<SearchResult> for crate::pb::SearchResult {
+    fn from(result: SearchResult) -> Self {
+        crate::pb::SearchResult {
+            bvid: result.bvid,
+            title: result.title,
+            author: result.author,
+            duration: result.duration,
+            play: result.play,
+        }
+    }
+}
+
+/// 搜索结果的一页
+#[derive(Debug, Clone, PartialEq)]
+pub struct SearchPage {
+    pub page: u32,
+    pub num_pages: u32,
+    pub num_results: u32,
+    pub results: Vec<SearchResult>,
+}
+
+#[derive(serde::Deserialize, Debug)]
+struct RawSearchResult {
+    #[serde(default)]
+    bvid: String,
+    // 标题中的关键字带有 <em class="keyword"> 标签
+    title: String,
+    #[serde(default)]
+    author: String,
+    // 时长，格式为 4:01
+    #[serde(default)]
+    duration: String,
+    // 播放次数，偶尔为 "--"
+    #[serde(default)]
+    play: Value,
+}
+
+#[derive(serde::Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct RawSearchPage {
+    page: u32,
+    #[serde(default)]
+    num_pages: u32,
+    #[serde(default)]
+    num_results: u32,
+    // 没有结果时接口不返回该字段
+    #[serde(default)]
+    result: Vec<RawSearchResult>,
+}
+
+/// 按关键字搜索视频
+///
+/// # 参数
+/// - keyword: 搜索关键字
+/// - page: 页码，从 1 开始
+pub async fn search_videos(
+    client: &Client,
+    api_base: &str,
+    keyword: &str,
+    page: u32,
+) -> Result<SearchPage, ApplicationError> {
+    let keyword = keyword.trim();
+    if keyword.is_empty() {
+        return Err(ApplicationError::InvalidArgumentError(
+            "搜索关键字不能为空".to_string(),
+        ));
+    }
+    let query = [
+        ("search_type", "video".to_string()),
+        ("keyword", keyword.to_string()),
+        ("page", page.max(1).to_string()),
+    ];
+    let raw: RawSearchPage =
+        fetch_api_data(client, api_base, SEARCH_API_PATH, &query, "search", keyword).await?;
+    let results = raw
+        .result
+        .into_iter()
+        .filter(|result| !result.bvid.is_empty())
+        .map(|result| SearchResult {
+            title: clean_title(&result.title),
+            duration: parse_length(&result.duration),
+            play: match &result.play {
+                Value::Number(play) => play.as_u64().unwrap_or_default(),
+                Value::String(play) => play.parse().unwrap_or_default(),
+                _ => 0,
+            },
+            bvid: result.bvid,
+            author: result.author,
+        })
+        .collect();
+    Ok(SearchPage {
+        page: raw.page,
+        num_pages: raw.num_pages,
+        num_results: raw.num_results,
+        results,
+    })
+}
+
+/// 去掉搜索结果标题中的高亮标签，并还原 HTML 转义字符
+pub fn clean_title(title: &str) -> String {
+    let mut text = String::with_capacity(title.len());
+    let mut in_tag = false;
+    for c in title.chars() {
+        match c {
+            '<' => in_tag = true,
+            '>' if in_tag => in_tag = false,
+            _ if !in_tag => text.push(c),
+            _ => {}
+        }
+    }
+    // &amp; 最后替换，避免 &amp;lt; 被还原两次
+    text.replace("&lt;", "<")
+        .replace("&gt;", ">")
+        .replace("&quot;", "\"")
+        .replace("&#39;", "'")
+        .replace("&amp;", "&")
 }
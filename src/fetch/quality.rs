@@ -0,0 +1,227 @@
+use std::{fmt, str::FromStr, sync::RwLock};
+
+use once_cell::sync::Lazy;
+
+use crate::errors::ApplicationError;
+
+// 当前使用的音质偏好，启动时从环境变量 AUDIO_QUALITY 读取
+pub static AUDIO_PREFERENCE: Lazy<RwLock<AudioPreference>> =
+    Lazy::new(|| RwLock::new(AudioPreference::default()));
+
+/// 音频流的类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AudioKind {
+    #[default]
+    Normal, // 普通音质 64K/132K/192K
+    Dolby, // 杜比全景声
+    Flac,  // Hi-Res 无损
+}
+
+/// 音频流的音质信息
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct AudioQuality {
+    // 音质 id，如 30280 为 192K
+    pub id: i64,
+    pub kind: AudioKind,
+    // 码率 (bps)
+    pub bandwidth: u64,
+    // 编码，如 mp4a.40.2、ec-3、fLaC
+    pub codecs: String,
+}
+
+impl AudioQuality {
+    /// 标称码率 (kbps)，未知的音质 id 按实际码率计算
+    pub fn kbps(&self) -> u64 {
+        match self.id {
+            30216 => 64,
+            30232 => 132,
+            30280 => 192,
+            _ => self.bandwidth / 1000,
+        }
+    }
+    /// 音质名称，如 192K、杜比全景声
+    pub fn label(&self) -> String {
+        match self.kind {
+            AudioKind::Normal => format!("{}K", self.kbps()),
+            AudioKind::Dolby => "杜比全景声".to_string(),
+            AudioKind::Flac => "Hi-Res 无损".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for AudioQuality {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} ({})", self.label(), self.codecs)
+    }
+}
+
+impl From<AudioQuality> for crate::pb::AudioQuality {
+    fn from(quality: AudioQuality) -> Self {
+        crate::pb::AudioQuality {
+            label: quality.label(),
+            id: quality.id,
+            bandwidth: quality.bandwidth,
+            codecs: quality.codecs,
+        }
+    }
+}
+
+/// DASH 中的一个音频流
+#[derive(Debug, Clone, PartialEq)]
+pub struct AudioStream {
+    pub quality: AudioQuality,
+    pub base_url: String,
+    // 备用地址，主地址不可用时使用
+    pub backup_urls: Vec<String>,
+}
+
+impl AudioStream {
+    /// 所有可用的地址，主地址在前
+    pub fn urls(&self) -> impl Iterator<Item = &str> {
+        std::iter::once(self.base_url.as_str()).chain(self.backup_urls.iter().map(String::as_str))
+    }
+    /// 当前地址的过期时间 (Unix 时间戳，秒)
+    pub fn deadline(&self) -> Option<u64> {
+        url_deadline(&self.base_url)
+    }
+    /// 当前地址是否会在 now 之后 margin 秒内过期，没有过期时间的地址视为不会过期
+    pub fn expires_within(&self, now: u64, margin: u64) -> bool {
+        self.deadline()
+            .is_some_and(|deadline| now.saturating_add(margin) >= deadline)
+    }
+    /// 主地址不可用时切换到下一个备用地址，没有备用地址时返回 None
+    pub fn failover(&mut self) -> Option<&str> {
+        if self.backup_urls.is_empty() {
+            return None;
+        }
+        self.base_url = self.backup_urls.remove(0);
+        Some(&self.base_url)
+    }
+}
+
+/// 从音频地址的 deadline 参数中解析过期时间 (Unix 时间戳，秒)
+pub fn url_deadline(url: &str) -> Option<u64> {
+    let url = reqwest::Url::parse(url).ok()?;
+    url.query_pairs()
+        .find(|(key, _)| key == "deadline")
+        .and_then(|(_, value)| value.parse().ok())
+}
+
+/// 选择音频流的偏好
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum AudioPreference {
+    #[default]
+    Highest, // 码率最高的音频流，包括无损和杜比
+    PreferFlac,  // 有无损时使用无损，否则使用码率最高的普通音质
+    PreferDolby, // 有杜比全景声时使用杜比，否则使用码率最高的普通音质
+    Cap(u64),    // 不超过指定码率 (kbps) 的普通音质，都超过时使用最低的
+}
+
+impl AudioPreference {
+    /// 按偏好从所有音频流中选择一个，没有音频流时返回 None
+    pub fn select<'a>(&self, streams: &'a [AudioStream]) -> Option<&'a AudioStream> {
+        let highest_of = |kind: AudioKind| {
+            streams
+                .iter()
+                .filter(|stream| stream.quality.kind == kind)
+                .max_by_key(|stream| stream.quality.bandwidth)
+        };
+        let highest = || streams.iter().max_by_key(|stream| stream.quality.bandwidth);
+        match *self {
+            AudioPreference::Highest => highest(),
+            AudioPreference::PreferFlac => highest_of(AudioKind::Flac)
+                .or_else(|| highest_of(AudioKind::Normal))
+                .or_else(highest),
+            AudioPreference::PreferDolby => highest_of(AudioKind::Dolby)
+                .or_else(|| highest_of(AudioKind::Normal))
+                .or_else(highest),
+            AudioPreference::Cap(kbps) => {
+                let normal: Vec<&AudioStream> = streams
+                    .iter()
+                    .filter(|stream| stream.quality.kind == AudioKind::Normal)
+                    .collect();
+                let candidates = if normal.is_empty() {
+                    streams.iter().collect()
+                } else {
+                    normal
+                };
+                candidates
+                    .iter()
+                    .filter(|stream| stream.quality.kbps() <= kbps)
+                    .max_by_key(|stream| stream.quality.bandwidth)
+                    .or_else(|| {
+                        candidates
+                            .iter()
+                            .min_by_key(|stream| stream.quality.bandwidth)
+                    })
+                    .copied()
+            }
+        }
+    }
+}
+
+impl FromStr for AudioPreference {
+    type Err = ApplicationError;
+
+    /// 支持 highest、flac、dolby，以及 132k、cap:132k 形式的码率上限
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let value = s.trim().to_lowercase();
+        match value.as_str() {
+            "" | "highest" | "best" => Ok(AudioPreference::Highest),
+            "flac" | "lossless" | "hires" => Ok(AudioPreference::PreferFlac),
+            "dolby" => Ok(AudioPreference::PreferDolby),
+            _ => {
+                let kbps = value
+                    .strip_prefix("cap:")
+                    .or_else(|| value.strip_prefix("max:"))
+                    .unwrap_or(&value)
+                    .trim()
+                    .trim_end_matches('k');
+                kbps.parse::<u64>()
+                    .ok()
+                    .filter(|kbps| *kbps > 0)
+                    .map(AudioPreference::Cap)
+                    .ok_or_else(|| {
+                        ApplicationError::DataParsingError(format!(
+                            "无效的音质偏好: {s}，可选 highest、flac、dolby 或码率上限如 132k"
+                        ))
+                    })
+            }
+        }
+    }
+}
+
+impl fmt::Display for AudioPreference {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AudioPreference::Highest => write!(f, "highest"),
+            AudioPreference::PreferFlac => write!(f, "flac"),
+            AudioPreference::PreferDolby => write!(f, "dolby"),
+            AudioPreference::Cap(kbps) => write!(f, "{kbps}k"),
+        }
+    }
+}
+
+/// 从环境变量 AUDIO_QUALITY 中读取音质偏好，未设置时使用最高码率
+pub fn audio_preference() -> Result<AudioPreference, ApplicationError> {
+    dotenvy::dotenv().ok();
+    match std::env::var("AUDIO_QUALITY") {
+        Ok(value) => value.parse(),
+        Err(_) => Ok(AudioPreference::default()),
+    }
+}
+
+/// 获取当前的音质偏好
+pub fn current_audio_preference() -> AudioPreference {
+    AUDIO_PREFERENCE
+        .read()
+        .map(|preference| *preference)
+        .unwrap_or_default()
+}
+
+/// 设置音质偏好，之后播放的歌曲生效
+pub fn set_audio_preference(preference: AudioPreference) {
+    if let Ok(mut current) = AUDIO_PREFERENCE.write() {
+        *current = preference;
+    }
+}
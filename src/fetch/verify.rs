@@ -4,7 +4,13 @@ use reqwest::{
 };
 use tokio::time::{Duration, sleep};
 
-use crate::{errors::ApplicationError, fetch::network::fetch_audio_url};
+use crate::{
+    errors::ApplicationError,
+    fetch::{
+        network::fetch_audio_stream,
+        quality::{AudioPreference, AudioStream},
+    },
+};
 
 /// 验证音频 URL 是否可用
 pub async fn verify_audio_url(client: &Client, url: &str) -> Result<bool, ApplicationError> {
@@ -21,12 +27,17 @@ pub async fn verify_audio_url(client: &Client, url: &str) -> Result<bool, Applic
     Ok(response.status().is_success())
 }
 
-/// 请求并验证音频 URL 是否可用，如果不可用则重试 3 次
+/// 按音质偏好请求并验证音频 URL 是否可用
+///
+/// 主地址不可用时依次尝试备用地址，返回的音频流以可用的地址为主地址；
+/// 所有地址都不可用时重新请求接口，最多重试 3 次
 pub async fn fetch_and_verify_audio_url(
     client: &Client,
+    api_base: &str,
     bvid: &str,
     cid: &str,
-) -> Result<String, ApplicationError> {
+    preference: AudioPreference,
+) -> Result<AudioStream, ApplicationError> {
     // 最大重试次数
     const MAX_RETRIES: u32 = 3;
     // 最初的重试延迟
@@ -35,14 +46,21 @@ pub async fn fetch_and_verify_audio_url(
     let mut retry_delay = INITIAL_RETRY_DELAY;
 
     for attempt in 1..=MAX_RETRIES {
-        match fetch_audio_url(client, bvid, cid).await {
-            Ok(url) => match verify_audio_url(client, &url).await {
-                Ok(true) => return Ok(url),
-                Ok(false) => {
-                    tracing::info!("Verification failed for URL: {}", url);
+        match fetch_audio_stream(client, api_base, bvid, cid, preference).await {
+            // 依次验证主地址和备用地址，都不可用时再重新请求接口
+            Ok(mut stream) => loop {
+                match verify_audio_url(client, &stream.base_url).await {
+                    Ok(true) => return Ok(stream),
+                    Ok(false) => {
+                        tracing::info!("Verification failed for URL: {}", stream.base_url);
+                    }
+                    Err(e) => {
+                        tracing::error!("Error verifying URL: {}", e);
+                    }
                 }
-                Err(e) => {
-                    tracing::error!("Error verifying URL: {}", e);
+                match stream.failover() {
+                    Some(url) => tracing::info!("Trying backup URL: {}", url),
+                    None => break,
                 }
             },
             Err(e) => {
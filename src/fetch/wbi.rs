@@ -0,0 +1,182 @@
+use std::{
+    collections::HashMap,
+    time::{Duration, Instant},
+};
+
+use once_cell::sync::Lazy;
+use reqwest::{Client, header::USER_AGENT};
+use serde::Deserialize;
+use tokio::sync::Mutex;
+
+use crate::{errors::ApplicationError, fetch::network::BROWSER_USER_AGENT, utils::unix_now};
+
+// 获取 img_key 和 sub_key 的接口，未登录时也会返回
+const WBI_KEYS_API_PATH: &str = "/x/web-interface/nav";
+// img_key 和 sub_key 每天更换，缓存一段时间后重新获取
+const WBI_KEYS_TTL: Duration = Duration::from_secs(60 * 60);
+// 打乱 img_key + sub_key 的固定顺序表
+const MIXIN_KEY_ENC_TAB: [usize; 64] = [
+    46, 47, 18, 2, 53, 8, 23, 32, 15, 50, 10, 31, 58, 3, 45, 35, 27, 43, 5, 49, 33, 9, 42, 19, 29,
+    28, 14, 39, 12, 38, 41, 13, 37, 48, 7, 16, 24, 55, 40, 61, 26, 17, 0, 1, 60, 51, 30, 4, 22, 25,
+    54, 21, 56, 59, 6, 63, 57, 62, 11, 36, 20, 34, 44, 52,
+];
+// 签名前需要从参数值中去掉的字符
+const WBI_FILTERED_CHARS: &[char] = &['!', '\'', '(', ')', '*'];
+
+// 按接口地址缓存的 WBI 密钥，测试时每个模拟服务互不影响
+static WBI_KEYS: Lazy<Mutex<HashMap<String, (WbiKeys, Instant)>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// WBI 签名用的 img_key 和 sub_key
+#[derive(Debug, Clone, PartialEq)]
+pub struct WbiKeys {
+    pub img_key: String,
+    pub sub_key: String,
+}
+
+impl WbiKeys {
+    /// 从 wbi_img 中的图片地址取出密钥，即去掉扩展名的文件名
+    pub fn from_urls(img_url: &str, sub_url: &str) -> Option<Self> {
+        let key = |url: &str| {
+            let name = url.rsplit('/').next()?;
+            let key = name.split('.').next()?;
+            (!key.is_empty()).then(|| key.to_string())
+        };
+        Some(WbiKeys {
+            img_key: key(img_url)?,
+            sub_key: key(sub_url)?,
+        })
+    }
+
+    /// 按顺序表打乱 img_key + sub_key，取前 32 个字符
+    pub fn mixin_key(&self) -> String {
+        let raw: Vec<char> = format!("{}{}", self.img_key, self.sub_key)
+            .chars()
+            .collect();
+        MIXIN_KEY_ENC_TAB
+            .iter()
+            .filter_map(|&i| raw.get(i))
+            .take(32)
+            .collect()
+    }
+}
+
+/// 路径中带有 /wbi/ 的接口需要签名
+pub fn requires_wbi(path: &str) -> bool {
+    path.contains("/wbi/")
+}
+
+/// 编码查询参数，和 encodeURIComponent 一致，空格编码为 %20
+pub fn encode_query(params: &[(&str, String)]) -> String {
+    params
+        .iter()
+        .map(|(key, value)| format!("{}={}", encode_component(key), encode_component(value)))
+        .collect::<Vec<_>>()
+        .join("&")
+}
+
+/// 签名查询参数，返回带有 wts 和 w_rid 的查询字符串
+///
+/// 参数加上 wts 后按名称排序，去掉值中的 !'()* 后编码，再拼接 mixin_key 计算 md5 作为 w_rid
+pub fn sign_query(params: &[(&str, String)], mixin_key: &str, wts: u64) -> String {
+    let mut params: Vec<(&str, String)> = params
+        .iter()
+        .map(|(key, value)| (*key, value.replace(WBI_FILTERED_CHARS, "")))
+        .collect();
+    params.push(("wts", wts.to_string()));
+    params.sort_by(|a, b| a.0.cmp(b.0));
+    let query = encode_query(&params);
+    let w_rid = md5::compute(format!("{query}{mixin_key}"));
+    format!("{query}&w_rid={w_rid:x}")
+}
+
+/// 构造请求地址，需要签名的接口自动加上 wts 和 w_rid
+pub async fn build_url(
+    client: &Client,
+    api_base: &str,
+    path: &str,
+    params: &[(&str, String)],
+) -> Result<String, ApplicationError> {
+    let query = if requires_wbi(path) {
+        let keys = wbi_keys(client, api_base).await?;
+        sign_query(params, &keys.mixin_key(), unix_now())
+    } else {
+        encode_query(params)
+    };
+    if query.is_empty() {
+        Ok(format!("{api_base}{path}"))
+    } else {
+        Ok(format!("{api_base}{path}?{query}"))
+    }
+}
+
+/// 获取 WBI 密钥，缓存过期后重新请求
+pub async fn wbi_keys(client: &Client, api_base: &str) -> Result<WbiKeys, ApplicationError> {
+    let mut cache = WBI_KEYS.lock().await;
+    if let Some((keys, fetched_at)) = cache.get(api_base)
+        && fetched_at.elapsed() < WBI_KEYS_TTL
+    {
+        return Ok(keys.clone());
+    }
+    let keys = fetch_wbi_keys(client, api_base).await?;
+    tracing::info!("WBI keys refreshed");
+    cache.insert(api_base.to_string(), (keys.clone(), Instant::now()));
+    Ok(keys)
+}
+
+/// 签名被拒绝时清除缓存的密钥，下次请求时重新获取
+pub async fn invalidate_wbi_keys(api_base: &str) {
+    WBI_KEYS.lock().await.remove(api_base);
+}
+
+#[derive(Debug, Deserialize)]
+struct WbiImg {
+    img_url: String,
+    sub_url: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct NavData {
+    wbi_img: WbiImg,
+}
+
+#[derive(Debug, Deserialize)]
+struct NavResponse {
+    data: Option<NavData>,
+}
+
+// 请求 nav 接口中的 wbi_img，未登录时 code 为 -101 但仍然有 data
+async fn fetch_wbi_keys(client: &Client, api_base: &str) -> Result<WbiKeys, ApplicationError> {
+    let url = format!("{api_base}{WBI_KEYS_API_PATH}");
+    let response: NavResponse = client
+        .get(&url)
+        .header(USER_AGENT, BROWSER_USER_AGENT)
+        .header("Referer", "https://www.bilibili.com")
+        .send()
+        .await
+        .map_err(|e| ApplicationError::FetchError(format!("Fetch wbi keys failed:{e}")))?
+        .json()
+        .await
+        .map_err(|e| ApplicationError::FetchError(format!("Fetch wbi keys failed:{e}")))?;
+    let wbi_img = response
+        .data
+        .ok_or_else(|| ApplicationError::DataParsingError("nav 接口没有返回 wbi_img".into()))?
+        .wbi_img;
+    WbiKeys::from_urls(&wbi_img.img_url, &wbi_img.sub_url).ok_or_else(|| {
+        ApplicationError::DataParsingError(format!("无效的 wbi_img: {}", wbi_img.img_url))
+    })
+}
+
+// 除了字母、数字和 -_.~ 以外的字符都按 UTF-8 编码为 %XX
+fn encode_component(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{byte:02X}")),
+        }
+    }
+    encoded
+}
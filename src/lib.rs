@@ -1,5 +1,6 @@
 pub mod errors;
 pub mod fetch;
+pub mod library;
 pub mod logger;
 pub mod pb;
 pub mod player;
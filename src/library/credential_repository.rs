@@ -0,0 +1,52 @@
+use sqlx::SqlitePool;
+
+use crate::{errors::ApplicationError, fetch::auth::Credentials};
+
+/// 登录凭据库，保存扫码或导入的 B 站登录凭据
+#[derive(Clone, Debug)]
+pub struct CredentialRepository {
+    pool: SqlitePool,
+}
+
+impl CredentialRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 读取保存的登录凭据，没有登录过时返回 None
+    pub async fn load(&self) -> Result<Option<Credentials>, ApplicationError> {
+        let credentials = sqlx::query_as::<_, Credentials>(
+            "SELECT sessdata, bili_jct, dede_user_id FROM credentials WHERE id = 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(credentials)
+    }
+
+    /// 保存登录凭据，覆盖之前的凭据
+    pub async fn save(&self, credentials: &Credentials) -> Result<(), ApplicationError> {
+        sqlx::query(
+            "INSERT INTO credentials (id, sessdata, bili_jct, dede_user_id)
+             VALUES (1, ?, ?, ?)
+             ON CONFLICT (id) DO UPDATE SET
+                 sessdata = excluded.sessdata,
+                 bili_jct = excluded.bili_jct,
+                 dede_user_id = excluded.dede_user_id,
+                 updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(&credentials.sessdata)
+        .bind(&credentials.bili_jct)
+        .bind(&credentials.dede_user_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+
+    /// 删除登录凭据
+    pub async fn clear(&self) -> Result<(), ApplicationError> {
+        sqlx::query("DELETE FROM credentials WHERE id = 1")
+            .execute(&self.pool)
+            .await?;
+        Ok(())
+    }
+}
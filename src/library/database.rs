@@ -0,0 +1,36 @@
+use std::str::FromStr;
+
+use sqlx::{
+    SqlitePool,
+    migrate::Migrator,
+    sqlite::{SqliteConnectOptions, SqlitePoolOptions},
+};
+
+use crate::errors::ApplicationError;
+
+/// 数据库迁移脚本，编译时从 migrations 目录中读取
+pub static MIGRATOR: Migrator = sqlx::migrate!("./migrations");
+
+/// 从环境变量中读取数据库地址，优先加载 .env 文件
+pub fn database_url() -> Result<String, ApplicationError> {
+    dotenvy::dotenv().ok();
+    std::env::var("DATABASE_URL")
+        .map_err(|_| ApplicationError::DatabaseError("DATABASE_URL 未设置".to_string()))
+}
+
+/// 连接数据库并执行迁移
+///
+/// # 参数
+/// - database_url: 数据库地址，如 `sqlite:musics_data.db`
+pub async fn init_database(database_url: &str) -> Result<SqlitePool, ApplicationError> {
+    // 数据库文件不存在时自动创建
+    let options = SqliteConnectOptions::from_str(database_url)?.create_if_missing(true);
+    let pool = SqlitePoolOptions::new()
+        .max_connections(5)
+        .connect_with(options)
+        .await?;
+    // 启动时自动执行迁移
+    MIGRATOR.run(&pool).await?;
+    tracing::info!("Database migrations applied successfully.");
+    Ok(pool)
+}
@@ -0,0 +1,132 @@
+use sqlx::{SqlitePool, types::chrono::NaiveDateTime};
+
+use crate::{
+    errors::ApplicationError,
+    player::equalizer::{BandGains, format_gains, parse_gains},
+};
+
+/// equalizer_presets 表中的一行数据
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct EqualizerPresetRecord {
+    pub id: i64,
+    pub name: String,
+    // 逗号分隔的频段增益 (dB)
+    pub gains: String,
+    pub is_builtin: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+impl EqualizerPresetRecord {
+    /// 解析各频段的增益
+    pub fn band_gains(&self) -> Result<BandGains, ApplicationError> {
+        parse_gains(&self.gains)
+    }
+}
+
+impl TryFrom<EqualizerPresetRecord> for crate::pb::EqualizerPreset {
+    type Error = ApplicationError;
+
+    fn try_from(record: EqualizerPresetRecord) -> Result<Self, Self::Error> {
+        Ok(crate::pb::EqualizerPreset {
+            gains: record.band_gains()?.to_vec(),
+            name: record.name,
+            builtin: record.is_builtin,
+        })
+    }
+}
+
+/// 均衡器预设库，负责 equalizer_presets 表的增删改查
+#[derive(Clone, Debug)]
+pub struct EqualizerRepository {
+    pool: SqlitePool,
+}
+
+impl EqualizerRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 列出所有预设，内置预设在前
+    pub async fn list(&self) -> Result<Vec<EqualizerPresetRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, EqualizerPresetRecord>(
+            "SELECT * FROM equalizer_presets ORDER BY is_builtin DESC, id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+
+    /// 按名称查找预设
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<EqualizerPresetRecord>, ApplicationError> {
+        let record = sqlx::query_as::<_, EqualizerPresetRecord>(
+            "SELECT * FROM equalizer_presets WHERE name = ?",
+        )
+        .bind(name.trim())
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 按名称查找预设，不存在时返回错误
+    pub async fn get_by_name(&self, name: &str) -> Result<EqualizerPresetRecord, ApplicationError> {
+        self.find_by_name(name).await?.ok_or_else(|| {
+            ApplicationError::NotFoundError(format!("均衡器预设 {} 不存在", name.trim()))
+        })
+    }
+
+    /// 保存自定义预设，已存在时覆盖其中的增益，内置预设不能修改
+    pub async fn save(
+        &self,
+        name: &str,
+        gains: &BandGains,
+    ) -> Result<EqualizerPresetRecord, ApplicationError> {
+        let name = check_name(name)?;
+        if let Some(record) = self.find_by_name(name).await?
+            && record.is_builtin
+        {
+            return Err(ApplicationError::StateError(format!(
+                "内置预设 {name} 不能修改"
+            )));
+        }
+        let record = sqlx::query_as::<_, EqualizerPresetRecord>(
+            "INSERT INTO equalizer_presets (name, gains) VALUES (?, ?)
+             ON CONFLICT (name) DO UPDATE SET gains = excluded.gains
+             RETURNING *",
+        )
+        .bind(name)
+        .bind(format_gains(gains))
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 删除自定义预设，内置预设不能删除
+    pub async fn delete(&self, name: &str) -> Result<EqualizerPresetRecord, ApplicationError> {
+        let record = self.get_by_name(name).await?;
+        if record.is_builtin {
+            return Err(ApplicationError::StateError(format!(
+                "内置预设 {} 不能删除",
+                record.name
+            )));
+        }
+        sqlx::query("DELETE FROM equalizer_presets WHERE id = ?")
+            .bind(record.id)
+            .execute(&self.pool)
+            .await?;
+        Ok(record)
+    }
+}
+
+fn check_name(name: &str) -> Result<&str, ApplicationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ApplicationError::InvalidArgumentError(
+            "预设名称不能为空".to_string(),
+        ));
+    }
+    Ok(name)
+}
@@ -0,0 +1,435 @@
+use std::{collections::HashSet, fmt, str::FromStr};
+
+use reqwest::Client;
+
+use crate::{
+    errors::ApplicationError,
+    fetch::network::{
+        FavoriteInfo, VideoFilter, VideoPage, VideoSummary, fetch_favorite_folder,
+        fetch_season_videos, fetch_uploader_videos, fetch_video_data,
+    },
+    library::{
+        playlist_repository::{PlaylistRecord, PlaylistRepository, PlaylistSummary},
+        repository::MusicRepository,
+    },
+    player::state::Music,
+};
+
+/// 导入多P视频时选择的分P
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PageSelection {
+    #[default]
+    First, // 只导入第一P
+    Single(u32),     // 导入指定的一P
+    Range(u32, u32), // 导入一个范围内的分P，包含两端
+    All,             // 导入所有分P
+}
+
+impl PageSelection {
+    /// 分P序号是否在选择范围内
+    pub fn contains(&self, page: u32) -> bool {
+        match *self {
+            PageSelection::First => page == 1,
+            PageSelection::Single(single) => page == single,
+            PageSelection::Range(start, end) => (start..=end).contains(&page),
+            PageSelection::All => true,
+        }
+    }
+    /// 从视频的所有分P中选出要导入的部分
+    pub fn select(&self, parts: &[VideoPage]) -> Result<Vec<VideoPage>, ApplicationError> {
+        let selected: Vec<VideoPage> = match self {
+            PageSelection::First => parts.iter().take(1).cloned().collect(),
+            _ => parts
+                .iter()
+                .filter(|part| self.contains(part.page))
+                .cloned()
+                .collect(),
+        };
+        if selected.is_empty() {
+            return Err(ApplicationError::NotFoundError(format!(
+                "分P {} 不存在，该视频共 {}P",
+                self,
+                parts.len()
+            )));
+        }
+        Ok(selected)
+    }
+}
+
+impl FromStr for PageSelection {
+    type Err = ApplicationError;
+
+    /// 支持 ""、"3"、"2-5"、"all" 几种格式
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let parse_page = |page: &str| {
+            page.trim()
+                .parse::<u32>()
+                .ok()
+                .filter(|page| *page > 0)
+                .ok_or_else(|| ApplicationError::InvalidArgumentError(format!("无效的分P: {s}")))
+        };
+        match s.to_lowercase().as_str() {
+            "" => Ok(PageSelection::First),
+            "all" | "*" => Ok(PageSelection::All),
+            _ => match s.split_once('-') {
+                Some((start, end)) => {
+                    let (start, end) = (parse_page(start)?, parse_page(end)?);
+                    if start > end {
+                        return Err(ApplicationError::InvalidArgumentError(format!(
+                            "无效的分P范围: {s}"
+                        )));
+                    }
+                    Ok(PageSelection::Range(start, end))
+                }
+                None => Ok(PageSelection::Single(parse_page(s)?)),
+            },
+        }
+    }
+}
+
+impl fmt::Display for PageSelection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PageSelection::First => write!(f, "P1"),
+            PageSelection::Single(page) => write!(f, "P{page}"),
+            PageSelection::Range(start, end) => write!(f, "P{start}-P{end}"),
+            PageSelection::All => write!(f, "全部"),
+        }
+    }
+}
+
+/// 根据 bvid 解析视频信息并写入音乐库，多P视频的每一P作为独立的歌曲
+///
+/// # 参数
+/// - api_base: 接口地址，一般为 BILIBILI_API_BASE
+/// - bvid: 视频的 bvid
+/// - song_name: 自定义歌曲名称，为空时使用视频标题或分P标题
+/// - pages: 要导入的分P
+pub async fn import_bvid(
+    client: &Client,
+    api_base: &str,
+    repository: &MusicRepository,
+    bvid: &str,
+    song_name: Option<&str>,
+    pages: PageSelection,
+) -> Result<Vec<Music>, ApplicationError> {
+    let bvid = bvid.trim();
+    if bvid.is_empty() {
+        return Err(ApplicationError::InvalidArgumentError(
+            "bvid 不能为空".to_string(),
+        ));
+    }
+    // 请求视频信息，获取标题、分P和作者
+    let video_data = fetch_video_data(client, api_base, bvid).await?;
+    let parts = video_data.parts();
+    let selected = pages.select(&parts)?;
+    let song_name = song_name.map(str::trim).filter(|name| !name.is_empty());
+    let mut imported = Vec::new();
+    let mut existed = Vec::new();
+    for part in &selected {
+        let title = match song_name {
+            Some(name) if selected.len() == 1 => name.to_string(),
+            Some(name) => format!("{name} - {}", part_title(&video_data.title, part)),
+            None if parts.len() == 1 => video_data.title.clone(),
+            None => part_title(&video_data.title, part),
+        };
+        let music = Music {
+            bvid: video_data.bvid.clone(),
+            cid: part.cid.to_string(),
+            title,
+            owner: video_data.owner.name.clone(),
+        };
+        match repository.find(&music.bvid, &music.cid).await? {
+            // 已在音乐库中的歌曲不重复添加
+            Some(record) if !record.is_deleted => {
+                existed.push(format!("{} - {}", record.song_name, record.bvid));
+                continue;
+            }
+            // 之前被删除过，更新信息后恢复
+            Some(_) => {
+                repository.update(&music).await?;
+                repository.restore(&music.bvid, Some(&music.cid)).await?;
+            }
+            None => {
+                repository.create(&music, part.page).await?;
+            }
+        }
+        tracing::info!("Imported {:?} into library", music);
+        imported.push(music);
+    }
+    if imported.is_empty() {
+        return Err(ApplicationError::AlreadyExistsError(format!(
+            "{} 已在音乐库中",
+            existed.join("、")
+        )));
+    }
+    Ok(imported)
+}
+
+// 分P标题为空时使用视频标题加分P序号
+fn part_title(title: &str, part: &VideoPage) -> String {
+    if part.part.trim().is_empty() {
+        format!("{title} P{}", part.page)
+    } else {
+        part.part.trim().to_string()
+    }
+}
+
+/// 导入收藏夹的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct FavoriteImportReport {
+    pub folder: FavoriteInfo,
+    pub playlist: PlaylistSummary,
+    // 本次新加入歌单的歌曲
+    pub added: Vec<Music>,
+    // 已在歌单中的视频数量
+    pub existing: usize,
+    // 歌单中有但收藏夹中已经没有的歌曲，只报告不移除
+    pub removed_upstream: Vec<Music>,
+    // 收藏夹中已失效或不是视频的条目
+    pub unavailable: Vec<String>,
+    // 导入失败的条目及原因
+    pub failed: Vec<String>,
+}
+
+/// 导入 B 站收藏夹为歌单，重复导入时只添加新的视频
+///
+/// 每个视频通过 fetch_video_data 解析 cid，只导入第一P
+///
+/// # 参数
+/// - api_base: 接口地址，一般为 BILIBILI_API_BASE
+/// - media_id: 收藏夹 id
+/// - playlist_name: 歌单名称，为空时使用收藏夹标题，已导入过的收藏夹沿用原来的歌单
+pub async fn import_favorite_folder(
+    client: &Client,
+    api_base: &str,
+    repository: &MusicRepository,
+    playlists: &PlaylistRepository,
+    media_id: i64,
+    playlist_name: Option<&str>,
+) -> Result<FavoriteImportReport, ApplicationError> {
+    let folder = fetch_favorite_folder(client, api_base, media_id).await?;
+    let playlist = favorite_playlist(playlists, media_id, playlist_name, &folder.info).await?;
+    let current = playlists.musics(playlist.id).await?;
+    let mut in_playlist: HashSet<String> =
+        current.iter().map(|record| record.bvid.clone()).collect();
+    let mut added = Vec::new();
+    let mut existing = 0;
+    let mut unavailable = Vec::new();
+    let mut failed = Vec::new();
+    for media in &folder.medias {
+        if !media.is_available() {
+            unavailable.push(format!("{} ({})", media.title, media.id));
+            continue;
+        }
+        if in_playlist.contains(&media.bvid) {
+            existing += 1;
+            continue;
+        }
+        // 音乐库中已有第一P时直接使用，否则请求视频信息后导入
+        let mut records: Vec<_> = repository
+            .find_by_bvid(&media.bvid)
+            .await?
+            .into_iter()
+            .filter(|record| !record.is_deleted && record.page == 1)
+            .collect();
+        if records.is_empty() {
+            match import_bvid(
+                client,
+                api_base,
+                repository,
+                &media.bvid,
+                None,
+                PageSelection::First,
+            )
+            .await
+            {
+                Ok(musics) => {
+                    for music in musics {
+                        if let Some(record) = repository.find(&music.bvid, &music.cid).await? {
+                            records.push(record);
+                        }
+                    }
+                }
+                Err(e) => {
+                    tracing::warn!(
+                        "Failed to import {} from favorite folder: {}",
+                        media.bvid,
+                        e
+                    );
+                    failed.push(format!("{} - {}: {}", media.title, media.bvid, e));
+                    continue;
+                }
+            }
+        }
+        for record in records {
+            // 之前删除过的歌曲恢复后，歌单中可能已有该条目
+            playlists.add_music(playlist.id, record.id).await?;
+            added.push(Music::from(record));
+        }
+        in_playlist.insert(media.bvid.clone());
+    }
+    let upstream: HashSet<&str> = folder
+        .medias
+        .iter()
+        .map(|media| media.bvid.as_str())
+        .collect();
+    let removed_upstream = current
+        .into_iter()
+        .filter(|record| !upstream.contains(record.bvid.as_str()))
+        .map(Music::from)
+        .collect();
+    let playlist = playlists.summary(&playlist.name).await?;
+    tracing::info!(
+        "Imported favorite folder {} into playlist {}: {} added, {} existing",
+        media_id,
+        playlist.name,
+        added.len(),
+        existing
+    );
+    Ok(FavoriteImportReport {
+        folder: folder.info,
+        playlist,
+        added,
+        existing,
+        removed_upstream,
+        unavailable,
+        failed,
+    })
+}
+
+// 找到收藏夹对应的歌单，没有时按名称查找或创建，并记录收藏夹 id
+async fn favorite_playlist(
+    playlists: &PlaylistRepository,
+    media_id: i64,
+    playlist_name: Option<&str>,
+    info: &FavoriteInfo,
+) -> Result<PlaylistRecord, ApplicationError> {
+    if let Some(record) = playlists.find_by_favorite(media_id).await? {
+        return Ok(record);
+    }
+    let name = playlist_name
+        .map(str::trim)
+        .filter(|name| !name.is_empty())
+        .unwrap_or(info.title.trim());
+    let record = match playlists.find_by_name(name).await? {
+        Some(record) => record,
+        None => playlists.create(name).await?,
+    };
+    playlists.set_favorite(record.id, media_id).await
+}
+
+/// 批量导入的视频来源
+#[derive(Debug, Clone, PartialEq)]
+pub enum VideoSource {
+    // 合集
+    Season { mid: i64, season_id: i64 },
+    // UP 主的投稿，max_pages 为 0 时导入所有投稿
+    Uploader { mid: i64, max_pages: u32 },
+}
+
+impl fmt::Display for VideoSource {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            VideoSource::Season { season_id, .. } => write!(f, "合集 {season_id}"),
+            VideoSource::Uploader { mid, .. } => write!(f, "UP 主 {mid}"),
+        }
+    }
+}
+
+/// 批量导入视频的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct VideosImportReport {
+    // 来源名称，合集为合集标题
+    pub source: String,
+    // 本次导入音乐库的歌曲
+    pub added: Vec<Music>,
+    // 满足过滤条件但已在音乐库中的视频数量
+    pub existing: usize,
+    // 导入失败的视频及原因
+    pub failed: Vec<String>,
+}
+
+/// 导入合集或 UP 主投稿中满足过滤条件的视频，每个视频只导入第一P
+///
+/// 与单个 bvid 一样通过 import_bvid 写入音乐库
+pub async fn import_videos(
+    client: &Client,
+    api_base: &str,
+    repository: &MusicRepository,
+    source: &VideoSource,
+    filter: &VideoFilter,
+) -> Result<VideosImportReport, ApplicationError> {
+    let (name, videos) = match *source {
+        VideoSource::Season { mid, season_id } => {
+            let (meta, videos) =
+                fetch_season_videos(client, api_base, mid, season_id, filter).await?;
+            (format!("合集 {}", meta.name), videos)
+        }
+        VideoSource::Uploader { mid, max_pages } => {
+            let videos = fetch_uploader_videos(client, api_base, mid, filter, max_pages).await?;
+            let name = match videos.first() {
+                Some(video) if !video.author.is_empty() => format!("UP 主 {}", video.author),
+                _ => source.to_string(),
+            };
+            (name, videos)
+        }
+    };
+    let mut report = VideosImportReport {
+        source: name,
+        added: Vec::new(),
+        existing: 0,
+        failed: Vec::new(),
+    };
+    for video in &videos {
+        import_video(client, api_base, repository, video, &mut report).await?;
+    }
+    tracing::info!(
+        "Imported {} from {}: {} added, {} existing, {} failed",
+        videos.len(),
+        report.source,
+        report.added.len(),
+        report.existing,
+        report.failed.len()
+    );
+    Ok(report)
+}
+
+// 导入单个视频，已在音乐库中的跳过，请求失败的记录到报告中
+async fn import_video(
+    client: &Client,
+    api_base: &str,
+    repository: &MusicRepository,
+    video: &VideoSummary,
+    report: &mut VideosImportReport,
+) -> Result<(), ApplicationError> {
+    let exists = repository
+        .find_by_bvid(&video.bvid)
+        .await?
+        .iter()
+        .any(|record| !record.is_deleted);
+    if exists {
+        report.existing += 1;
+        return Ok(());
+    }
+    match import_bvid(
+        client,
+        api_base,
+        repository,
+        &video.bvid,
+        None,
+        PageSelection::First,
+    )
+    .await
+    {
+        Ok(musics) => report.added.extend(musics),
+        Err(e) => {
+            tracing::warn!("Failed to import {}: {}", video.bvid, e);
+            report
+                .failed
+                .push(format!("{} - {}: {}", video.title, video.bvid, e));
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,7 @@
+pub mod credential_repository;
+pub mod database;
+pub mod equalizer_repository;
+pub mod import;
+pub mod playlist_repository;
+pub mod repository;
+pub mod session_repository;
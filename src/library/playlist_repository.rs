@@ -0,0 +1,284 @@
+use sqlx::{SqlitePool, types::chrono::NaiveDateTime};
+
+use crate::{errors::ApplicationError, library::repository::MusicRecord};
+
+/// playlists 表中的一行数据
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct PlaylistRecord {
+    pub id: i64,
+    pub name: String,
+    pub is_active: bool,
+    // 从收藏夹导入时对应的收藏夹 id
+    pub favorite_media_id: Option<i64>,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+/// 歌单概要，包含歌曲数量
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct PlaylistSummary {
+    pub id: i64,
+    pub name: String,
+    pub is_active: bool,
+    pub track_count: i64,
+}
+
+impl From<PlaylistSummary> for crate::pb::PlaylistInfo {
+    fn from(summary: PlaylistSummary) -> Self {
+        crate::pb::PlaylistInfo {
+            id: summary.id,
+            name: summary.name,
+            is_active: summary.is_active,
+            track_count: summary.track_count as u32,
+        }
+    }
+}
+
+/// 歌单库，负责 playlists 和 playlist_items 表的增删改查
+#[derive(Clone, Debug)]
+pub struct PlaylistRepository {
+    pool: SqlitePool,
+}
+
+impl PlaylistRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 创建歌单，名称重复时返回错误
+    pub async fn create(&self, name: &str) -> Result<PlaylistRecord, ApplicationError> {
+        let name = check_name(name)?;
+        if self.find_by_name(name).await?.is_some() {
+            return Err(ApplicationError::AlreadyExistsError(format!(
+                "歌单 {name} 已存在"
+            )));
+        }
+        let record = sqlx::query_as::<_, PlaylistRecord>(
+            "INSERT INTO playlists (name) VALUES (?) RETURNING *",
+        )
+        .bind(name)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 按名称查找歌单
+    pub async fn find_by_name(
+        &self,
+        name: &str,
+    ) -> Result<Option<PlaylistRecord>, ApplicationError> {
+        let record = sqlx::query_as::<_, PlaylistRecord>("SELECT * FROM playlists WHERE name = ?")
+            .bind(name.trim())
+            .fetch_optional(&self.pool)
+            .await?;
+        Ok(record)
+    }
+
+    /// 按名称查找歌单，不存在时返回错误
+    pub async fn get_by_name(&self, name: &str) -> Result<PlaylistRecord, ApplicationError> {
+        self.find_by_name(name)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFoundError(format!("歌单 {} 不存在", name.trim())))
+    }
+
+    /// 按收藏夹 id 查找导入的歌单
+    pub async fn find_by_favorite(
+        &self,
+        media_id: i64,
+    ) -> Result<Option<PlaylistRecord>, ApplicationError> {
+        let record = sqlx::query_as::<_, PlaylistRecord>(
+            "SELECT * FROM playlists WHERE favorite_media_id = ?",
+        )
+        .bind(media_id)
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 记录歌单对应的收藏夹 id
+    pub async fn set_favorite(
+        &self,
+        playlist_id: i64,
+        media_id: i64,
+    ) -> Result<PlaylistRecord, ApplicationError> {
+        let record = sqlx::query_as::<_, PlaylistRecord>(
+            "UPDATE playlists SET favorite_media_id = ? WHERE id = ? RETURNING *",
+        )
+        .bind(media_id)
+        .bind(playlist_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 获取当前播放的歌单
+    pub async fn active(&self) -> Result<Option<PlaylistRecord>, ApplicationError> {
+        let record =
+            sqlx::query_as::<_, PlaylistRecord>("SELECT * FROM playlists WHERE is_active = 1")
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(record)
+    }
+
+    /// 重命名歌单
+    pub async fn rename(
+        &self,
+        name: &str,
+        new_name: &str,
+    ) -> Result<PlaylistRecord, ApplicationError> {
+        let new_name = check_name(new_name)?;
+        let record = self.get_by_name(name).await?;
+        if record.name != new_name && self.find_by_name(new_name).await?.is_some() {
+            return Err(ApplicationError::AlreadyExistsError(format!(
+                "歌单 {new_name} 已存在"
+            )));
+        }
+        let record = sqlx::query_as::<_, PlaylistRecord>(
+            "UPDATE playlists SET name = ? WHERE id = ? RETURNING *",
+        )
+        .bind(new_name)
+        .bind(record.id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 删除歌单，当前播放的歌单不能删除
+    pub async fn delete(&self, name: &str) -> Result<PlaylistRecord, ApplicationError> {
+        let record = self.get_by_name(name).await?;
+        if record.is_active {
+            return Err(ApplicationError::StateError(format!(
+                "歌单 {} 正在播放，请先切换到其他歌单",
+                record.name
+            )));
+        }
+        sqlx::query("DELETE FROM playlists WHERE id = ?")
+            .bind(record.id)
+            .execute(&self.pool)
+            .await?;
+        Ok(record)
+    }
+
+    /// 列出所有歌单及其中未删除的歌曲数量
+    pub async fn list(&self) -> Result<Vec<PlaylistSummary>, ApplicationError> {
+        let summaries = sqlx::query_as::<_, PlaylistSummary>(
+            "SELECT p.id, p.name, p.is_active, COUNT(m.id) AS track_count
+             FROM playlists p
+             LEFT JOIN playlist_items i ON i.playlist_id = p.id
+             LEFT JOIN musics m ON m.id = i.music_id AND m.is_deleted = 0
+             GROUP BY p.id
+             ORDER BY p.id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(summaries)
+    }
+
+    /// 获取歌单概要
+    pub async fn summary(&self, name: &str) -> Result<PlaylistSummary, ApplicationError> {
+        let record = self.get_by_name(name).await?;
+        let summary = sqlx::query_as::<_, PlaylistSummary>(
+            "SELECT p.id, p.name, p.is_active, COUNT(m.id) AS track_count
+             FROM playlists p
+             LEFT JOIN playlist_items i ON i.playlist_id = p.id
+             LEFT JOIN musics m ON m.id = i.music_id AND m.is_deleted = 0
+             WHERE p.id = ?
+             GROUP BY p.id",
+        )
+        .bind(record.id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(summary)
+    }
+
+    /// 设置当前播放的歌单
+    pub async fn set_active(&self, name: &str) -> Result<PlaylistRecord, ApplicationError> {
+        let record = self.get_by_name(name).await?;
+        let mut tx = self.pool.begin().await?;
+        sqlx::query("UPDATE playlists SET is_active = 0 WHERE is_active = 1 AND id != ?")
+            .bind(record.id)
+            .execute(&mut *tx)
+            .await?;
+        let record = sqlx::query_as::<_, PlaylistRecord>(
+            "UPDATE playlists SET is_active = 1 WHERE id = ? RETURNING *",
+        )
+        .bind(record.id)
+        .fetch_one(&mut *tx)
+        .await?;
+        tx.commit().await?;
+        Ok(record)
+    }
+
+    /// 添加歌曲到歌单末尾，返回是否新增
+    pub async fn add_music(
+        &self,
+        playlist_id: i64,
+        music_id: i64,
+    ) -> Result<bool, ApplicationError> {
+        let result = sqlx::query(
+            "INSERT OR IGNORE INTO playlist_items (playlist_id, music_id, position)
+             SELECT ?, ?, COALESCE(MAX(position), 0) + 1 FROM playlist_items WHERE playlist_id = ?",
+        )
+        .bind(playlist_id)
+        .bind(music_id)
+        .bind(playlist_id)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 从歌单中移除歌曲，返回是否有记录被移除
+    pub async fn remove_music(
+        &self,
+        playlist_id: i64,
+        music_id: i64,
+    ) -> Result<bool, ApplicationError> {
+        let result =
+            sqlx::query("DELETE FROM playlist_items WHERE playlist_id = ? AND music_id = ?")
+                .bind(playlist_id)
+                .bind(music_id)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 歌单中是否包含该歌曲
+    pub async fn contains(
+        &self,
+        playlist_id: i64,
+        music_id: i64,
+    ) -> Result<bool, ApplicationError> {
+        let count: i64 = sqlx::query_scalar(
+            "SELECT COUNT(*) FROM playlist_items WHERE playlist_id = ? AND music_id = ?",
+        )
+        .bind(playlist_id)
+        .bind(music_id)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(count > 0)
+    }
+
+    /// 按顺序列出歌单中未删除的歌曲
+    pub async fn musics(&self, playlist_id: i64) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "SELECT m.* FROM playlist_items i
+             JOIN musics m ON m.id = i.music_id
+             WHERE i.playlist_id = ? AND m.is_deleted = 0
+             ORDER BY i.position, i.id",
+        )
+        .bind(playlist_id)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+}
+
+fn check_name(name: &str) -> Result<&str, ApplicationError> {
+    let name = name.trim();
+    if name.is_empty() {
+        return Err(ApplicationError::InvalidArgumentError(
+            "歌单名称不能为空".to_string(),
+        ));
+    }
+    Ok(name)
+}
@@ -0,0 +1,331 @@
+use sqlx::{QueryBuilder, Sqlite, SqlitePool, types::chrono::NaiveDateTime};
+
+use crate::{
+    errors::ApplicationError,
+    player::{loudness::Loudness, state::Music},
+};
+
+/// musics 表中的一行数据
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct MusicRecord {
+    pub id: i64,
+    pub bvid: String,
+    pub song_name: String,
+    pub cid: String,
+    pub author: String,
+    pub is_liked: bool,
+    pub is_deleted: bool,
+    pub created_at: Option<NaiveDateTime>,
+    pub updated_at: Option<NaiveDateTime>,
+    pub page: i64,
+    // 测量的整体响度 (LUFS) 和采样峰值，未测量时为 None
+    pub loudness_lufs: Option<f64>,
+    pub loudness_peak: Option<f64>,
+}
+
+impl MusicRecord {
+    /// 已测量的响度
+    pub fn loudness(&self) -> Option<Loudness> {
+        Some(Loudness {
+            integrated: self.loudness_lufs?,
+            peak: self.loudness_peak.unwrap_or(1.0),
+        })
+    }
+}
+
+impl From<MusicRecord> for Music {
+    fn from(record: MusicRecord) -> Self {
+        Music {
+            bvid: record.bvid,
+            cid: record.cid,
+            title: record.song_name,
+            owner: record.author,
+        }
+    }
+}
+
+/// 在音乐库中查找音乐的条件，多个条件同时满足
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MusicQuery {
+    // 以下条件均为不区分大小写的子串匹配，为 None 时不过滤
+    pub bvid: Option<String>,
+    pub cid: Option<String>,
+    pub title: Option<String>,
+    pub owner: Option<String>,
+    // 是否包含已删除的音乐
+    pub include_deleted: bool,
+    // 最多返回的数量，为 0 时使用默认值
+    pub limit: usize,
+}
+
+impl From<crate::pb::FindMusicRequest> for MusicQuery {
+    fn from(request: crate::pb::FindMusicRequest) -> Self {
+        let filter = |value: String| Some(value).filter(|value| !value.trim().is_empty());
+        MusicQuery {
+            bvid: filter(request.bvid),
+            cid: filter(request.cid),
+            title: filter(request.title),
+            owner: filter(request.owner),
+            include_deleted: request.include_deleted,
+            limit: request.limit as usize,
+        }
+    }
+}
+
+// 查找音乐时默认返回的数量
+pub const DEFAULT_FIND_LIMIT: usize = 50;
+// trigram 分词至少需要 3 个字符
+const FULL_TEXT_MIN_CHARS: usize = 3;
+
+/// 查找到的音乐以及它是否在当前播放的歌单中
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct FoundMusicRecord {
+    #[sqlx(flatten)]
+    pub record: MusicRecord,
+    pub in_active_playlist: bool,
+}
+
+impl From<FoundMusicRecord> for crate::pb::FoundMusic {
+    fn from(found: FoundMusicRecord) -> Self {
+        crate::pb::FoundMusic {
+            is_deleted: found.record.is_deleted,
+            is_liked: found.record.is_liked,
+            in_active_playlist: found.in_active_playlist,
+            music: Some(Music::from(found.record).into()),
+        }
+    }
+}
+
+/// 音乐库，负责 musics 表的增删改查
+#[derive(Clone, Debug)]
+pub struct MusicRepository {
+    pool: SqlitePool,
+}
+
+impl MusicRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 添加音乐，返回新插入的记录
+    ///
+    /// # 参数
+    /// - page: 分P序号，单P视频为 1
+    pub async fn create(&self, music: &Music, page: u32) -> Result<MusicRecord, ApplicationError> {
+        let record = sqlx::query_as::<_, MusicRecord>(
+            "INSERT INTO musics (bvid, song_name, cid, author, page) VALUES (?, ?, ?, ?, ?) RETURNING *",
+        )
+        .bind(&music.bvid)
+        .bind(&music.title)
+        .bind(&music.cid)
+        .bind(&music.owner)
+        .bind(page)
+        .fetch_one(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 按 (bvid, cid) 查找音乐，包含已删除的记录
+    pub async fn find(
+        &self,
+        bvid: &str,
+        cid: &str,
+    ) -> Result<Option<MusicRecord>, ApplicationError> {
+        let record =
+            sqlx::query_as::<_, MusicRecord>("SELECT * FROM musics WHERE bvid = ? AND cid = ?")
+                .bind(bvid)
+                .bind(cid)
+                .fetch_optional(&self.pool)
+                .await?;
+        Ok(record)
+    }
+
+    /// 按 bvid 查找视频的所有分P，包含已删除的记录
+    pub async fn find_by_bvid(&self, bvid: &str) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "SELECT * FROM musics WHERE bvid = ? ORDER BY page, id",
+        )
+        .bind(bvid)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+
+    /// 更新音乐信息，返回是否有记录被更新
+    pub async fn update(&self, music: &Music) -> Result<bool, ApplicationError> {
+        let result =
+            sqlx::query("UPDATE musics SET song_name = ?, author = ? WHERE bvid = ? AND cid = ?")
+                .bind(&music.title)
+                .bind(&music.owner)
+                .bind(&music.bvid)
+                .bind(&music.cid)
+                .execute(&self.pool)
+                .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 软删除音乐，返回被删除的记录
+    ///
+    /// # 参数
+    /// - cid: 指定分P，为 None 时删除该视频的所有分P
+    pub async fn soft_delete(
+        &self,
+        bvid: &str,
+        cid: Option<&str>,
+    ) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "UPDATE musics SET is_deleted = 1
+             WHERE bvid = ? AND (? IS NULL OR cid = ?) AND is_deleted = 0
+             RETURNING *",
+        )
+        .bind(bvid)
+        .bind(cid)
+        .bind(cid)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(sort_by_page(records))
+    }
+
+    /// 恢复已软删除的音乐，返回被恢复的记录
+    ///
+    /// # 参数
+    /// - cid: 指定分P，为 None 时恢复该视频的所有分P
+    pub async fn restore(
+        &self,
+        bvid: &str,
+        cid: Option<&str>,
+    ) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "UPDATE musics SET is_deleted = 0
+             WHERE bvid = ? AND (? IS NULL OR cid = ?) AND is_deleted = 1
+             RETURNING *",
+        )
+        .bind(bvid)
+        .bind(cid)
+        .bind(cid)
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(sort_by_page(records))
+    }
+
+    /// 列出所有未删除的音乐，按添加顺序排列
+    pub async fn list(&self) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "SELECT * FROM musics WHERE is_deleted = 0 ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+
+    /// 保存测量的响度，返回是否有记录被更新
+    pub async fn save_loudness(
+        &self,
+        music: &Music,
+        loudness: &Loudness,
+    ) -> Result<bool, ApplicationError> {
+        let result = sqlx::query(
+            "UPDATE musics SET loudness_lufs = ?, loudness_peak = ? WHERE bvid = ? AND cid = ?",
+        )
+        .bind(loudness.integrated)
+        .bind(loudness.peak)
+        .bind(&music.bvid)
+        .bind(&music.cid)
+        .execute(&self.pool)
+        .await?;
+        Ok(result.rows_affected() > 0)
+    }
+
+    /// 列出所有已测量响度的音乐，包含已删除的记录
+    pub async fn measured_loudness(&self) -> Result<Vec<MusicRecord>, ApplicationError> {
+        let records = sqlx::query_as::<_, MusicRecord>(
+            "SELECT * FROM musics WHERE loudness_lufs IS NOT NULL ORDER BY id",
+        )
+        .fetch_all(&self.pool)
+        .await?;
+        Ok(records)
+    }
+
+    /// 按条件查找音乐，并标记是否在当前播放的歌单中
+    pub async fn search(
+        &self,
+        query: &MusicQuery,
+    ) -> Result<Vec<FoundMusicRecord>, ApplicationError> {
+        let mut builder = QueryBuilder::<Sqlite>::new(
+            "SELECT m.*, EXISTS(
+                 SELECT 1 FROM playlist_items i
+                 JOIN playlists p ON p.id = i.playlist_id
+                 WHERE p.is_active = 1 AND i.music_id = m.id
+             ) AS in_active_playlist
+             FROM musics m WHERE 1 = 1",
+        );
+        if !query.include_deleted {
+            builder.push(" AND m.is_deleted = 0");
+        }
+        push_like(&mut builder, "m.bvid", query.bvid.as_deref());
+        push_like(&mut builder, "m.cid", query.cid.as_deref());
+        // 标题和作者通过全文索引做子串匹配
+        push_full_text(&mut builder, "song_name", query.title.as_deref());
+        push_full_text(&mut builder, "author", query.owner.as_deref());
+        let limit = match query.limit {
+            0 => DEFAULT_FIND_LIMIT,
+            limit => limit,
+        };
+        builder
+            .push(" ORDER BY m.id LIMIT ")
+            .push_bind(limit as i64);
+        let records = builder
+            .build_query_as::<FoundMusicRecord>()
+            .fetch_all(&self.pool)
+            .await?;
+        Ok(records)
+    }
+}
+
+// 去掉首尾空白，空字符串视为没有条件
+fn non_empty(value: Option<&str>) -> Option<&str> {
+    value.map(str::trim).filter(|value| !value.is_empty())
+}
+
+// 添加不区分大小写的子串匹配条件，LIKE 对 ASCII 字母不区分大小写
+fn push_like(builder: &mut QueryBuilder<'_, Sqlite>, column: &str, value: Option<&str>) {
+    if let Some(value) = non_empty(value) {
+        builder
+            .push(format!(" AND {column} LIKE "))
+            .push_bind(format!("%{}%", escape_like(value)))
+            .push(" ESCAPE '\\'");
+    }
+}
+
+// 添加只匹配 musics_fts 中指定列的子串条件，trigram 分词不区分大小写
+// 少于 3 个字符时无法使用全文索引，退回到 LIKE 子串匹配
+fn push_full_text(builder: &mut QueryBuilder<'_, Sqlite>, column: &str, value: Option<&str>) {
+    match non_empty(value) {
+        Some(value) if value.chars().count() >= FULL_TEXT_MIN_CHARS => {
+            builder
+                .push(" AND m.id IN (SELECT rowid FROM musics_fts WHERE musics_fts MATCH ")
+                .push_bind(format!("{column} : {}", full_text_phrase(value)))
+                .push(")");
+        }
+        value => push_like(builder, &format!("m.{column}"), value),
+    }
+}
+
+// 转义 LIKE 中的通配符
+fn escape_like(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('%', "\\%")
+        .replace('_', "\\_")
+}
+
+// 作为一个短语匹配，避免关键字中的 AND、* 等被当作 FTS5 语法
+fn full_text_phrase(value: &str) -> String {
+    format!("\"{}\"", value.replace('"', "\"\""))
+}
+
+// RETURNING 返回的顺序不确定，按分P排序
+fn sort_by_page(mut records: Vec<MusicRecord>) -> Vec<MusicRecord> {
+    records.sort_by_key(|record| (record.page, record.id));
+    records
+}
@@ -0,0 +1,76 @@
+use sqlx::{SqlitePool, types::chrono::NaiveDateTime};
+
+use crate::errors::ApplicationError;
+
+/// player_session 表中的一行数据
+#[derive(sqlx::FromRow, Debug, Clone, PartialEq)]
+pub struct SessionRecord {
+    pub current_index: i64,
+    pub current_bvid: Option<String>,
+    pub current_cid: Option<String>,
+    pub position: f64,
+    pub play_mode: String,
+    pub volume: i64,
+    pub muted: bool,
+    pub crossfade_seconds: i64,
+    // 均衡器各频段的增益，逗号分隔
+    pub equalizer_gains: String,
+    pub equalizer_preset: Option<String>,
+    pub updated_at: Option<NaiveDateTime>,
+}
+
+/// 播放会话库，负责保存和读取上一次的播放状态
+#[derive(Clone, Debug)]
+pub struct SessionRepository {
+    pool: SqlitePool,
+}
+
+impl SessionRepository {
+    pub fn new(pool: SqlitePool) -> Self {
+        Self { pool }
+    }
+
+    /// 读取上一次保存的播放会话，从未保存过时返回 None
+    pub async fn load(&self) -> Result<Option<SessionRecord>, ApplicationError> {
+        let record = sqlx::query_as::<_, SessionRecord>(
+            "SELECT current_index, current_bvid, current_cid, position, play_mode, volume, muted, crossfade_seconds, equalizer_gains, equalizer_preset, updated_at
+             FROM player_session WHERE id = 1",
+        )
+        .fetch_optional(&self.pool)
+        .await?;
+        Ok(record)
+    }
+
+    /// 保存播放会话，覆盖之前的记录
+    pub async fn save(&self, record: &SessionRecord) -> Result<(), ApplicationError> {
+        sqlx::query(
+            "INSERT INTO player_session (id, current_index, current_bvid, current_cid, position, play_mode, volume, muted, crossfade_seconds, equalizer_gains, equalizer_preset)
+             VALUES (1, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)
+             ON CONFLICT (id) DO UPDATE SET
+                 current_index = excluded.current_index,
+                 current_bvid = excluded.current_bvid,
+                 current_cid = excluded.current_cid,
+                 position = excluded.position,
+                 play_mode = excluded.play_mode,
+                 volume = excluded.volume,
+                 muted = excluded.muted,
+                 crossfade_seconds = excluded.crossfade_seconds,
+                 equalizer_gains = excluded.equalizer_gains,
+                 equalizer_preset = excluded.equalizer_preset,
+                 updated_at = CURRENT_TIMESTAMP",
+        )
+        .bind(record.current_index)
+        .bind(&record.current_bvid)
+        .bind(&record.current_cid)
+        .bind(record.position)
+        .bind(&record.play_mode)
+        .bind(record.volume)
+        .bind(record.muted)
+        .bind(record.crossfade_seconds)
+        .bind(&record.equalizer_gains)
+        .bind(&record.equalizer_preset)
+        .execute(&self.pool)
+        .await?;
+        Ok(())
+    }
+}
@@ -11,6 +11,18 @@ pub struct CreateMusicRequest {
     #[prost(string, tag = "4")]
     pub author: ::prost::alloc::string::String,
 }
+/// 音乐信息
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct MusicInfo {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub owner: ::prost::alloc::string::String,
+}
 /// 添加音乐到数据库中的响应参数
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PlayRequest {}
@@ -25,6 +37,9 @@ pub struct PlayResponse {
 pub struct PlayBvidRequest {
     #[prost(string, tag = "1")]
     pub bvid: ::prost::alloc::string::String,
+    /// 多P视频的分P cid，为空时播放该视频在播放列表中的第一项
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct PlayBvidResponse {
@@ -87,55 +102,178 @@ pub struct AddPlaylistRequest {
     pub bvid: ::prost::alloc::string::String,
     #[prost(string, tag = "2")]
     pub song_name: ::prost::alloc::string::String,
+    /// 要导入的分P：为空时只导入第一P，支持 "3"、"2-5"、"all"
+    #[prost(string, tag = "3")]
+    pub pages: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct AddPlaylistResponse {
     #[prost(bool, tag = "1")]
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    /// 第一首导入的歌曲
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    /// 所有导入的歌曲
+    #[prost(message, repeated, tag = "4")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct DeletedRequest {
     #[prost(string, tag = "1")]
     pub bvid: ::prost::alloc::string::String,
+    /// 分P cid，为空时删除该视频的所有分P
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
 }
-#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct DeletedResponse {
     #[prost(bool, tag = "1")]
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(message, repeated, tag = "4")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RestoreRequest {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    /// 分P cid，为空时恢复该视频的所有分P
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RestoreResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(message, repeated, tag = "4")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct GetStateRequest {}
+/// 音频流的音质
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AudioQuality {
+    /// 音质 id，如 30280 为 192K
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    /// 音质名称，如 192K、杜比全景声、Hi-Res 无损
+    #[prost(string, tag = "2")]
+    pub label: ::prost::alloc::string::String,
+    /// 码率 (bps)
+    #[prost(uint64, tag = "3")]
+    pub bandwidth: u64,
+    /// 编码，如 mp4a.40.2
+    #[prost(string, tag = "4")]
+    pub codecs: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct GetStateResponse {
     #[prost(bool, tag = "1")]
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub current_music: ::core::option::Option<MusicInfo>,
+    #[prost(enumeration = "PlaybackState", tag = "4")]
+    pub state: i32,
+    #[prost(enumeration = "PlayMode", tag = "5")]
+    pub play_mode: i32,
+    #[prost(uint32, optional, tag = "6")]
+    pub current_index: ::core::option::Option<u32>,
+    #[prost(uint32, tag = "7")]
+    pub playlist_len: u32,
+    #[prost(double, optional, tag = "8")]
+    pub position: ::core::option::Option<f64>,
+    #[prost(double, optional, tag = "9")]
+    pub duration: ::core::option::Option<f64>,
+    #[prost(uint32, tag = "10")]
+    pub volume: u32,
+    #[prost(bool, tag = "11")]
+    pub muted: bool,
+    /// 当前歌曲使用的音质
+    #[prost(message, optional, tag = "12")]
+    pub quality: ::core::option::Option<AudioQuality>,
+    /// 切换歌曲时的淡入淡出时长 (秒)
+    #[prost(uint32, tag = "13")]
+    pub crossfade: u32,
+    /// 均衡器使用的预设名称，调整过频段时为 "自定义"
+    #[prost(string, tag = "14")]
+    pub equalizer: ::prost::alloc::string::String,
+    /// 播放速度，1.0 为正常速度
+    #[prost(double, tag = "15")]
+    pub rate: f64,
+    /// 变速时是否保持音调
+    #[prost(bool, tag = "16")]
+    pub preserve_pitch: bool,
 }
 #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct ShowPlayListRequest {
+    /// 页码从 1 开始，为 0 时返回当前播放歌曲所在的页
     #[prost(int32, tag = "1")]
     pub page: i32,
+    /// 每页数量，为 0 时使用默认值
+    #[prost(int32, tag = "2")]
+    pub page_size: i32,
 }
+/// 播放列表中的一项
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistEntry {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(bool, tag = "3")]
+    pub is_current: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
 pub struct ShowPlayListResponse {
     #[prost(bool, tag = "1")]
     pub success: bool,
+    /// 歌曲总数
     #[prost(int32, tag = "2")]
     pub total: i32,
+    /// 当前页码
     #[prost(int32, tag = "3")]
     pub current: i32,
-    #[prost(string, repeated, tag = "4")]
-    pub infos: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    #[prost(message, repeated, tag = "5")]
+    pub entries: ::prost::alloc::vec::Vec<PlaylistEntry>,
+    #[prost(int32, tag = "6")]
+    pub page_size: i32,
+    #[prost(int32, tag = "7")]
+    pub total_pages: i32,
+    #[prost(bool, tag = "8")]
+    pub has_previous: bool,
+    #[prost(bool, tag = "9")]
+    pub has_next: bool,
 }
-#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SetVolumeRequest {
-    #[prost(double, tag = "1")]
-    pub volume: f64,
+    #[prost(oneof = "set_volume_request::Action", tags = "2, 3, 4")]
+    pub action: ::core::option::Option<set_volume_request::Action>,
+}
+/// Nested message and enum types in `SetVolumeRequest`.
+pub mod set_volume_request {
+    #[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Action {
+        /// 设置音量百分比 (0-200)
+        #[prost(uint32, tag = "2")]
+        Level(u32),
+        /// 相对调整音量，正数增大，负数减小
+        #[prost(int32, tag = "3")]
+        Delta(i32),
+        /// true 静音，false 取消静音
+        #[prost(bool, tag = "4")]
+        Mute(bool),
+    }
 }
 #[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
 pub struct SetVolumeResponse {
@@ -143,281 +281,855 @@ pub struct SetVolumeResponse {
     pub success: bool,
     #[prost(string, tag = "2")]
     pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub volume: u32,
+    #[prost(bool, tag = "4")]
+    pub muted: bool,
 }
-/// Generated client implementations.
-pub mod player_service_client {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    use tonic::codegen::http::Uri;
-    /// service
-    #[derive(Debug, Clone)]
-    pub struct PlayerServiceClient<T> {
-        inner: tonic::client::Grpc<T>,
-    }
-    impl PlayerServiceClient<tonic::transport::Channel> {
-        /// Attempt to create a new client by connecting to a given endpoint.
-        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
-        where
-            D: TryInto<tonic::transport::Endpoint>,
-            D::Error: Into<StdError>,
-        {
-            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
-            Ok(Self::new(conn))
-        }
-    }
-    impl<T> PlayerServiceClient<T>
-    where
-        T: tonic::client::GrpcService<tonic::body::Body>,
-        T::Error: Into<StdError>,
-        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
-        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
-    {
-        pub fn new(inner: T) -> Self {
-            let inner = tonic::client::Grpc::new(inner);
-            Self { inner }
-        }
-        pub fn with_origin(inner: T, origin: Uri) -> Self {
-            let inner = tonic::client::Grpc::with_origin(inner, origin);
-            Self { inner }
-        }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> PlayerServiceClient<InterceptedService<T, F>>
-        where
-            F: tonic::service::Interceptor,
-            T::ResponseBody: Default,
-            T: tonic::codegen::Service<
-                http::Request<tonic::body::Body>,
-                Response = http::Response<
-                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
-                >,
-            >,
-            <T as tonic::codegen::Service<
-                http::Request<tonic::body::Body>,
-            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
-        {
-            PlayerServiceClient::new(InterceptedService::new(inner, interceptor))
-        }
-        /// Compress requests with the given encoding.
-        ///
-        /// This requires the server to support it otherwise it might respond with an
-        /// error.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.send_compressed(encoding);
-            self
-        }
-        /// Enable decompressing responses.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.inner = self.inner.accept_compressed(encoding);
-            self
-        }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_decoding_message_size(limit);
-            self
-        }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.inner = self.inner.max_encoding_message_size(limit);
-            self
-        }
-        pub async fn play(
-            &mut self,
-            request: impl tonic::IntoRequest<super::PlayRequest>,
-        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Play",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Play"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn play_bvid(
-            &mut self,
-            request: impl tonic::IntoRequest<super::PlayBvidRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::PlayBvidResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/PlayBvid",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "PlayBvid"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn pause(
-            &mut self,
-            request: impl tonic::IntoRequest<super::PauseRequest>,
-        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Pause",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "Pause"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn next(
-            &mut self,
-            request: impl tonic::IntoRequest<super::NextRequest>,
-        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Next",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Next"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn previous(
-            &mut self,
-            request: impl tonic::IntoRequest<super::PreviousRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::PreviousResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Previous",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "Previous"));
-            self.inner.unary(req, path, codec).await
-        }
-        pub async fn stop(
-            &mut self,
-            request: impl tonic::IntoRequest<super::StopRequest>,
-        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status> {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Stop",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Stop"));
-            self.inner.unary(req, path, codec).await
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetCrossfadeRequest {
+    /// 淡入淡出时长 (0-12 秒)，0 表示无缝衔接
+    #[prost(uint32, tag = "1")]
+    pub seconds: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetCrossfadeResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "3")]
+    pub seconds: u32,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetRateRequest {
+    /// 播放速度 (0.5-2.0)
+    #[prost(double, tag = "1")]
+    pub rate: f64,
+    /// 是否使用 scaletempo 保持音调
+    #[prost(bool, tag = "2")]
+    pub preserve_pitch: bool,
+    /// 切换歌曲时是否保持速度，否则恢复为 1.0
+    #[prost(bool, tag = "3")]
+    pub keep: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SetRateResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(double, tag = "3")]
+    pub rate: f64,
+    #[prost(bool, tag = "4")]
+    pub preserve_pitch: bool,
+    #[prost(bool, tag = "5")]
+    pub keep: bool,
+}
+/// 均衡器的一个频段
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct EqualizerBand {
+    /// 中心频率 (Hz)
+    #[prost(uint32, tag = "1")]
+    pub frequency: u32,
+    /// 增益 (dB)
+    #[prost(double, tag = "2")]
+    pub gain: f64,
+}
+/// 均衡器预设
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EqualizerPreset {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 10 个频段的增益 (dB)，从低频到高频
+    #[prost(double, repeated, tag = "2")]
+    pub gains: ::prost::alloc::vec::Vec<f64>,
+    /// 内置预设不能修改和删除
+    #[prost(bool, tag = "3")]
+    pub builtin: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct GetEqualizerRequest {}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SetEqualizerBandRequest {
+    /// 频段序号 (0-9)，从低频到高频
+    #[prost(uint32, tag = "1")]
+    pub band: u32,
+    /// 增益 (-24 到 12 dB)
+    #[prost(double, tag = "2")]
+    pub gain: f64,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ApplyEqualizerPresetRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SaveEqualizerPresetRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    /// 10 个频段的增益 (dB)，为空时保存当前的设置
+    #[prost(double, repeated, tag = "2")]
+    pub gains: ::prost::alloc::vec::Vec<f64>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeleteEqualizerPresetRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+/// 均衡器相关请求共用的响应，包含当前设置和所有预设
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct EqualizerResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub bands: ::prost::alloc::vec::Vec<EqualizerBand>,
+    /// 当前使用的预设名称，调整过频段时为 "自定义"
+    #[prost(string, tag = "4")]
+    pub preset: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "5")]
+    pub presets: ::prost::alloc::vec::Vec<EqualizerPreset>,
+}
+#[derive(Clone, Copy, PartialEq, ::prost::Message)]
+pub struct SeekRequest {
+    /// 目标位置 (秒)，relative 为 true 时表示相对当前位置的偏移
+    #[prost(double, tag = "1")]
+    pub position: f64,
+    #[prost(bool, tag = "2")]
+    pub relative: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SeekResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(double, tag = "3")]
+    pub position: f64,
+}
+/// 歌单信息
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistInfo {
+    #[prost(int64, tag = "1")]
+    pub id: i64,
+    #[prost(string, tag = "2")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(bool, tag = "3")]
+    pub is_active: bool,
+    #[prost(uint32, tag = "4")]
+    pub track_count: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreatePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct CreatePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub playlist: ::core::option::Option<PlaylistInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub new_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RenamePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub playlist: ::core::option::Option<PlaylistInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct DeletePlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ListPlaylistsRequest {}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ListPlaylistsResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(message, repeated, tag = "2")]
+    pub playlists: ::prost::alloc::vec::Vec<PlaylistInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwitchPlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SwitchPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub playlist: ::core::option::Option<PlaylistInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct AddToPlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub bvid: ::prost::alloc::string::String,
+    /// 要添加的分P，格式同 AddPlaylistRequest.pages
+    #[prost(string, tag = "3")]
+    pub pages: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct AddToPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(message, repeated, tag = "4")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct RemoveFromPlaylistRequest {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub bvid: ::prost::alloc::string::String,
+    /// 分P cid，为空时移除该视频的所有分P
+    #[prost(string, tag = "3")]
+    pub cid: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct RemoveFromPlaylistResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(message, repeated, tag = "4")]
+    pub musics: ::prost::alloc::vec::Vec<MusicInfo>,
+}
+/// 导入 B 站收藏夹为歌单
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportFavoriteRequest {
+    #[prost(int64, tag = "1")]
+    pub media_id: i64,
+    /// 歌单名称，为空时使用收藏夹标题
+    #[prost(string, tag = "2")]
+    pub playlist_name: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportFavoriteResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, optional, tag = "3")]
+    pub playlist: ::core::option::Option<PlaylistInfo>,
+    /// 本次新加入歌单的歌曲
+    #[prost(message, repeated, tag = "4")]
+    pub added: ::prost::alloc::vec::Vec<MusicInfo>,
+    /// 已在歌单中的视频数量
+    #[prost(uint32, tag = "5")]
+    pub existing: u32,
+    /// 收藏夹中已移除但仍在歌单中的歌曲
+    #[prost(message, repeated, tag = "6")]
+    pub removed_upstream: ::prost::alloc::vec::Vec<MusicInfo>,
+    /// 已失效的条目
+    #[prost(string, repeated, tag = "7")]
+    pub unavailable: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+    /// 导入失败的条目及原因
+    #[prost(string, repeated, tag = "8")]
+    pub failed: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// 批量导入时过滤视频的条件
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VideoFilter {
+    /// 标题中包含的关键字，为空时不过滤
+    #[prost(string, tag = "1")]
+    pub keyword: ::prost::alloc::string::String,
+    /// 最短时长 (秒)，为 0 时不限制
+    #[prost(uint32, tag = "2")]
+    pub min_duration: u32,
+    /// 最长时长 (秒)，为 0 时不限制
+    #[prost(uint32, tag = "3")]
+    pub max_duration: u32,
+}
+/// 导入合集中的视频到当前歌单
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportSeasonRequest {
+    #[prost(int64, tag = "1")]
+    pub mid: i64,
+    #[prost(int64, tag = "2")]
+    pub season_id: i64,
+    #[prost(message, optional, tag = "3")]
+    pub filter: ::core::option::Option<VideoFilter>,
+}
+/// 导入 UP 主的投稿到当前歌单
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ImportUploaderRequest {
+    #[prost(int64, tag = "1")]
+    pub mid: i64,
+    #[prost(message, optional, tag = "2")]
+    pub filter: ::core::option::Option<VideoFilter>,
+    /// 最多请求的页数，为 0 时请求所有投稿
+    #[prost(uint32, tag = "3")]
+    pub max_pages: u32,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct ImportVideosResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub added: ::prost::alloc::vec::Vec<MusicInfo>,
+    /// 已在音乐库中的视频数量
+    #[prost(uint32, tag = "4")]
+    pub existing: u32,
+    /// 导入失败的视频及原因
+    #[prost(string, repeated, tag = "5")]
+    pub failed: ::prost::alloc::vec::Vec<::prost::alloc::string::String>,
+}
+/// 搜索 B 站视频
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SearchRequest {
+    #[prost(string, tag = "1")]
+    pub keyword: ::prost::alloc::string::String,
+    /// 页码，从 1 开始，为 0 时返回第一页
+    #[prost(uint32, tag = "2")]
+    pub page: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SearchResult {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub title: ::prost::alloc::string::String,
+    /// UP 主名称
+    #[prost(string, tag = "3")]
+    pub author: ::prost::alloc::string::String,
+    /// 时长 (秒)
+    #[prost(uint64, tag = "4")]
+    pub duration: u64,
+    /// 播放次数
+    #[prost(uint64, tag = "5")]
+    pub play: u64,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct SearchResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub results: ::prost::alloc::vec::Vec<SearchResult>,
+    #[prost(uint32, tag = "4")]
+    pub page: u32,
+    #[prost(uint32, tag = "5")]
+    pub num_pages: u32,
+    #[prost(uint32, tag = "6")]
+    pub num_results: u32,
+}
+/// 在本地音乐库中查找歌曲，多个条件同时满足，均为不区分大小写的子串匹配
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FindMusicRequest {
+    #[prost(string, tag = "1")]
+    pub bvid: ::prost::alloc::string::String,
+    #[prost(string, tag = "2")]
+    pub cid: ::prost::alloc::string::String,
+    #[prost(string, tag = "3")]
+    pub title: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub owner: ::prost::alloc::string::String,
+    /// 是否包含已删除的歌曲
+    #[prost(bool, tag = "6")]
+    pub include_deleted: bool,
+    /// 最多返回的数量，为 0 时使用默认值
+    #[prost(uint32, tag = "7")]
+    pub limit: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct FoundMusic {
+    #[prost(message, optional, tag = "1")]
+    pub music: ::core::option::Option<MusicInfo>,
+    /// 是否在当前播放的歌单中
+    #[prost(bool, tag = "2")]
+    pub in_active_playlist: bool,
+    #[prost(bool, tag = "3")]
+    pub is_deleted: bool,
+    #[prost(bool, tag = "4")]
+    pub is_liked: bool,
+}
+#[derive(Clone, PartialEq, ::prost::Message)]
+pub struct FindMusicResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+    #[prost(message, repeated, tag = "3")]
+    pub musics: ::prost::alloc::vec::Vec<FoundMusic>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LoginStatusRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LoginStatusResponse {
+    #[prost(bool, tag = "1")]
+    pub logged_in: bool,
+    #[prost(int64, tag = "2")]
+    pub mid: i64,
+    #[prost(string, tag = "3")]
+    pub uname: ::prost::alloc::string::String,
+    #[prost(string, tag = "4")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SetCredentialsRequest {
+    /// 浏览器复制的 cookie，如 "SESSDATA=xxx; bili_jct=yyy"
+    #[prost(string, tag = "1")]
+    pub cookie: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QrLoginStartRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QrLoginStartResponse {
+    /// 二维码内容
+    #[prost(string, tag = "1")]
+    pub url: ::prost::alloc::string::String,
+    /// 轮询扫码状态用的 key
+    #[prost(string, tag = "2")]
+    pub qrcode_key: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QrLoginPollRequest {
+    #[prost(string, tag = "1")]
+    pub qrcode_key: ::prost::alloc::string::String,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct QrLoginPollResponse {
+    #[prost(enumeration = "QrLoginState", tag = "1")]
+    pub state: i32,
+    /// 确认登录后的登录状态
+    #[prost(message, optional, tag = "2")]
+    pub status: ::core::option::Option<LoginStatusResponse>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LogoutRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct LogoutResponse {
+    #[prost(bool, tag = "1")]
+    pub success: bool,
+    #[prost(string, tag = "2")]
+    pub message: ::prost::alloc::string::String,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeEventsRequest {}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct TrackChangedEvent {
+    #[prost(uint32, tag = "1")]
+    pub index: u32,
+    #[prost(message, optional, tag = "2")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(message, optional, tag = "3")]
+    pub quality: ::core::option::Option<AudioQuality>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct StateChangedEvent {
+    #[prost(enumeration = "PlaybackState", tag = "1")]
+    pub state: i32,
+    /// 缓冲进度，仅在 BUFFERING 状态下有值
+    #[prost(int32, optional, tag = "2")]
+    pub buffering_percent: ::core::option::Option<i32>,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct VolumeChangedEvent {
+    #[prost(uint32, tag = "1")]
+    pub volume: u32,
+    #[prost(bool, tag = "2")]
+    pub muted: bool,
+}
+#[derive(Clone, Copy, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ModeChangedEvent {
+    #[prost(enumeration = "PlayMode", tag = "1")]
+    pub play_mode: i32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistChangedEvent {
+    #[prost(enumeration = "PlaylistAction", tag = "1")]
+    pub action: i32,
+    #[prost(message, optional, tag = "2")]
+    pub music: ::core::option::Option<MusicInfo>,
+    #[prost(uint32, tag = "3")]
+    pub playlist_len: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct PlaylistSwitchedEvent {
+    #[prost(string, tag = "1")]
+    pub name: ::prost::alloc::string::String,
+    #[prost(uint32, tag = "2")]
+    pub playlist_len: u32,
+}
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct ErrorEvent {
+    #[prost(string, tag = "1")]
+    pub message: ::prost::alloc::string::String,
+}
+/// 播放器事件
+#[derive(Clone, PartialEq, Eq, Hash, ::prost::Message)]
+pub struct SubscribeEventsResponse {
+    /// 事件时间 (毫秒时间戳)
+    #[prost(int64, tag = "1")]
+    pub timestamp: i64,
+    #[prost(oneof = "subscribe_events_response::Event", tags = "2, 3, 4, 5, 6, 7, 8")]
+    pub event: ::core::option::Option<subscribe_events_response::Event>,
+}
+/// Nested message and enum types in `SubscribeEventsResponse`.
+pub mod subscribe_events_response {
+    #[derive(Clone, PartialEq, Eq, Hash, ::prost::Oneof)]
+    pub enum Event {
+        #[prost(message, tag = "2")]
+        TrackChanged(super::TrackChangedEvent),
+        #[prost(message, tag = "3")]
+        StateChanged(super::StateChangedEvent),
+        #[prost(message, tag = "4")]
+        VolumeChanged(super::VolumeChangedEvent),
+        #[prost(message, tag = "5")]
+        ModeChanged(super::ModeChangedEvent),
+        #[prost(message, tag = "6")]
+        PlaylistChanged(super::PlaylistChangedEvent),
+        #[prost(message, tag = "7")]
+        Error(super::ErrorEvent),
+        #[prost(message, tag = "8")]
+        PlaylistSwitched(super::PlaylistSwitchedEvent),
+    }
+}
+/// 播放状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlaybackState {
+    Stopped = 0,
+    Playing = 1,
+    Paused = 2,
+    Buffering = 3,
+}
+impl PlaybackState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Stopped => "STOPPED",
+            Self::Playing => "PLAYING",
+            Self::Paused => "PAUSED",
+            Self::Buffering => "BUFFERING",
         }
-        pub async fn set_model(
-            &mut self,
-            request: impl tonic::IntoRequest<super::SetModelRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::SetModelResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/SetModel",
-            );
-            let mut req = request.into_request();
-            req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "SetModel"));
-            self.inner.unary(req, path, codec).await
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "STOPPED" => Some(Self::Stopped),
+            "PLAYING" => Some(Self::Playing),
+            "PAUSED" => Some(Self::Paused),
+            "BUFFERING" => Some(Self::Buffering),
+            _ => None,
         }
-        pub async fn add_playlist(
-            &mut self,
-            request: impl tonic::IntoRequest<super::AddPlaylistRequest>,
-        ) -> std::result::Result<
-            tonic::Response<super::AddPlaylistResponse>,
-            tonic::Status,
-        > {
-            self.inner
-                .ready()
-                .await
-                .map_err(|e| {
-                    tonic::Status::unknown(
-                        format!("Service was not ready: {}", e.into()),
-                    )
-                })?;
-            let codec = tonic_prost::ProstCodec::default();
-            let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/AddPlaylist",
-            );
-            let mut req = request.into_request();
+    }
+}
+/// 播放模式
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlayMode {
+    Normal = 0,
+    Shuffle = 1,
+    Repeat = 2,
+}
+impl PlayMode {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Normal => "NORMAL",
+            Self::Shuffle => "SHUFFLE",
+            Self::Repeat => "REPEAT",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "NORMAL" => Some(Self::Normal),
+            "SHUFFLE" => Some(Self::Shuffle),
+            "REPEAT" => Some(Self::Repeat),
+            _ => None,
+        }
+    }
+}
+/// 二维码登录状态
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum QrLoginState {
+    QrWaiting = 0,
+    QrScanned = 1,
+    QrExpired = 2,
+    QrConfirmed = 3,
+}
+impl QrLoginState {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::QrWaiting => "QR_WAITING",
+            Self::QrScanned => "QR_SCANNED",
+            Self::QrExpired => "QR_EXPIRED",
+            Self::QrConfirmed => "QR_CONFIRMED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "QR_WAITING" => Some(Self::QrWaiting),
+            "QR_SCANNED" => Some(Self::QrScanned),
+            "QR_EXPIRED" => Some(Self::QrExpired),
+            "QR_CONFIRMED" => Some(Self::QrConfirmed),
+            _ => None,
+        }
+    }
+}
+/// 播放列表变更类型
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord, ::prost::Enumeration)]
+#[repr(i32)]
+pub enum PlaylistAction {
+    Added = 0,
+    Removed = 1,
+    Restored = 2,
+}
+impl PlaylistAction {
+    /// String value of the enum field names used in the ProtoBuf definition.
+    ///
+    /// The values are not transformed in any way and thus are considered stable
+    /// (if the ProtoBuf definition does not change) and safe for programmatic use.
+    pub fn as_str_name(&self) -> &'static str {
+        match self {
+            Self::Added => "ADDED",
+            Self::Removed => "REMOVED",
+            Self::Restored => "RESTORED",
+        }
+    }
+    /// Creates an enum from field names used in the ProtoBuf definition.
+    pub fn from_str_name(value: &str) -> ::core::option::Option<Self> {
+        match value {
+            "ADDED" => Some(Self::Added),
+            "REMOVED" => Some(Self::Removed),
+            "RESTORED" => Some(Self::Restored),
+            _ => None,
+        }
+    }
+}
+/// Generated client implementations.
+pub mod player_service_client {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    use tonic::codegen::http::Uri;
+    /// service
+    #[derive(Debug, Clone)]
+    pub struct PlayerServiceClient<T> {
+        inner: tonic::client::Grpc<T>,
+    }
+    impl PlayerServiceClient<tonic::transport::Channel> {
+        /// Attempt to create a new client by connecting to a given endpoint.
+        pub async fn connect<D>(dst: D) -> Result<Self, tonic::transport::Error>
+        where
+            D: TryInto<tonic::transport::Endpoint>,
+            D::Error: Into<StdError>,
+        {
+            let conn = tonic::transport::Endpoint::new(dst)?.connect().await?;
+            Ok(Self::new(conn))
+        }
+    }
+    impl<T> PlayerServiceClient<T>
+    where
+        T: tonic::client::GrpcService<tonic::body::Body>,
+        T::Error: Into<StdError>,
+        T::ResponseBody: Body<Data = Bytes> + std::marker::Send + 'static,
+        <T::ResponseBody as Body>::Error: Into<StdError> + std::marker::Send,
+    {
+        pub fn new(inner: T) -> Self {
+            let inner = tonic::client::Grpc::new(inner);
+            Self { inner }
+        }
+        pub fn with_origin(inner: T, origin: Uri) -> Self {
+            let inner = tonic::client::Grpc::with_origin(inner, origin);
+            Self { inner }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> PlayerServiceClient<InterceptedService<T, F>>
+        where
+            F: tonic::service::Interceptor,
+            T::ResponseBody: Default,
+            T: tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+                Response = http::Response<
+                    <T as tonic::client::GrpcService<tonic::body::Body>>::ResponseBody,
+                >,
+            >,
+            <T as tonic::codegen::Service<
+                http::Request<tonic::body::Body>,
+            >>::Error: Into<StdError> + std::marker::Send + std::marker::Sync,
+        {
+            PlayerServiceClient::new(InterceptedService::new(inner, interceptor))
+        }
+        /// Compress requests with the given encoding.
+        ///
+        /// This requires the server to support it otherwise it might respond with an
+        /// error.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.send_compressed(encoding);
+            self
+        }
+        /// Enable decompressing responses.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.inner = self.inner.accept_compressed(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_decoding_message_size(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.inner = self.inner.max_encoding_message_size(limit);
+            self
+        }
+        pub async fn play(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PlayRequest>,
+        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Play",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Play"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn play_bvid(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PlayBvidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PlayBvidResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/PlayBvid",
+            );
+            let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "AddPlaylist"));
+                .insert(GrpcMethod::new("player.PlayerService", "PlayBvid"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn deleted(
+        pub async fn pause(
             &mut self,
-            request: impl tonic::IntoRequest<super::DeletedRequest>,
+            request: impl tonic::IntoRequest<super::PauseRequest>,
+        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Pause",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "Pause"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn next(
+            &mut self,
+            request: impl tonic::IntoRequest<super::NextRequest>,
+        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Next",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Next"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn previous(
+            &mut self,
+            request: impl tonic::IntoRequest<super::PreviousRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::DeletedResponse>,
+            tonic::Response<super::PreviousResponse>,
             tonic::Status,
         > {
             self.inner
@@ -430,18 +1142,38 @@ pub mod player_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/Deleted",
+                "/player.PlayerService/Previous",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "Deleted"));
+                .insert(GrpcMethod::new("player.PlayerService", "Previous"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn get_state(
+        pub async fn stop(
             &mut self,
-            request: impl tonic::IntoRequest<super::GetStateRequest>,
+            request: impl tonic::IntoRequest<super::StopRequest>,
+        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Stop",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Stop"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_model(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetModelRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetStateResponse>,
+            tonic::Response<super::SetModelResponse>,
             tonic::Status,
         > {
             self.inner
@@ -454,18 +1186,18 @@ pub mod player_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/GetState",
+                "/player.PlayerService/SetModel",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "GetState"));
+                .insert(GrpcMethod::new("player.PlayerService", "SetModel"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn show_play_list(
+        pub async fn add_playlist(
             &mut self,
-            request: impl tonic::IntoRequest<super::ShowPlayListRequest>,
+            request: impl tonic::IntoRequest<super::AddPlaylistRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ShowPlayListResponse>,
+            tonic::Response<super::AddPlaylistResponse>,
             tonic::Status,
         > {
             self.inner
@@ -478,18 +1210,18 @@ pub mod player_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/ShowPlayList",
+                "/player.PlayerService/AddPlaylist",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "ShowPlayList"));
+                .insert(GrpcMethod::new("player.PlayerService", "AddPlaylist"));
             self.inner.unary(req, path, codec).await
         }
-        pub async fn set_volume(
+        pub async fn deleted(
             &mut self,
-            request: impl tonic::IntoRequest<super::SetVolumeRequest>,
+            request: impl tonic::IntoRequest<super::DeletedRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetVolumeResponse>,
+            tonic::Response<super::DeletedResponse>,
             tonic::Status,
         > {
             self.inner
@@ -502,193 +1234,2129 @@ pub mod player_service_client {
                 })?;
             let codec = tonic_prost::ProstCodec::default();
             let path = http::uri::PathAndQuery::from_static(
-                "/player.PlayerService/SetVolume",
+                "/player.PlayerService/Deleted",
             );
             let mut req = request.into_request();
             req.extensions_mut()
-                .insert(GrpcMethod::new("player.PlayerService", "SetVolume"));
+                .insert(GrpcMethod::new("player.PlayerService", "Deleted"));
             self.inner.unary(req, path, codec).await
         }
-    }
-}
-/// Generated server implementations.
-pub mod player_service_server {
-    #![allow(
-        unused_variables,
-        dead_code,
-        missing_docs,
-        clippy::wildcard_imports,
-        clippy::let_unit_value,
-    )]
-    use tonic::codegen::*;
-    /// Generated trait containing gRPC methods that should be implemented for use with PlayerServiceServer.
-    #[async_trait]
-    pub trait PlayerService: std::marker::Send + std::marker::Sync + 'static {
-        async fn play(
-            &self,
-            request: tonic::Request<super::PlayRequest>,
-        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status>;
-        async fn play_bvid(
-            &self,
-            request: tonic::Request<super::PlayBvidRequest>,
+        pub async fn restore(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RestoreRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PlayBvidResponse>,
+            tonic::Response<super::RestoreResponse>,
             tonic::Status,
-        >;
-        async fn pause(
-            &self,
-            request: tonic::Request<super::PauseRequest>,
-        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status>;
-        async fn next(
-            &self,
-            request: tonic::Request<super::NextRequest>,
-        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status>;
-        async fn previous(
-            &self,
-            request: tonic::Request<super::PreviousRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Restore",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "Restore"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_state(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetStateRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::PreviousResponse>,
+            tonic::Response<super::GetStateResponse>,
             tonic::Status,
-        >;
-        async fn stop(
-            &self,
-            request: tonic::Request<super::StopRequest>,
-        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status>;
-        async fn set_model(
-            &self,
-            request: tonic::Request<super::SetModelRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/GetState",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "GetState"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn show_play_list(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ShowPlayListRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetModelResponse>,
+            tonic::Response<super::ShowPlayListResponse>,
             tonic::Status,
-        >;
-        async fn add_playlist(
-            &self,
-            request: tonic::Request<super::AddPlaylistRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ShowPlayList",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ShowPlayList"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_volume(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetVolumeRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::AddPlaylistResponse>,
+            tonic::Response<super::SetVolumeResponse>,
             tonic::Status,
-        >;
-        async fn deleted(
-            &self,
-            request: tonic::Request<super::DeletedRequest>,
-        ) -> std::result::Result<tonic::Response<super::DeletedResponse>, tonic::Status>;
-        async fn get_state(
-            &self,
-            request: tonic::Request<super::GetStateRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SetVolume",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SetVolume"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn seek(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SeekRequest>,
+        ) -> std::result::Result<tonic::Response<super::SeekResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Seek",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut().insert(GrpcMethod::new("player.PlayerService", "Seek"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_crossfade(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetCrossfadeRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::GetStateResponse>,
+            tonic::Response<super::SetCrossfadeResponse>,
             tonic::Status,
-        >;
-        async fn show_play_list(
-            &self,
-            request: tonic::Request<super::ShowPlayListRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SetCrossfade",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SetCrossfade"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_rate(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetRateRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::ShowPlayListResponse>,
+            tonic::Response<super::SetRateResponse>,
             tonic::Status,
-        >;
-        async fn set_volume(
-            &self,
-            request: tonic::Request<super::SetVolumeRequest>,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SetRate",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SetRate"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_equalizer(
+            &mut self,
+            request: impl tonic::IntoRequest<super::GetEqualizerRequest>,
         ) -> std::result::Result<
-            tonic::Response<super::SetVolumeResponse>,
+            tonic::Response<super::EqualizerResponse>,
             tonic::Status,
-        >;
-    }
-    /// service
-    #[derive(Debug)]
-    pub struct PlayerServiceServer<T> {
-        inner: Arc<T>,
-        accept_compression_encodings: EnabledCompressionEncodings,
-        send_compression_encodings: EnabledCompressionEncodings,
-        max_decoding_message_size: Option<usize>,
-        max_encoding_message_size: Option<usize>,
-    }
-    impl<T> PlayerServiceServer<T> {
-        pub fn new(inner: T) -> Self {
-            Self::from_arc(Arc::new(inner))
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/GetEqualizer",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "GetEqualizer"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn from_arc(inner: Arc<T>) -> Self {
-            Self {
-                inner,
-                accept_compression_encodings: Default::default(),
-                send_compression_encodings: Default::default(),
-                max_decoding_message_size: None,
-                max_encoding_message_size: None,
-            }
+        pub async fn set_equalizer_band(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetEqualizerBandRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SetEqualizerBand",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SetEqualizerBand"));
+            self.inner.unary(req, path, codec).await
         }
-        pub fn with_interceptor<F>(
-            inner: T,
-            interceptor: F,
-        ) -> InterceptedService<Self, F>
-        where
-            F: tonic::service::Interceptor,
-        {
-            InterceptedService::new(Self::new(inner), interceptor)
+        pub async fn apply_equalizer_preset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ApplyEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ApplyEqualizerPreset",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ApplyEqualizerPreset"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Enable decompressing requests with the given encoding.
-        #[must_use]
-        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.accept_compression_encodings.enable(encoding);
-            self
+        pub async fn save_equalizer_preset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SaveEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SaveEqualizerPreset",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SaveEqualizerPreset"));
+            self.inner.unary(req, path, codec).await
         }
-        /// Compress responses with the given encoding, if the client supports it.
-        #[must_use]
-        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
-            self.send_compression_encodings.enable(encoding);
-            self
+        pub async fn delete_equalizer_preset(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeleteEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/DeleteEqualizerPreset",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(
+                    GrpcMethod::new("player.PlayerService", "DeleteEqualizerPreset"),
+                );
+            self.inner.unary(req, path, codec).await
         }
-        /// Limits the maximum size of a decoded message.
-        ///
-        /// Default: `4MB`
-        #[must_use]
-        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
-            self.max_decoding_message_size = Some(limit);
-            self
+        pub async fn subscribe_events(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SubscribeEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<tonic::codec::Streaming<super::SubscribeEventsResponse>>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SubscribeEvents",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SubscribeEvents"));
+            self.inner.server_streaming(req, path, codec).await
         }
-        /// Limits the maximum size of an encoded message.
-        ///
-        /// Default: `usize::MAX`
-        #[must_use]
-        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
-            self.max_encoding_message_size = Some(limit);
-            self
+        pub async fn create_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::CreatePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreatePlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/CreatePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "CreatePlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-    }
-    impl<T, B> tonic::codegen::Service<http::Request<B>> for PlayerServiceServer<T>
-    where
-        T: PlayerService,
-        B: Body + std::marker::Send + 'static,
-        B::Error: Into<StdError> + std::marker::Send + 'static,
-    {
-        type Response = http::Response<tonic::body::Body>;
-        type Error = std::convert::Infallible;
-        type Future = BoxFuture<Self::Response, Self::Error>;
-        fn poll_ready(
+        pub async fn rename_playlist(
             &mut self,
-            _cx: &mut Context<'_>,
-        ) -> Poll<std::result::Result<(), Self::Error>> {
-            Poll::Ready(Ok(()))
+            request: impl tonic::IntoRequest<super::RenamePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenamePlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/RenamePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "RenamePlaylist"));
+            self.inner.unary(req, path, codec).await
         }
-        fn call(&mut self, req: http::Request<B>) -> Self::Future {
-            match req.uri().path() {
-                "/player.PlayerService/Play" => {
+        pub async fn delete_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::DeletePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/DeletePlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "DeletePlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn list_playlists(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ListPlaylistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPlaylistsResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ListPlaylists",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ListPlaylists"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn switch_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SwitchPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwitchPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SwitchPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SwitchPlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn add_to_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::AddToPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/AddToPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "AddToPlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn remove_from_playlist(
+            &mut self,
+            request: impl tonic::IntoRequest<super::RemoveFromPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromPlaylistResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/RemoveFromPlaylist",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "RemoveFromPlaylist"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn import_favorite(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportFavoriteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportFavoriteResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ImportFavorite",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ImportFavorite"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn import_season(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportSeasonRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportVideosResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ImportSeason",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ImportSeason"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn import_uploader(
+            &mut self,
+            request: impl tonic::IntoRequest<super::ImportUploaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportVideosResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/ImportUploader",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "ImportUploader"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn search(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SearchRequest>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Search",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "Search"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn find_music(
+            &mut self,
+            request: impl tonic::IntoRequest<super::FindMusicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindMusicResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/FindMusic",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "FindMusic"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn get_login_status(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LoginStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/GetLoginStatus",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "GetLoginStatus"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn set_credentials(
+            &mut self,
+            request: impl tonic::IntoRequest<super::SetCredentialsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/SetCredentials",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "SetCredentials"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn qr_login_start(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QrLoginStartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QrLoginStartResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/QrLoginStart",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "QrLoginStart"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn qr_login_poll(
+            &mut self,
+            request: impl tonic::IntoRequest<super::QrLoginPollRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QrLoginPollResponse>,
+            tonic::Status,
+        > {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/QrLoginPoll",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "QrLoginPoll"));
+            self.inner.unary(req, path, codec).await
+        }
+        pub async fn logout(
+            &mut self,
+            request: impl tonic::IntoRequest<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status> {
+            self.inner
+                .ready()
+                .await
+                .map_err(|e| {
+                    tonic::Status::unknown(
+                        format!("Service was not ready: {}", e.into()),
+                    )
+                })?;
+            let codec = tonic_prost::ProstCodec::default();
+            let path = http::uri::PathAndQuery::from_static(
+                "/player.PlayerService/Logout",
+            );
+            let mut req = request.into_request();
+            req.extensions_mut()
+                .insert(GrpcMethod::new("player.PlayerService", "Logout"));
+            self.inner.unary(req, path, codec).await
+        }
+    }
+}
+/// Generated server implementations.
+pub mod player_service_server {
+    #![allow(
+        unused_variables,
+        dead_code,
+        missing_docs,
+        clippy::wildcard_imports,
+        clippy::let_unit_value,
+    )]
+    use tonic::codegen::*;
+    /// Generated trait containing gRPC methods that should be implemented for use with PlayerServiceServer.
+    #[async_trait]
+    pub trait PlayerService: std::marker::Send + std::marker::Sync + 'static {
+        async fn play(
+            &self,
+            request: tonic::Request<super::PlayRequest>,
+        ) -> std::result::Result<tonic::Response<super::PlayResponse>, tonic::Status>;
+        async fn play_bvid(
+            &self,
+            request: tonic::Request<super::PlayBvidRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PlayBvidResponse>,
+            tonic::Status,
+        >;
+        async fn pause(
+            &self,
+            request: tonic::Request<super::PauseRequest>,
+        ) -> std::result::Result<tonic::Response<super::PauseResponse>, tonic::Status>;
+        async fn next(
+            &self,
+            request: tonic::Request<super::NextRequest>,
+        ) -> std::result::Result<tonic::Response<super::NextResponse>, tonic::Status>;
+        async fn previous(
+            &self,
+            request: tonic::Request<super::PreviousRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::PreviousResponse>,
+            tonic::Status,
+        >;
+        async fn stop(
+            &self,
+            request: tonic::Request<super::StopRequest>,
+        ) -> std::result::Result<tonic::Response<super::StopResponse>, tonic::Status>;
+        async fn set_model(
+            &self,
+            request: tonic::Request<super::SetModelRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetModelResponse>,
+            tonic::Status,
+        >;
+        async fn add_playlist(
+            &self,
+            request: tonic::Request<super::AddPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn deleted(
+            &self,
+            request: tonic::Request<super::DeletedRequest>,
+        ) -> std::result::Result<tonic::Response<super::DeletedResponse>, tonic::Status>;
+        async fn restore(
+            &self,
+            request: tonic::Request<super::RestoreRequest>,
+        ) -> std::result::Result<tonic::Response<super::RestoreResponse>, tonic::Status>;
+        async fn get_state(
+            &self,
+            request: tonic::Request<super::GetStateRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::GetStateResponse>,
+            tonic::Status,
+        >;
+        async fn show_play_list(
+            &self,
+            request: tonic::Request<super::ShowPlayListRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ShowPlayListResponse>,
+            tonic::Status,
+        >;
+        async fn set_volume(
+            &self,
+            request: tonic::Request<super::SetVolumeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetVolumeResponse>,
+            tonic::Status,
+        >;
+        async fn seek(
+            &self,
+            request: tonic::Request<super::SeekRequest>,
+        ) -> std::result::Result<tonic::Response<super::SeekResponse>, tonic::Status>;
+        async fn set_crossfade(
+            &self,
+            request: tonic::Request<super::SetCrossfadeRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SetCrossfadeResponse>,
+            tonic::Status,
+        >;
+        async fn set_rate(
+            &self,
+            request: tonic::Request<super::SetRateRequest>,
+        ) -> std::result::Result<tonic::Response<super::SetRateResponse>, tonic::Status>;
+        async fn get_equalizer(
+            &self,
+            request: tonic::Request<super::GetEqualizerRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        >;
+        async fn set_equalizer_band(
+            &self,
+            request: tonic::Request<super::SetEqualizerBandRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        >;
+        async fn apply_equalizer_preset(
+            &self,
+            request: tonic::Request<super::ApplyEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        >;
+        async fn save_equalizer_preset(
+            &self,
+            request: tonic::Request<super::SaveEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        >;
+        async fn delete_equalizer_preset(
+            &self,
+            request: tonic::Request<super::DeleteEqualizerPresetRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::EqualizerResponse>,
+            tonic::Status,
+        >;
+        /// Server streaming response type for the SubscribeEvents method.
+        type SubscribeEventsStream: tonic::codegen::tokio_stream::Stream<
+                Item = std::result::Result<super::SubscribeEventsResponse, tonic::Status>,
+            >
+            + std::marker::Send
+            + 'static;
+        async fn subscribe_events(
+            &self,
+            request: tonic::Request<super::SubscribeEventsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<Self::SubscribeEventsStream>,
+            tonic::Status,
+        >;
+        async fn create_playlist(
+            &self,
+            request: tonic::Request<super::CreatePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::CreatePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn rename_playlist(
+            &self,
+            request: tonic::Request<super::RenamePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RenamePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn delete_playlist(
+            &self,
+            request: tonic::Request<super::DeletePlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::DeletePlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn list_playlists(
+            &self,
+            request: tonic::Request<super::ListPlaylistsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ListPlaylistsResponse>,
+            tonic::Status,
+        >;
+        async fn switch_playlist(
+            &self,
+            request: tonic::Request<super::SwitchPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::SwitchPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn add_to_playlist(
+            &self,
+            request: tonic::Request<super::AddToPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::AddToPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn remove_from_playlist(
+            &self,
+            request: tonic::Request<super::RemoveFromPlaylistRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::RemoveFromPlaylistResponse>,
+            tonic::Status,
+        >;
+        async fn import_favorite(
+            &self,
+            request: tonic::Request<super::ImportFavoriteRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportFavoriteResponse>,
+            tonic::Status,
+        >;
+        async fn import_season(
+            &self,
+            request: tonic::Request<super::ImportSeasonRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportVideosResponse>,
+            tonic::Status,
+        >;
+        async fn import_uploader(
+            &self,
+            request: tonic::Request<super::ImportUploaderRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::ImportVideosResponse>,
+            tonic::Status,
+        >;
+        async fn search(
+            &self,
+            request: tonic::Request<super::SearchRequest>,
+        ) -> std::result::Result<tonic::Response<super::SearchResponse>, tonic::Status>;
+        async fn find_music(
+            &self,
+            request: tonic::Request<super::FindMusicRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::FindMusicResponse>,
+            tonic::Status,
+        >;
+        async fn get_login_status(
+            &self,
+            request: tonic::Request<super::LoginStatusRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        >;
+        async fn set_credentials(
+            &self,
+            request: tonic::Request<super::SetCredentialsRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::LoginStatusResponse>,
+            tonic::Status,
+        >;
+        async fn qr_login_start(
+            &self,
+            request: tonic::Request<super::QrLoginStartRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QrLoginStartResponse>,
+            tonic::Status,
+        >;
+        async fn qr_login_poll(
+            &self,
+            request: tonic::Request<super::QrLoginPollRequest>,
+        ) -> std::result::Result<
+            tonic::Response<super::QrLoginPollResponse>,
+            tonic::Status,
+        >;
+        async fn logout(
+            &self,
+            request: tonic::Request<super::LogoutRequest>,
+        ) -> std::result::Result<tonic::Response<super::LogoutResponse>, tonic::Status>;
+    }
+    /// service
+    #[derive(Debug)]
+    pub struct PlayerServiceServer<T> {
+        inner: Arc<T>,
+        accept_compression_encodings: EnabledCompressionEncodings,
+        send_compression_encodings: EnabledCompressionEncodings,
+        max_decoding_message_size: Option<usize>,
+        max_encoding_message_size: Option<usize>,
+    }
+    impl<T> PlayerServiceServer<T> {
+        pub fn new(inner: T) -> Self {
+            Self::from_arc(Arc::new(inner))
+        }
+        pub fn from_arc(inner: Arc<T>) -> Self {
+            Self {
+                inner,
+                accept_compression_encodings: Default::default(),
+                send_compression_encodings: Default::default(),
+                max_decoding_message_size: None,
+                max_encoding_message_size: None,
+            }
+        }
+        pub fn with_interceptor<F>(
+            inner: T,
+            interceptor: F,
+        ) -> InterceptedService<Self, F>
+        where
+            F: tonic::service::Interceptor,
+        {
+            InterceptedService::new(Self::new(inner), interceptor)
+        }
+        /// Enable decompressing requests with the given encoding.
+        #[must_use]
+        pub fn accept_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.accept_compression_encodings.enable(encoding);
+            self
+        }
+        /// Compress responses with the given encoding, if the client supports it.
+        #[must_use]
+        pub fn send_compressed(mut self, encoding: CompressionEncoding) -> Self {
+            self.send_compression_encodings.enable(encoding);
+            self
+        }
+        /// Limits the maximum size of a decoded message.
+        ///
+        /// Default: `4MB`
+        #[must_use]
+        pub fn max_decoding_message_size(mut self, limit: usize) -> Self {
+            self.max_decoding_message_size = Some(limit);
+            self
+        }
+        /// Limits the maximum size of an encoded message.
+        ///
+        /// Default: `usize::MAX`
+        #[must_use]
+        pub fn max_encoding_message_size(mut self, limit: usize) -> Self {
+            self.max_encoding_message_size = Some(limit);
+            self
+        }
+    }
+    impl<T, B> tonic::codegen::Service<http::Request<B>> for PlayerServiceServer<T>
+    where
+        T: PlayerService,
+        B: Body + std::marker::Send + 'static,
+        B::Error: Into<StdError> + std::marker::Send + 'static,
+    {
+        type Response = http::Response<tonic::body::Body>;
+        type Error = std::convert::Infallible;
+        type Future = BoxFuture<Self::Response, Self::Error>;
+        fn poll_ready(
+            &mut self,
+            _cx: &mut Context<'_>,
+        ) -> Poll<std::result::Result<(), Self::Error>> {
+            Poll::Ready(Ok(()))
+        }
+        fn call(&mut self, req: http::Request<B>) -> Self::Future {
+            match req.uri().path() {
+                "/player.PlayerService/Play" => {
+                    #[allow(non_camel_case_types)]
+                    struct PlaySvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PlayRequest> for PlaySvc<T> {
+                        type Response = super::PlayResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PlayRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::play(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PlaySvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/PlayBvid" => {
+                    #[allow(non_camel_case_types)]
+                    struct PlayBvidSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PlayBvidRequest>
+                    for PlayBvidSvc<T> {
+                        type Response = super::PlayBvidResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PlayBvidRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::play_bvid(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PlayBvidSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Pause" => {
+                    #[allow(non_camel_case_types)]
+                    struct PauseSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PauseRequest> for PauseSvc<T> {
+                        type Response = super::PauseResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PauseRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::pause(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PauseSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Next" => {
+                    #[allow(non_camel_case_types)]
+                    struct NextSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::NextRequest> for NextSvc<T> {
+                        type Response = super::NextResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::NextRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::next(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = NextSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Previous" => {
+                    #[allow(non_camel_case_types)]
+                    struct PreviousSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::PreviousRequest>
+                    for PreviousSvc<T> {
+                        type Response = super::PreviousResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::PreviousRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::previous(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = PreviousSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Stop" => {
+                    #[allow(non_camel_case_types)]
+                    struct StopSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::StopRequest> for StopSvc<T> {
+                        type Response = super::StopResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::StopRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::stop(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = StopSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetModel" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetModelSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetModelRequest>
+                    for SetModelSvc<T> {
+                        type Response = super::SetModelResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetModelRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_model(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetModelSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/AddPlaylist" => {
+                    #[allow(non_camel_case_types)]
+                    struct AddPlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::AddPlaylistRequest>
+                    for AddPlaylistSvc<T> {
+                        type Response = super::AddPlaylistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::AddPlaylistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::add_playlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = AddPlaylistSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Deleted" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeletedSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::DeletedRequest>
+                    for DeletedSvc<T> {
+                        type Response = super::DeletedResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeletedRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::deleted(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeletedSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Restore" => {
+                    #[allow(non_camel_case_types)]
+                    struct RestoreSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::RestoreRequest>
+                    for RestoreSvc<T> {
+                        type Response = super::RestoreResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::RestoreRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::restore(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = RestoreSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/GetState" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetStateSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::GetStateRequest>
+                    for GetStateSvc<T> {
+                        type Response = super::GetStateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetStateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::get_state(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetStateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/ShowPlayList" => {
+                    #[allow(non_camel_case_types)]
+                    struct ShowPlayListSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::ShowPlayListRequest>
+                    for ShowPlayListSvc<T> {
+                        type Response = super::ShowPlayListResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ShowPlayListRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::show_play_list(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ShowPlayListSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetVolume" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetVolumeSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetVolumeRequest>
+                    for SetVolumeSvc<T> {
+                        type Response = super::SetVolumeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetVolumeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_volume(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetVolumeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Seek" => {
+                    #[allow(non_camel_case_types)]
+                    struct SeekSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SeekRequest> for SeekSvc<T> {
+                        type Response = super::SeekResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SeekRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::seek(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SeekSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetCrossfade" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetCrossfadeSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetCrossfadeRequest>
+                    for SetCrossfadeSvc<T> {
+                        type Response = super::SetCrossfadeResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetCrossfadeRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_crossfade(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetCrossfadeSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetRate" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetRateSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetRateRequest>
+                    for SetRateSvc<T> {
+                        type Response = super::SetRateResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetRateRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_rate(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetRateSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/GetEqualizer" => {
+                    #[allow(non_camel_case_types)]
+                    struct GetEqualizerSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::GetEqualizerRequest>
+                    for GetEqualizerSvc<T> {
+                        type Response = super::EqualizerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::GetEqualizerRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::get_equalizer(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = GetEqualizerSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetEqualizerBand" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetEqualizerBandSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetEqualizerBandRequest>
+                    for SetEqualizerBandSvc<T> {
+                        type Response = super::EqualizerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetEqualizerBandRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_equalizer_band(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetEqualizerBandSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/ApplyEqualizerPreset" => {
+                    #[allow(non_camel_case_types)]
+                    struct ApplyEqualizerPresetSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::ApplyEqualizerPresetRequest>
+                    for ApplyEqualizerPresetSvc<T> {
+                        type Response = super::EqualizerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::ApplyEqualizerPresetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::apply_equalizer_preset(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = ApplyEqualizerPresetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SaveEqualizerPreset" => {
+                    #[allow(non_camel_case_types)]
+                    struct SaveEqualizerPresetSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SaveEqualizerPresetRequest>
+                    for SaveEqualizerPresetSvc<T> {
+                        type Response = super::EqualizerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SaveEqualizerPresetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::save_equalizer_preset(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SaveEqualizerPresetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/DeleteEqualizerPreset" => {
+                    #[allow(non_camel_case_types)]
+                    struct DeleteEqualizerPresetSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::DeleteEqualizerPresetRequest>
+                    for DeleteEqualizerPresetSvc<T> {
+                        type Response = super::EqualizerResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::DeleteEqualizerPresetRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::delete_equalizer_preset(
+                                        &inner,
+                                        request,
+                                    )
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = DeleteEqualizerPresetSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SubscribeEvents" => {
+                    #[allow(non_camel_case_types)]
+                    struct SubscribeEventsSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::ServerStreamingService<
+                        super::SubscribeEventsRequest,
+                    > for SubscribeEventsSvc<T> {
+                        type Response = super::SubscribeEventsResponse;
+                        type ResponseStream = T::SubscribeEventsStream;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::ResponseStream>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SubscribeEventsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::subscribe_events(&inner, request)
+                                    .await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SubscribeEventsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.server_streaming(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/CreatePlaylist" => {
+                    #[allow(non_camel_case_types)]
+                    struct CreatePlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::CreatePlaylistRequest>
+                    for CreatePlaylistSvc<T> {
+                        type Response = super::CreatePlaylistResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::CreatePlaylistRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::create_playlist(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = CreatePlaylistSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/RenamePlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct PlaySvc<T: PlayerService>(pub Arc<T>);
+                    struct RenamePlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PlayRequest> for PlaySvc<T> {
-                        type Response = super::PlayResponse;
+                    > tonic::server::UnaryService<super::RenamePlaylistRequest>
+                    for RenamePlaylistSvc<T> {
+                        type Response = super::RenamePlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PlayRequest>,
+                            request: tonic::Request<super::RenamePlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::play(&inner, request).await
+                                <T as PlayerService>::rename_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -699,7 +3367,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PlaySvc(inner);
+                        let method = RenamePlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -715,25 +3383,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/PlayBvid" => {
+                "/player.PlayerService/DeletePlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct PlayBvidSvc<T: PlayerService>(pub Arc<T>);
+                    struct DeletePlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PlayBvidRequest>
-                    for PlayBvidSvc<T> {
-                        type Response = super::PlayBvidResponse;
+                    > tonic::server::UnaryService<super::DeletePlaylistRequest>
+                    for DeletePlaylistSvc<T> {
+                        type Response = super::DeletePlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PlayBvidRequest>,
+                            request: tonic::Request<super::DeletePlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::play_bvid(&inner, request).await
+                                <T as PlayerService>::delete_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -744,7 +3412,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PlayBvidSvc(inner);
+                        let method = DeletePlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -760,24 +3428,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Pause" => {
+                "/player.PlayerService/ListPlaylists" => {
                     #[allow(non_camel_case_types)]
-                    struct PauseSvc<T: PlayerService>(pub Arc<T>);
+                    struct ListPlaylistsSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PauseRequest> for PauseSvc<T> {
-                        type Response = super::PauseResponse;
+                    > tonic::server::UnaryService<super::ListPlaylistsRequest>
+                    for ListPlaylistsSvc<T> {
+                        type Response = super::ListPlaylistsResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PauseRequest>,
+                            request: tonic::Request<super::ListPlaylistsRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::pause(&inner, request).await
+                                <T as PlayerService>::list_playlists(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -788,7 +3457,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PauseSvc(inner);
+                        let method = ListPlaylistsSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -804,24 +3473,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Next" => {
+                "/player.PlayerService/SwitchPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct NextSvc<T: PlayerService>(pub Arc<T>);
+                    struct SwitchPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::NextRequest> for NextSvc<T> {
-                        type Response = super::NextResponse;
+                    > tonic::server::UnaryService<super::SwitchPlaylistRequest>
+                    for SwitchPlaylistSvc<T> {
+                        type Response = super::SwitchPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::NextRequest>,
+                            request: tonic::Request<super::SwitchPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::next(&inner, request).await
+                                <T as PlayerService>::switch_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -832,7 +3502,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = NextSvc(inner);
+                        let method = SwitchPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -848,25 +3518,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Previous" => {
+                "/player.PlayerService/AddToPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct PreviousSvc<T: PlayerService>(pub Arc<T>);
+                    struct AddToPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::PreviousRequest>
-                    for PreviousSvc<T> {
-                        type Response = super::PreviousResponse;
+                    > tonic::server::UnaryService<super::AddToPlaylistRequest>
+                    for AddToPlaylistSvc<T> {
+                        type Response = super::AddToPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::PreviousRequest>,
+                            request: tonic::Request<super::AddToPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::previous(&inner, request).await
+                                <T as PlayerService>::add_to_playlist(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -877,7 +3547,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = PreviousSvc(inner);
+                        let method = AddToPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -893,24 +3563,26 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Stop" => {
+                "/player.PlayerService/RemoveFromPlaylist" => {
                     #[allow(non_camel_case_types)]
-                    struct StopSvc<T: PlayerService>(pub Arc<T>);
+                    struct RemoveFromPlaylistSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::StopRequest> for StopSvc<T> {
-                        type Response = super::StopResponse;
+                    > tonic::server::UnaryService<super::RemoveFromPlaylistRequest>
+                    for RemoveFromPlaylistSvc<T> {
+                        type Response = super::RemoveFromPlaylistResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::StopRequest>,
+                            request: tonic::Request<super::RemoveFromPlaylistRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::stop(&inner, request).await
+                                <T as PlayerService>::remove_from_playlist(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -921,7 +3593,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = StopSvc(inner);
+                        let method = RemoveFromPlaylistSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -937,25 +3609,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/SetModel" => {
+                "/player.PlayerService/ImportFavorite" => {
                     #[allow(non_camel_case_types)]
-                    struct SetModelSvc<T: PlayerService>(pub Arc<T>);
+                    struct ImportFavoriteSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::SetModelRequest>
-                    for SetModelSvc<T> {
-                        type Response = super::SetModelResponse;
+                    > tonic::server::UnaryService<super::ImportFavoriteRequest>
+                    for ImportFavoriteSvc<T> {
+                        type Response = super::ImportFavoriteResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetModelRequest>,
+                            request: tonic::Request<super::ImportFavoriteRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::set_model(&inner, request).await
+                                <T as PlayerService>::import_favorite(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -966,7 +3638,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetModelSvc(inner);
+                        let method = ImportFavoriteSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -982,25 +3654,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/AddPlaylist" => {
+                "/player.PlayerService/ImportSeason" => {
                     #[allow(non_camel_case_types)]
-                    struct AddPlaylistSvc<T: PlayerService>(pub Arc<T>);
+                    struct ImportSeasonSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::AddPlaylistRequest>
-                    for AddPlaylistSvc<T> {
-                        type Response = super::AddPlaylistResponse;
+                    > tonic::server::UnaryService<super::ImportSeasonRequest>
+                    for ImportSeasonSvc<T> {
+                        type Response = super::ImportVideosResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::AddPlaylistRequest>,
+                            request: tonic::Request<super::ImportSeasonRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::add_playlist(&inner, request).await
+                                <T as PlayerService>::import_season(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1011,7 +3683,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = AddPlaylistSvc(inner);
+                        let method = ImportSeasonSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1027,25 +3699,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/Deleted" => {
+                "/player.PlayerService/ImportUploader" => {
                     #[allow(non_camel_case_types)]
-                    struct DeletedSvc<T: PlayerService>(pub Arc<T>);
+                    struct ImportUploaderSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::DeletedRequest>
-                    for DeletedSvc<T> {
-                        type Response = super::DeletedResponse;
+                    > tonic::server::UnaryService<super::ImportUploaderRequest>
+                    for ImportUploaderSvc<T> {
+                        type Response = super::ImportVideosResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::DeletedRequest>,
+                            request: tonic::Request<super::ImportUploaderRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::deleted(&inner, request).await
+                                <T as PlayerService>::import_uploader(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1056,7 +3728,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = DeletedSvc(inner);
+                        let method = ImportUploaderSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1072,25 +3744,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/GetState" => {
+                "/player.PlayerService/Search" => {
                     #[allow(non_camel_case_types)]
-                    struct GetStateSvc<T: PlayerService>(pub Arc<T>);
+                    struct SearchSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::GetStateRequest>
-                    for GetStateSvc<T> {
-                        type Response = super::GetStateResponse;
+                    > tonic::server::UnaryService<super::SearchRequest>
+                    for SearchSvc<T> {
+                        type Response = super::SearchResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::GetStateRequest>,
+                            request: tonic::Request<super::SearchRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::get_state(&inner, request).await
+                                <T as PlayerService>::search(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1101,7 +3773,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = GetStateSvc(inner);
+                        let method = SearchSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1117,25 +3789,25 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/ShowPlayList" => {
+                "/player.PlayerService/FindMusic" => {
                     #[allow(non_camel_case_types)]
-                    struct ShowPlayListSvc<T: PlayerService>(pub Arc<T>);
+                    struct FindMusicSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::ShowPlayListRequest>
-                    for ShowPlayListSvc<T> {
-                        type Response = super::ShowPlayListResponse;
+                    > tonic::server::UnaryService<super::FindMusicRequest>
+                    for FindMusicSvc<T> {
+                        type Response = super::FindMusicResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::ShowPlayListRequest>,
+                            request: tonic::Request<super::FindMusicRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::show_play_list(&inner, request).await
+                                <T as PlayerService>::find_music(&inner, request).await
                             };
                             Box::pin(fut)
                         }
@@ -1146,7 +3818,7 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = ShowPlayListSvc(inner);
+                        let method = FindMusicSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
@@ -1162,25 +3834,26 @@ pub mod player_service_server {
                     };
                     Box::pin(fut)
                 }
-                "/player.PlayerService/SetVolume" => {
+                "/player.PlayerService/GetLoginStatus" => {
                     #[allow(non_camel_case_types)]
-                    struct SetVolumeSvc<T: PlayerService>(pub Arc<T>);
+                    struct GetLoginStatusSvc<T: PlayerService>(pub Arc<T>);
                     impl<
                         T: PlayerService,
-                    > tonic::server::UnaryService<super::SetVolumeRequest>
-                    for SetVolumeSvc<T> {
-                        type Response = super::SetVolumeResponse;
+                    > tonic::server::UnaryService<super::LoginStatusRequest>
+                    for GetLoginStatusSvc<T> {
+                        type Response = super::LoginStatusResponse;
                         type Future = BoxFuture<
                             tonic::Response<Self::Response>,
                             tonic::Status,
                         >;
                         fn call(
                             &mut self,
-                            request: tonic::Request<super::SetVolumeRequest>,
+                            request: tonic::Request<super::LoginStatusRequest>,
                         ) -> Self::Future {
                             let inner = Arc::clone(&self.0);
                             let fut = async move {
-                                <T as PlayerService>::set_volume(&inner, request).await
+                                <T as PlayerService>::get_login_status(&inner, request)
+                                    .await
                             };
                             Box::pin(fut)
                         }
@@ -1191,7 +3864,187 @@ pub mod player_service_server {
                     let max_encoding_message_size = self.max_encoding_message_size;
                     let inner = self.inner.clone();
                     let fut = async move {
-                        let method = SetVolumeSvc(inner);
+                        let method = GetLoginStatusSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/SetCredentials" => {
+                    #[allow(non_camel_case_types)]
+                    struct SetCredentialsSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::SetCredentialsRequest>
+                    for SetCredentialsSvc<T> {
+                        type Response = super::LoginStatusResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::SetCredentialsRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::set_credentials(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = SetCredentialsSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/QrLoginStart" => {
+                    #[allow(non_camel_case_types)]
+                    struct QrLoginStartSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::QrLoginStartRequest>
+                    for QrLoginStartSvc<T> {
+                        type Response = super::QrLoginStartResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QrLoginStartRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::qr_login_start(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QrLoginStartSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/QrLoginPoll" => {
+                    #[allow(non_camel_case_types)]
+                    struct QrLoginPollSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::QrLoginPollRequest>
+                    for QrLoginPollSvc<T> {
+                        type Response = super::QrLoginPollResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::QrLoginPollRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::qr_login_poll(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = QrLoginPollSvc(inner);
+                        let codec = tonic_prost::ProstCodec::default();
+                        let mut grpc = tonic::server::Grpc::new(codec)
+                            .apply_compression_config(
+                                accept_compression_encodings,
+                                send_compression_encodings,
+                            )
+                            .apply_max_message_size_config(
+                                max_decoding_message_size,
+                                max_encoding_message_size,
+                            );
+                        let res = grpc.unary(method, req).await;
+                        Ok(res)
+                    };
+                    Box::pin(fut)
+                }
+                "/player.PlayerService/Logout" => {
+                    #[allow(non_camel_case_types)]
+                    struct LogoutSvc<T: PlayerService>(pub Arc<T>);
+                    impl<
+                        T: PlayerService,
+                    > tonic::server::UnaryService<super::LogoutRequest>
+                    for LogoutSvc<T> {
+                        type Response = super::LogoutResponse;
+                        type Future = BoxFuture<
+                            tonic::Response<Self::Response>,
+                            tonic::Status,
+                        >;
+                        fn call(
+                            &mut self,
+                            request: tonic::Request<super::LogoutRequest>,
+                        ) -> Self::Future {
+                            let inner = Arc::clone(&self.0);
+                            let fut = async move {
+                                <T as PlayerService>::logout(&inner, request).await
+                            };
+                            Box::pin(fut)
+                        }
+                    }
+                    let accept_compression_encodings = self.accept_compression_encodings;
+                    let send_compression_encodings = self.send_compression_encodings;
+                    let max_decoding_message_size = self.max_decoding_message_size;
+                    let max_encoding_message_size = self.max_encoding_message_size;
+                    let inner = self.inner.clone();
+                    let fut = async move {
+                        let method = LogoutSvc(inner);
                         let codec = tonic_prost::ProstCodec::default();
                         let mut grpc = tonic::server::Grpc::new(codec)
                             .apply_compression_config(
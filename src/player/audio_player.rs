@@ -1,112 +1,148 @@
 use crate::{
     errors::ApplicationError,
-    fetch::verify::fetch_and_verify_audio_url,
+    fetch::{
+        network::BILIBILI_API_BASE,
+        quality::{AudioQuality, AudioStream, current_audio_preference},
+        verify::fetch_and_verify_audio_url,
+    },
+    library::{
+        import::{PageSelection, import_bvid, import_favorite_folder, import_videos},
+        playlist_repository::{PlaylistRecord, PlaylistRepository, PlaylistSummary},
+        repository::MusicRepository,
+        session_repository::{SessionRecord, SessionRepository},
+    },
+    pb::{AddToPlaylistRequest, RemoveFromPlaylistRequest},
     player::{
-        command::{PlayMode, PlayerCommand},
+        command::{EqualizerAction, PlayMode, PlayerCommand, SeekTarget, VolumeAction},
+        crossfade::Crossfade,
+        equalizer::equalizer,
+        event::{PlayerEvent, PlaylistAction},
+        loudness::{
+            NormalizationMode, current_normalization, load_measured_loudness, measured_loudness,
+            playlist_loudness, record_loudness,
+        },
+        mixer::{
+            PreloadedTrack, TrackBranch, add_track_branch, build_output_chain, sync_branch_state,
+            track_branches,
+        },
         play_list::{
-            PLAYLIST, get_current_music, move_to_next_music, move_to_previous_music,
+            CURRENT_MUSIC_INDEX, PLAYLIST, Playlist, add_music_to_playlist, find_music_in_playlist,
+            get_current_music, get_playlist_page, move_to_next_music, move_to_previous_music,
+            peek_next_music, playlist_len, remove_music_from_playlist, replace_playlist,
             set_current_music_index,
         },
+        rate::{RateSettings, playback_rate},
+        session::{PlaybackSession, ResumeMode},
+        state::{Music, PlaybackState, PlayerStateSnapshot},
+        volume::Volume,
     },
+    utils::unix_now,
 };
 use futures_util::StreamExt;
 use gstreamer::{
     MessageView,
-    prelude::{ElementExt, GstBinExt, GstBinExtManual, GstObjectExt, PadExt},
+    prelude::{ElementExt, GstBinExt, GstBinExtManual, GstObjectExt},
+};
+use gstreamer::{
+    glib::object::{Cast, ObjectExt},
+    prelude::ElementExtManual,
 };
-use gstreamer::{glib::object::ObjectExt, prelude::ElementExtManual};
-use std::sync::Arc;
-use std::sync::atomic::{AtomicU32, Ordering};
-use tokio::sync::{Mutex, RwLock, mpsc};
+use once_cell::sync::Lazy;
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{Mutex, RwLock, broadcast, mpsc};
+
+// 播放过程中定时保存播放位置的间隔
+const SESSION_SAVE_INTERVAL: Duration = Duration::from_secs(10);
+// 音频地址在这个时间内过期时，继续播放或跳转前先刷新地址
+const URL_REFRESH_MARGIN: Duration = Duration::from_secs(60);
+// 当前歌曲使用的音频流，用于状态和事件，播放中地址失效时从中切换备用地址
+static CURRENT_AUDIO_STREAM: Lazy<Mutex<Option<AudioStream>>> = Lazy::new(|| Mutex::new(None));
+// 当前歌曲剩余时间少于这个值 (再加上淡入淡出时长) 时预加载下一首
+const PRELOAD_AHEAD: Duration = Duration::from_secs(20);
+// 检查预加载、切换歌曲和更新淡入淡出音量的间隔
+const TRACK_MONITOR_INTERVAL: Duration = Duration::from_millis(50);
 // 用来存放播放状态
 #[derive(Clone)]
 pub struct AudioPlayer {
     pub pipeline: Arc<gstreamer::Pipeline>,
     pub client: Arc<reqwest::Client>,
+    pub repository: MusicRepository,      // 音乐库
+    pub playlists: PlaylistRepository,    // 歌单库
+    pub sessions: SessionRepository,      // 播放会话，用于重启后恢复
+    pub resume_position: Option<f64>,     // 启动时恢复的播放位置 (秒)
     pub play_mode: Arc<RwLock<PlayMode>>, // 播放模式，如 "Normal", "Shuffle", "Repeat"
-    pub volume: Arc<AtomicU32>,           // 使用原子整型存储音量
+    pub volume: Arc<Volume>,              // 音量，切换歌曲时保持不变
+    pub crossfade: Arc<Crossfade>,        // 切换歌曲时的淡入淡出时长
     pub command_receiver: Arc<Mutex<mpsc::Receiver<PlayerCommand>>>, // 命令接收器
     pub eos_sender: mpsc::Sender<()>,     // 结束信号发送器
+    pub event_sender: broadcast::Sender<PlayerEvent>, // 事件发送器
 }
 
 impl AudioPlayer {
+    /// 创建播放器，并从上一次保存的播放会话中恢复播放模式、音量和当前歌曲
+    ///
+    /// client 由 AuthSession 创建，登录后可以获取会员音质
     pub async fn new(
-        play_mode: PlayMode,
-        volume: u32,
-        initial_music_index: usize,
+        session: &PlaybackSession,
+        repository: MusicRepository,
+        playlists: PlaylistRepository,
+        sessions: SessionRepository,
+        client: reqwest::Client,
         command_receiver: Arc<Mutex<mpsc::Receiver<PlayerCommand>>>,
+        event_sender: broadcast::Sender<PlayerEvent>,
     ) -> Result<Self, ApplicationError> {
         // 初始化 gstreamer
         gstreamer::init().map_err(|e| ApplicationError::InitError(e.to_string()))?;
         // 初始化音频播放器
         let pipeline = Arc::new(gstreamer::Pipeline::new());
-        // 创建 client
-        let client = Arc::new(reqwest::Client::new());
+        // 登录后 client 的请求会带上 cookie
+        let client = Arc::new(client);
+        // 在当前播放列表中重新定位上次播放的歌曲
+        let (initial_music_index, resume_position) = {
+            let playlist = PLAYLIST.lock().await;
+            let playlist = playlist.as_ref().map_err(|e| e.clone())?;
+            session.resolve(&playlist.musics)
+        };
         set_current_music_index(initial_music_index).await?;
+        let volume = Volume::new(session.volume)?;
+        volume.set_muted(session.muted);
+        let crossfade = Crossfade::new(session.crossfade)?;
+        equalizer().set(session.equalizer.clone())?;
+        // 读取已测量的歌曲响度，用于响度均衡
+        load_measured_loudness(&repository).await?;
         // 创建接收音频流结束的通道
         let (eos_sender, eos_receiver) = mpsc::channel(1);
         tracing::info!("GStreamer created successfully.");
         let audio_player = AudioPlayer {
             pipeline,
             client,
-            volume: Arc::new(AtomicU32::new(volume)),
-            play_mode: Arc::new(RwLock::new(play_mode)),
+            repository,
+            playlists,
+            sessions,
+            resume_position,
+            volume: Arc::new(volume),
+            crossfade: Arc::new(crossfade),
+            play_mode: Arc::new(RwLock::new(session.play_mode)),
             command_receiver,
             eos_sender,
+            event_sender,
         };
         // 启动 EOS 监听器
         audio_player.start_eos_listener(eos_receiver).await?;
         // 返回 audio_player
         Ok(audio_player)
     }
-    /// 设置音量 (0-100)
+    /// 设置音量 (0-200)
     pub fn set_volume(&self, percentage: u32) -> Result<(), ApplicationError> {
-        if percentage > 200 {
-            return Err(ApplicationError::VolumeError("音量值在：0-200".into()));
-        }
-
-        self.volume.store(percentage * 10, Ordering::Relaxed);
-
+        self.volume.set_level(percentage)?;
         // 应用到当前播放
-        self.apply_volume_to_pipeline();
-
+        self.volume.apply(&self.pipeline);
         Ok(())
     }
-    /// 获取音量百分比 (0-100)
+    /// 获取音量百分比 (0-200)
     pub fn get_volume(&self) -> u32 {
-        self.volume.load(Ordering::Relaxed) / 10
-    }
-    /// 获取 GStreamer 音量值 (0.0-2.0)
-    fn get_gstreamer_volume(&self) -> f64 {
-        let volume_int = self.volume.load(Ordering::Relaxed);
-        volume_int as f64 / 1000.0
-    }
-    /// 应用到当前pipeline
-    fn apply_volume_to_pipeline(&self) {
-        if let Some(volume_elem) = self.pipeline.by_name("audio_volume") {
-            let vol_value = self.get_gstreamer_volume();
-            volume_elem.set_property("volume", vol_value);
-        }
-    }
-    /// 处理播放器命令
-    pub async fn handle_command(&mut self, command: PlayerCommand) {
-        match command {
-            PlayerCommand::Play => todo!(),
-            PlayerCommand::PlayBvid(_play_bvid_request) => todo!(),
-            PlayerCommand::Pause => todo!(),
-            PlayerCommand::Next => todo!(),
-            PlayerCommand::Previous => todo!(),
-            PlayerCommand::Stop => todo!(),
-            PlayerCommand::SetModel(_set_model_request) => todo!(),
-            PlayerCommand::SetVolume(_set_volume_request) => todo!(),
-            PlayerCommand::AddPlaylist(_add_playlist_request) => todo!(),
-            PlayerCommand::Delete(_deleted_request) => todo!(),
-            PlayerCommand::GetState(_sender) => todo!(),
-            PlayerCommand::ShowPlaylist() => todo!(),
-            PlayerCommand::Seek(_seek_time) => todo!(),
-        }
+        self.volume.level()
     }
-
     // 监听 EOS 事件
     async fn start_eos_listener(
         &self,
@@ -115,7 +151,10 @@ impl AudioPlayer {
         let pipeline = Arc::clone(&self.pipeline);
         let client = Arc::clone(&self.client);
         let play_mode = Arc::clone(&self.play_mode);
-        let volume_value = self.get_gstreamer_volume();
+        let volume = Arc::clone(&self.volume);
+        let crossfade = Arc::clone(&self.crossfade);
+        let sessions = self.sessions.clone();
+        let event_sender = self.event_sender.clone();
         // 开启一个线程用来接收播放完成的信号
         tokio::task::spawn(async move {
             while (eos_receiver.recv().await).is_some() {
@@ -126,31 +165,107 @@ impl AudioPlayer {
                     && let Err(e) = move_to_next_music(current_play_mode).await
                 {
                     tracing::error!("Failed to move to next music: {}", e);
+                    send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                     continue;
                 }
-                if let Err(e) = play_music(&pipeline, volume_value, &client).await {
+                if let Err(e) = play_music(&pipeline, &volume, &client).await {
                     tracing::error!("Failed to play next music: {}", e);
+                    send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                 }
+                save_session(&sessions, &pipeline, current_play_mode, &volume, &crossfade).await;
             }
         });
 
         Ok(())
     }
+    // 监听当前歌曲的进度：快结束时预加载下一首，到时切换过去，并更新淡入淡出的音量
+    fn start_track_monitor(&self) {
+        let pipeline = Arc::clone(&self.pipeline);
+        let client = Arc::clone(&self.client);
+        let play_mode = Arc::clone(&self.play_mode);
+        let crossfade = Arc::clone(&self.crossfade);
+        let eos_sender = self.eos_sender.clone();
+        let event_sender = self.event_sender.clone();
+        tokio::task::spawn(async move {
+            let mut interval = tokio::time::interval(TRACK_MONITOR_INTERVAL);
+            loop {
+                interval.tick().await;
+                // 暂停时位置不变，淡入淡出也随之暂停
+                if pipeline.current_state() != gstreamer::State::Playing {
+                    continue;
+                }
+                let fade = crossfade.duration();
+                let (promoted, preload) = {
+                    let mut branches = track_branches();
+                    let Some(running_time) = branches.running_time(&pipeline) else {
+                        continue;
+                    };
+                    let promoted = branches.promote_preloaded(&pipeline, running_time);
+                    branches.update_fade(&pipeline, running_time);
+                    // 变速播放时新分支的速度与 audiomixer 不一致，不预加载，结束后再切换
+                    let preload = branches.can_preload()
+                        && playback_rate().settings().is_normal()
+                        && branches.remaining(&pipeline).is_some_and(|remaining| {
+                            remaining <= (PRELOAD_AHEAD + fade).as_nanos() as i64
+                        });
+                    if preload {
+                        branches.preloading = true;
+                    }
+                    (promoted, preload)
+                };
+                if let Some((music, stream)) = promoted {
+                    if promote_preloaded_track(music, stream).await {
+                        send_track_changed(&event_sender).await;
+                    }
+                    // 歌曲已被移出播放列表，和播放结束一样按播放模式切换到真正的下一首
+                    else if eos_sender.send(()).await.is_err() {
+                        tracing::error!("Failed to send EOS after dropping preloaded music");
+                    }
+                }
+                if preload {
+                    let current_play_mode = *play_mode.read().await;
+                    let pipeline = Arc::clone(&pipeline);
+                    let client = Arc::clone(&client);
+                    tokio::task::spawn(async move {
+                        if let Err(e) =
+                            preload_next_track(&pipeline, &client, current_play_mode, fade).await
+                        {
+                            tracing::warn!("Failed to preload next music: {}", e);
+                        }
+                        track_branches().preloading = false;
+                    });
+                }
+            }
+        });
+    }
     /// 播放列表中的歌曲
-    pub async fn play_playlist(&self) -> Result<(), ApplicationError> {
+    ///
+    /// # 参数
+    /// - resume_mode: 启动后继续播放，或恢复到上次的位置后暂停
+    pub async fn play_playlist(&self, resume_mode: ResumeMode) -> Result<(), ApplicationError> {
         let pipeline = Arc::clone(&self.pipeline);
         let client = Arc::clone(&self.client);
+        let repository = self.repository.clone();
+        let playlists = self.playlists.clone();
+        let sessions = self.sessions.clone();
         let play_mode = Arc::clone(&self.play_mode);
+        let volume = Arc::clone(&self.volume);
+        let crossfade = Arc::clone(&self.crossfade);
         let command_receiver = Arc::clone(&self.command_receiver);
         let eos_sender = self.eos_sender.clone();
-        let volume_value = self.get_gstreamer_volume();
+        let event_sender = self.event_sender.clone();
         // Watch GStreamer bus messages
         let bus = self.pipeline.bus().ok_or_else(|| {
             ApplicationError::PipelineError("Failed to get GStreamer bus".to_string())
         })?;
 
+        let bus_event_sender = event_sender.clone();
+        // 播放中音频地址出错的信号，带上出错的地址
+        let (failover_sender, mut failover_receiver) = mpsc::channel::<String>(1);
         let bus_receiver = bus.stream().for_each(move |msg| {
             let eos_sender = eos_sender.clone();
+            let failover_sender = failover_sender.clone();
+            let event_sender = bus_event_sender.clone();
             async move {
                 match msg.view() {
                     MessageView::Eos(_) => {
@@ -161,6 +276,48 @@ impl AudioPlayer {
                     }
                     MessageView::Error(err) => {
                         tracing::error!("Error from GStreamer pipeline: {}", err);
+                        // 音频地址读取出错时交给命令循环切换备用地址，不作为错误上报
+                        match failed_http_location(&msg) {
+                            Some(location) => {
+                                let _ = failover_sender.try_send(location);
+                            }
+                            None => send_event(
+                                &event_sender,
+                                PlayerEvent::Error(err.error().to_string()),
+                            ),
+                        }
+                    }
+                    // 新的音频流开始播放，说明已切换歌曲
+                    MessageView::StreamStart(_) => {
+                        send_track_changed(&event_sender).await;
+                    }
+                    // 只关心 pipeline 自身完成的状态切换
+                    MessageView::StateChanged(state_changed)
+                        if state_changed.pending() == gstreamer::State::VoidPending
+                            && msg.src().is_some_and(|src| src.is::<gstreamer::Pipeline>()) =>
+                    {
+                        send_event(
+                            &event_sender,
+                            PlayerEvent::StateChanged {
+                                state: state_changed.current().into(),
+                                buffering_percent: None,
+                            },
+                        );
+                    }
+                    MessageView::Buffering(buffering) => {
+                        let percent = buffering.percent();
+                        let state = if percent < 100 {
+                            PlaybackState::Buffering
+                        } else {
+                            PlaybackState::Playing
+                        };
+                        send_event(
+                            &event_sender,
+                            PlayerEvent::StateChanged {
+                                state,
+                                buffering_percent: Some(percent),
+                            },
+                        );
                     }
                     _ => (),
                 }
@@ -171,16 +328,31 @@ impl AudioPlayer {
         tokio::task::spawn(async move {
             let mut command_receiver = command_receiver.lock().await;
             tokio::pin!(bus_receiver);
+            let mut save_interval = tokio::time::interval(SESSION_SAVE_INTERVAL);
+            // 定时记录的播放位置，地址出错后 pipeline 查询不到位置时使用
+            let mut last_position = None;
             loop {
                 tokio::select! {
                     command = command_receiver.recv() => {
                         if let Some(command) = command {
-                            // self.handle_command(command).await;
+                            // 查询类的命令不改变播放状态，不需要保存
+                            let persist = !matches!(
+                                command,
+                                PlayerCommand::GetState(_) | PlayerCommand::ShowPlaylist(..) | PlayerCommand::Shutdown(_)
+                            );
+                            let changes_playlist = command.changes_playlist();
                             match command {
                                 PlayerCommand::Play => {
                                     tracing::info!("Resume playback");
-                                    if let Err(e) = pipeline.set_state(gstreamer::State::Playing) {
+                                    // 暂停太久时地址可能已经过期，刷新地址后会直接继续播放
+                                    let result = match refresh_expired_stream(&pipeline, &volume, &client, ResumeMode::Playing).await {
+                                        Ok(true) => Ok(()),
+                                        Ok(false) => pipeline.set_state(gstreamer::State::Playing).map(|_| ()).map_err(|e| ApplicationError::StateError(e.to_string())),
+                                        Err(e) => Err(e),
+                                    };
+                                    if let Err(e) = result {
                                         tracing::error!("Failed to play: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                                     }
                                 }
                                 PlayerCommand::PlayBvid(play_bvid_request) => {
@@ -189,20 +361,23 @@ impl AudioPlayer {
                                         let playlist = PLAYLIST.lock().await;
                                         let playlist = playlist.as_ref().unwrap();
 
-                                        if let Some(new_index) = playlist.find_music_index(&play_bvid_request.bvid).await {
+                                        if let Some(new_index) = playlist.find_music_index(&play_bvid_request.bvid, cid_filter(&play_bvid_request.cid)).await {
                                             set_current_music_index(new_index).await.ok();
                                         } else {
                                             tracing::error!("Music with bvid {} not found in the playlist", play_bvid_request.bvid);
+                                            send_event(&event_sender, PlayerEvent::Error(format!("{} 不在播放列表中", play_bvid_request.bvid)));
                                         }
                                     }
-                                    if let Err(e) = play_music(&pipeline,volume_value, &client).await {
+                                    if let Err(e) = play_music(&pipeline, &volume, &client).await {
                                         tracing::error!("Failed to play track after set new bvid: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                                     }
                                 }
                                 PlayerCommand::Pause => {
                                     tracing::info!("Pause");
                                     if let Err(e) = pipeline.set_state(gstreamer::State::Paused) {
                                         tracing::error!("Failed to pause: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                                     }
                                 }
                                 PlayerCommand::Next => {
@@ -215,8 +390,10 @@ impl AudioPlayer {
                                     };
                                     if let Err(e) = move_to_next_music(mode).await {
                                         tracing::error!("Failed to skip to next track: {}", e);
-                                    } else if let Err(e) = play_music(&pipeline,volume_value, &client).await {
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
+                                    } else if let Err(e) = skip_to_current_music(&pipeline, &volume, &client, &crossfade, &event_sender).await {
                                         tracing::error!("Failed to play next track: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                                     }
                                 }
                                 PlayerCommand::Previous => {
@@ -229,29 +406,285 @@ impl AudioPlayer {
                                     };
                                     if let Err(e) = move_to_previous_music(mode).await {
                                         tracing::error!("Failed to skip to previous track: {}", e);
-                                    } else if let Err(e) = play_music(&pipeline, volume_value, &client).await {
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
+                                    } else if let Err(e) = skip_to_current_music(&pipeline, &volume, &client, &crossfade, &event_sender).await {
                                         tracing::error!("Failed to play previous track: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
                                     }
                                 }
                                 PlayerCommand::Stop => {
                                     if let Err(e) = pipeline.set_state(gstreamer::State::Null) {
                                         tracing::error!("Failed to stop: {}", e);
+                                        send_event(&event_sender, PlayerEvent::Error(e.to_string()));
+                                    } else {
+                                        // 切换到 Null 时总线已停止，不会收到状态变化的消息
+                                        send_event(&event_sender, PlayerEvent::StateChanged { state: PlaybackState::Stopped, buffering_percent: None });
                                     }
                                 }
                                 PlayerCommand::SetModel(set_model_request) => {
                                     let mut write_guard = play_mode.write().await;
                                     *write_guard = PlayMode::from_string(set_model_request.model.as_str()).unwrap_or(PlayMode::Normal);
+                                    // 换了播放模式后下一首可能不同，重新预加载
+                                    track_branches().discard_preloaded(&pipeline);
+                                    send_event(&event_sender, PlayerEvent::ModeChanged(*write_guard));
+                                }
+                                PlayerCommand::SetVolume(volume_action, sender) => {
+                                    let result = match volume_action {
+                                        VolumeAction::Set(level) => volume.set_level(level).map(|_| ()),
+                                        VolumeAction::Adjust(delta) => {
+                                            volume.adjust(delta);
+                                            Ok(())
+                                        }
+                                        VolumeAction::Mute(muted) => {
+                                            volume.set_muted(muted);
+                                            Ok(())
+                                        }
+                                    }
+                                    .map(|_| {
+                                        volume.apply(&pipeline);
+                                        tracing::info!("Volume set to {} (muted: {})", volume.level(), volume.is_muted());
+                                        send_event(&event_sender, PlayerEvent::VolumeChanged { volume: volume.level(), muted: volume.is_muted() });
+                                        (volume.level(), volume.is_muted())
+                                    });
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send volume result");
+                                    }
+                                }
+                                PlayerCommand::AddPlaylist(add_playlist_request, sender) => {
+                                    tracing::info!("Add {} to playlist", add_playlist_request.bvid);
+                                    let result = match add_playlist_request.pages.parse::<PageSelection>() {
+                                        Ok(pages) => import_bvid(
+                                            &client,
+                                            BILIBILI_API_BASE,
+                                            &repository,
+                                            &add_playlist_request.bvid,
+                                            Some(add_playlist_request.song_name.as_str()),
+                                            pages,
+                                        )
+                                        .await,
+                                        Err(e) => Err(e),
+                                    };
+                                    let result = match result {
+                                        // 追加到当前歌单末尾，不打断当前播放
+                                        Ok(musics) => add_musics_to_active_playlist(&repository, &playlists, musics).await,
+                                        Err(e) => Err(e),
+                                    };
+                                    match &result {
+                                        Ok(musics) => send_playlist_events(&event_sender, PlaylistAction::Added, musics).await,
+                                        Err(e) => tracing::error!("Failed to add {}: {}", add_playlist_request.bvid, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send add playlist result");
+                                    }
+                                }
+                                PlayerCommand::Delete(deleted_request, sender) => {
+                                    tracing::info!("Delete {}", deleted_request.bvid);
+                                    let result = delete_music(&pipeline, &volume, &client, &repository, &deleted_request.bvid, cid_filter(&deleted_request.cid)).await;
+                                    match &result {
+                                        Ok(musics) => send_playlist_events(&event_sender, PlaylistAction::Removed, musics).await,
+                                        Err(e) => tracing::error!("Failed to delete {}: {}", deleted_request.bvid, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send delete result");
+                                    }
+                                }
+                                PlayerCommand::Restore(restore_request, sender) => {
+                                    tracing::info!("Restore {}", restore_request.bvid);
+                                    let result = restore_music(&repository, &playlists, &restore_request.bvid, cid_filter(&restore_request.cid)).await;
+                                    match &result {
+                                        Ok(musics) => send_playlist_events(&event_sender, PlaylistAction::Restored, musics).await,
+                                        Err(e) => tracing::error!("Failed to restore {}: {}", restore_request.bvid, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send restore result");
+                                    }
+                                }
+                                PlayerCommand::GetState(sender) => {
+                                    let current_play_mode = *play_mode.read().await;
+                                    let snapshot = build_state_snapshot(&pipeline, current_play_mode, &volume, &crossfade).await;
+                                    if sender.send(snapshot).is_err() {
+                                        tracing::error!("Failed to send player state");
+                                    }
+                                }
+                                PlayerCommand::ShowPlaylist(show_playlist_request, sender) => {
+                                    let page = get_playlist_page(
+                                        show_playlist_request.page.max(0) as usize,
+                                        show_playlist_request.page_size.max(0) as usize,
+                                    )
+                                    .await;
+                                    if sender.send(page).is_err() {
+                                        tracing::error!("Failed to send playlist page");
+                                    }
                                 }
-                                PlayerCommand::SetVolume(set_volume_request) => {
-                                    pipeline.set_property("volume", set_volume_request.volume);
-                                    tracing::info!("Volume set to {}", set_volume_request.volume);
+                                PlayerCommand::Seek(seek_target, sender) => {
+                                    tracing::info!("Seek {:?}", seek_target);
+                                    let resume_mode = if pipeline.current_state() == gstreamer::State::Paused {
+                                        ResumeMode::Paused
+                                    } else {
+                                        ResumeMode::Playing
+                                    };
+                                    let result = match refresh_expired_stream(&pipeline, &volume, &client, resume_mode).await {
+                                        Ok(_) => seek_pipeline(&pipeline, seek_target),
+                                        Err(e) => Err(e),
+                                    };
+                                    if let Err(e) = &result {
+                                        tracing::error!("Failed to seek: {}", e);
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send seek result");
+                                    }
+                                }
+                                PlayerCommand::SetCrossfade(seconds, sender) => {
+                                    tracing::info!("Set crossfade to {}s", seconds);
+                                    let result = crossfade.set(seconds);
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send crossfade result");
+                                    }
+                                }
+                                PlayerCommand::SetRate(settings, sender) => {
+                                    tracing::info!("Set rate {:?}", settings);
+                                    let result = set_playback_rate(&pipeline, &volume, &client, settings).await;
+                                    if let Err(e) = &result {
+                                        tracing::error!("Failed to set rate: {}", e);
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send rate result");
+                                    }
+                                }
+                                PlayerCommand::SetEqualizer(action, sender) => {
+                                    tracing::info!("Set equalizer {:?}", action);
+                                    let result = match action {
+                                        EqualizerAction::Band(band, gain) => equalizer().set_band(band, gain),
+                                        EqualizerAction::Apply(settings) => equalizer().set(settings),
+                                    };
+                                    // 应用到当前播放
+                                    equalizer().apply(&pipeline);
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send equalizer result");
+                                    }
                                 }
-                                PlayerCommand::AddPlaylist(_add_playlist_request) => todo!(),
-                                PlayerCommand::Delete(_deleted_request) => todo!(),
-                                PlayerCommand::GetState(_sender) => todo!(),
-                                PlayerCommand::ShowPlaylist() => todo!(),
-                                PlayerCommand::Seek(_seek_time) => todo!(),
+                                PlayerCommand::SwitchPlaylist(switch_playlist_request, sender) => {
+                                    tracing::info!("Switch to playlist {}", switch_playlist_request.name);
+                                    let result = switch_playlist(&pipeline, &volume, &client, &playlists, &switch_playlist_request.name).await;
+                                    match &result {
+                                        Ok(summary) => send_event(&event_sender, PlayerEvent::PlaylistSwitched {
+                                            name: summary.name.clone(),
+                                            playlist_len: playlist_len().await.unwrap_or_default(),
+                                        }),
+                                        Err(e) => tracing::error!("Failed to switch to playlist {}: {}", switch_playlist_request.name, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send switch playlist result");
+                                    }
+                                }
+                                PlayerCommand::AddToPlaylist(add_to_playlist_request, sender) => {
+                                    tracing::info!("Add {} to playlist {}", add_to_playlist_request.bvid, add_to_playlist_request.name);
+                                    let result = add_to_playlist(&client, &repository, &playlists, &add_to_playlist_request).await;
+                                    match &result {
+                                        Ok((musics, true)) => send_playlist_events(&event_sender, PlaylistAction::Added, musics).await,
+                                        Ok(_) => {}
+                                        Err(e) => tracing::error!("Failed to add {} to playlist {}: {}", add_to_playlist_request.bvid, add_to_playlist_request.name, e),
+                                    }
+                                    if sender.send(result.map(|(musics, _)| musics)).is_err() {
+                                        tracing::error!("Failed to send add to playlist result");
+                                    }
+                                }
+                                PlayerCommand::RemoveFromPlaylist(remove_from_playlist_request, sender) => {
+                                    tracing::info!("Remove {} from playlist {}", remove_from_playlist_request.bvid, remove_from_playlist_request.name);
+                                    let result = remove_from_playlist(&pipeline, &volume, &client, &repository, &playlists, &remove_from_playlist_request).await;
+                                    match &result {
+                                        Ok((musics, true)) => send_playlist_events(&event_sender, PlaylistAction::Removed, musics).await,
+                                        Ok(_) => {}
+                                        Err(e) => tracing::error!("Failed to remove {} from playlist {}: {}", remove_from_playlist_request.bvid, remove_from_playlist_request.name, e),
+                                    }
+                                    if sender.send(result.map(|(musics, _)| musics)).is_err() {
+                                        tracing::error!("Failed to send remove from playlist result");
+                                    }
+                                }
+                                PlayerCommand::ImportFavorite(import_favorite_request, sender) => {
+                                    tracing::info!("Import favorite folder {}", import_favorite_request.media_id);
+                                    let result = import_favorite_folder(
+                                        &client,
+                                        BILIBILI_API_BASE,
+                                        &repository,
+                                        &playlists,
+                                        import_favorite_request.media_id,
+                                        Some(import_favorite_request.playlist_name.as_str()),
+                                    )
+                                    .await;
+                                    match &result {
+                                        // 导入到当前歌单时追加到播放列表末尾
+                                        Ok(report) if report.playlist.is_active => {
+                                            for music in &report.added {
+                                                if let Err(e) = add_music_to_playlist(music.clone()).await {
+                                                    tracing::error!("Failed to add {:?} to playlist: {}", music, e);
+                                                }
+                                            }
+                                            send_playlist_events(&event_sender, PlaylistAction::Added, &report.added).await;
+                                        }
+                                        Ok(_) => {}
+                                        Err(e) => tracing::error!("Failed to import favorite folder {}: {}", import_favorite_request.media_id, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send import favorite result");
+                                    }
+                                }
+                                PlayerCommand::ImportVideos(video_source, video_filter, sender) => {
+                                    tracing::info!("Import videos from {}", video_source);
+                                    let result = match import_videos(&client, BILIBILI_API_BASE, &repository, &video_source, &video_filter).await {
+                                        // 与单个 bvid 一样追加到当前歌单末尾
+                                        Ok(mut report) => add_musics_to_active_playlist(&repository, &playlists, std::mem::take(&mut report.added))
+                                            .await
+                                            .map(|musics| {
+                                                report.added = musics;
+                                                report
+                                            }),
+                                        Err(e) => Err(e),
+                                    };
+                                    match &result {
+                                        Ok(report) => send_playlist_events(&event_sender, PlaylistAction::Added, &report.added).await,
+                                        Err(e) => tracing::error!("Failed to import videos from {}: {}", video_source, e),
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send import videos result");
+                                    }
+                                }
+                                PlayerCommand::Shutdown(sender) => {
+                                    tracing::info!("Saving session before shutdown");
+                                    let current_play_mode = *play_mode.read().await;
+                                    let result = save_session_or_error(&sessions, &pipeline, current_play_mode, &volume, &crossfade).await;
+                                    if let Err(e) = pipeline.set_state(gstreamer::State::Null) {
+                                        tracing::error!("Failed to stop: {}", e);
+                                    }
+                                    if sender.send(result).is_err() {
+                                        tracing::error!("Failed to send shutdown result");
+                                    }
+                                    break;
+                                }
+                            }
+                            if changes_playlist {
+                                // 播放列表变化后预加载的歌曲可能已被删除或不再是下一首，重新预加载
+                                track_branches().discard_preloaded(&pipeline);
                             }
+                            if persist {
+                                let current_play_mode = *play_mode.read().await;
+                                save_session(&sessions, &pipeline, current_play_mode, &volume, &crossfade).await;
+                            }
+                        }
+                    },
+                    // 播放中音频地址出错，切换到备用地址
+                    Some(failed_url) = failover_receiver.recv() => {
+                        if let Err(e) = failover_playback(&pipeline, &volume, &client, &failed_url, last_position).await {
+                            tracing::error!("Failed to switch audio URL: {}", e);
+                            send_event(&event_sender, PlayerEvent::Error(e.to_string()));
+                        }
+                    },
+                    // 播放中定时保存播放位置
+                    _ = save_interval.tick() => {
+                        if pipeline.current_state() == gstreamer::State::Playing {
+                            last_position = track_position(&pipeline);
+                            let current_play_mode = *play_mode.read().await;
+                            save_session(&sessions, &pipeline, current_play_mode, &volume, &crossfade).await;
                         }
                     },
                     _ = &mut bus_receiver => {},
@@ -259,20 +692,196 @@ impl AudioPlayer {
             }
         });
 
-        play_music(&self.pipeline, volume_value, &self.client).await?;
+        self.start_track_monitor();
+        play_music(&self.pipeline, &self.volume, &self.client).await?;
+        resume_playback(&self.pipeline, self.resume_position, resume_mode).await?;
         Ok(())
     }
 }
 
+/// 恢复上次的播放位置，并按恢复方式决定继续播放还是暂停
+async fn resume_playback(
+    pipeline: &Arc<gstreamer::Pipeline>,
+    position: Option<f64>,
+    resume_mode: ResumeMode,
+) -> Result<(), ApplicationError> {
+    if position.is_none() && resume_mode == ResumeMode::Playing {
+        return Ok(());
+    }
+    pipeline.set_state(gstreamer::State::Paused).map_err(|_| {
+        ApplicationError::StateError("Failed to set pipeline to Paused".to_string())
+    })?;
+    if let Some(position) = position {
+        // 等待 pipeline 预加载完成后才能跳转
+        let waiting_pipeline = Arc::clone(pipeline);
+        let _ = tokio::task::spawn_blocking(move || {
+            waiting_pipeline.state(gstreamer::ClockTime::from_seconds(10))
+        })
+        .await;
+        match seek_pipeline(pipeline, SeekTarget::Absolute(position)) {
+            Ok(position) => tracing::info!("Resumed at {:.1}s", position),
+            Err(e) => tracing::warn!("Failed to resume position: {}", e),
+        }
+    }
+    if resume_mode == ResumeMode::Playing {
+        pipeline.set_state(gstreamer::State::Playing).map_err(|_| {
+            ApplicationError::StateError("Failed to set pipeline to Playing".to_string())
+        })?;
+    }
+    Ok(())
+}
+
+/// 保存当前的播放会话
+async fn save_session_or_error(
+    sessions: &SessionRepository,
+    pipeline: &gstreamer::Pipeline,
+    play_mode: PlayMode,
+    volume: &Volume,
+    crossfade: &Crossfade,
+) -> Result<(), ApplicationError> {
+    let snapshot = build_state_snapshot(pipeline, play_mode, volume, crossfade).await;
+    let record = SessionRecord::from(&PlaybackSession::from(&snapshot));
+    sessions.save(&record).await
+}
+
+/// 保存当前的播放会话，失败时只记录日志
+async fn save_session(
+    sessions: &SessionRepository,
+    pipeline: &gstreamer::Pipeline,
+    play_mode: PlayMode,
+    volume: &Volume,
+    crossfade: &Crossfade,
+) {
+    if let Err(e) = save_session_or_error(sessions, pipeline, play_mode, volume, crossfade).await {
+        tracing::error!("Failed to save session: {}", e);
+    }
+}
+
+/// 发送播放器事件，没有订阅者时直接丢弃
+fn send_event(event_sender: &broadcast::Sender<PlayerEvent>, event: PlayerEvent) {
+    let _ = event_sender.send(event);
+}
+
+/// 发送播放列表变化事件，每首歌曲一个事件
+async fn send_playlist_events(
+    event_sender: &broadcast::Sender<PlayerEvent>,
+    action: PlaylistAction,
+    musics: &[Music],
+) {
+    let playlist_len = playlist_len().await.unwrap_or_default();
+    for music in musics {
+        send_event(
+            event_sender,
+            PlayerEvent::PlaylistChanged {
+                action,
+                music: music.clone(),
+                playlist_len,
+            },
+        );
+    }
+}
+
 /// 播放音乐
+///
+/// 切换到新的歌曲，没有选择保持速度时恢复为正常速度
 pub async fn play_music(
     pipeline: &gstreamer::Pipeline,
-    volume_value: f64,
+    volume: &Volume,
     client: &reqwest::Client,
 ) -> Result<(), ApplicationError> {
+    let rate = playback_rate().track_changed();
+    reload_current_music(pipeline, volume, client).await?;
+    if !rate.is_normal() {
+        apply_rate(pipeline).await;
+    }
+    Ok(())
+}
+
+/// 重新请求当前歌曲的音频地址并从头播放，播放速度不变
+async fn reload_current_music(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
+) -> Result<(), ApplicationError> {
+    reset_pipeline(pipeline)?;
+
+    let music = get_current_music().await?;
+    let stream = fetch_and_verify_audio_url(
+        client,
+        BILIBILI_API_BASE,
+        &music.bvid,
+        &music.cid,
+        current_audio_preference(),
+    )
+    .await?;
+    if let Some(deadline) = stream.deadline() {
+        tracing::debug!("Audio URL expires at {}", deadline);
+    }
+    set_pipeline_uri_with_headers(pipeline, volume, &music, &stream.base_url).await?;
+    *CURRENT_AUDIO_STREAM.lock().await = Some(stream);
+
+    pipeline.set_state(gstreamer::State::Playing).map_err(|_| {
+        ApplicationError::StateError("Failed to set pipeline to Playing".to_string())
+    })?;
+    Ok(())
+}
+
+// 等待 pipeline 预加载完成后，按当前速度从头播放
+async fn apply_rate(pipeline: &gstreamer::Pipeline) {
+    let waiting_pipeline = pipeline.clone();
+    let _ = tokio::task::spawn_blocking(move || {
+        waiting_pipeline.state(gstreamer::ClockTime::from_seconds(10))
+    })
+    .await;
+    if let Err(e) = seek_pipeline(pipeline, SeekTarget::Absolute(0.0)) {
+        tracing::warn!("Failed to apply playback rate: {}", e);
+    }
+}
+
+/// 设置播放速度，正在播放时通过带速度的跳转立即生效
+///
+/// scaletempo 只在建立输出链时加入，切换是否保持音调时重新加载当前歌曲并恢复到原来的位置。
+/// 应用失败时恢复之前的设置
+async fn set_playback_rate(
+    pipeline: &Arc<gstreamer::Pipeline>,
+    volume: &Volume,
+    client: &reqwest::Client,
+    settings: RateSettings,
+) -> Result<RateSettings, ApplicationError> {
+    let previous = playback_rate().settings();
+    let settings = playback_rate().set(settings)?;
+    if PlaybackState::from(pipeline.current_state()) == PlaybackState::Stopped {
+        return Ok(settings);
+    }
+    let result = if settings.preserve_pitch != previous.preserve_pitch {
+        let position = track_position(pipeline);
+        let resume_mode = if pipeline.current_state() == gstreamer::State::Paused {
+            ResumeMode::Paused
+        } else {
+            ResumeMode::Playing
+        };
+        match reload_current_music(pipeline, volume, client).await {
+            Ok(()) => resume_playback(pipeline, position.or(Some(0.0)), resume_mode).await,
+            Err(e) => Err(e),
+        }
+    } else {
+        seek_pipeline(pipeline, SeekTarget::Relative(0.0)).map(|_| ())
+    };
+    if let Err(e) = result {
+        let _ = playback_rate().set(previous);
+        return Err(e);
+    }
+    Ok(settings)
+}
+
+// 移除 pipeline 中上一首歌曲的元素，准备设置新的音频地址
+fn reset_pipeline(pipeline: &gstreamer::Pipeline) -> Result<(), ApplicationError> {
     pipeline
         .set_state(gstreamer::State::Null)
         .map_err(|_| ApplicationError::StateError("Failed to set pipeline to Null".to_string()))?;
+    // 先清空分支，持有锁时预加载任务不会再往 pipeline 中添加元素
+    let mut branches = track_branches();
+    branches.reset();
 
     for element in pipeline.children() {
         pipeline.remove(&element).map_err(|_| {
@@ -283,230 +892,476 @@ pub async fn play_music(
     pipeline
         .set_state(gstreamer::State::Ready)
         .map_err(|_| ApplicationError::StateError("Failed to set pipeline to Ready".to_string()))?;
+    Ok(())
+}
 
-    let music = get_current_music().await?;
-    let url = fetch_and_verify_audio_url(client, &music.bvid, &music.cid).await?;
+/// 播放中音频地址出错时切换到下一个备用地址，并从出错时的位置继续播放
+///
+/// 备用地址都用完时重新请求音频地址。failed_url 不是当前地址时说明已经切换过，忽略这次错误
+async fn failover_playback(
+    pipeline: &Arc<gstreamer::Pipeline>,
+    volume: &Volume,
+    client: &reqwest::Client,
+    failed_url: &str,
+    last_position: Option<f64>,
+) -> Result<(), ApplicationError> {
+    // 预加载或淡出中的地址出错时只移除那个分支，预加载之后会重新请求
+    if track_branches().discard_failed(pipeline, failed_url) {
+        tracing::warn!("Audio URL of other track failed: {}", failed_url);
+        return Ok(());
+    }
+    let next_url = {
+        let mut current_stream = CURRENT_AUDIO_STREAM.lock().await;
+        match current_stream.as_mut() {
+            // 地址已经过期时备用地址也不可用，直接重新请求
+            Some(stream) if stream.base_url == failed_url => {
+                if stream.expires_within(unix_now(), 0) {
+                    None
+                } else {
+                    stream.failover().map(str::to_string)
+                }
+            }
+            _ => return Ok(()),
+        }
+    };
+    // 出错后 pipeline 可能查询不到位置，使用最近一次记录的位置
+    let position = track_position(pipeline).or(last_position);
+    let resume_mode = if pipeline.current_state() == gstreamer::State::Paused {
+        ResumeMode::Paused
+    } else {
+        ResumeMode::Playing
+    };
+    match next_url {
+        Some(url) => {
+            tracing::warn!("Audio URL failed, switching to backup URL: {}", url);
+            let music = get_current_music().await?;
+            reset_pipeline(pipeline)?;
+            set_pipeline_uri_with_headers(pipeline, volume, &music, &url).await?;
+            pipeline.set_state(gstreamer::State::Playing).map_err(|_| {
+                ApplicationError::StateError("Failed to set pipeline to Playing".to_string())
+            })?;
+        }
+        None => {
+            tracing::warn!("All backup URLs failed, fetching audio URL again");
+            reload_current_music(pipeline, volume, client).await?;
+        }
+    }
+    resume_playback(pipeline, position, resume_mode).await
+}
+
+/// 当前音频地址即将过期时重新请求地址，并恢复到原来的播放位置
+///
+/// 返回是否刷新了地址，刷新后 pipeline 按 resume_mode 继续播放或暂停
+async fn refresh_expired_stream(
+    pipeline: &Arc<gstreamer::Pipeline>,
+    volume: &Volume,
+    client: &reqwest::Client,
+    resume_mode: ResumeMode,
+) -> Result<bool, ApplicationError> {
+    let expired = CURRENT_AUDIO_STREAM
+        .lock()
+        .await
+        .as_ref()
+        .is_some_and(|stream| stream.expires_within(unix_now(), URL_REFRESH_MARGIN.as_secs()));
+    if !expired || pipeline.current_state() == gstreamer::State::Null {
+        return Ok(false);
+    }
+    let position = track_position(pipeline);
+    tracing::info!("Audio URL expired, refreshing at {:?}s", position);
+    reload_current_music(pipeline, volume, client).await?;
+    // 没有位置时 resume_playback 不会暂停，从头开始
+    resume_playback(pipeline, position.or(Some(0.0)), resume_mode).await?;
+    Ok(true)
+}
 
-    set_pipeline_uri_with_headers(pipeline, volume_value, &url).await?;
+// 当前歌曲使用的音质
+async fn current_audio_quality() -> Option<AudioQuality> {
+    CURRENT_AUDIO_STREAM
+        .lock()
+        .await
+        .as_ref()
+        .map(|stream| stream.quality.clone())
+}
 
-    pipeline.set_state(gstreamer::State::Playing).map_err(|_| {
-        ApplicationError::StateError("Failed to set pipeline to Playing".to_string())
-    })?;
-    Ok(())
+/// 生成播放器状态快照，播放位置和时长从 pipeline 中查询
+async fn build_state_snapshot(
+    pipeline: &gstreamer::Pipeline,
+    play_mode: PlayMode,
+    volume: &Volume,
+    crossfade: &Crossfade,
+) -> PlayerStateSnapshot {
+    let playlist_len = playlist_len().await.unwrap_or_default();
+    let current_music = get_current_music().await.ok();
+    let current_index = if current_music.is_some() {
+        Some(*CURRENT_MUSIC_INDEX.lock().await)
+    } else {
+        None
+    };
+    let state = PlaybackState::from(pipeline.current_state());
+    // 停止状态下 pipeline 查询不到有效的位置
+    let (current_position, duration) = if state == PlaybackState::Stopped {
+        (None, None)
+    } else {
+        (track_position(pipeline), track_duration())
+    };
+    PlayerStateSnapshot {
+        current_music,
+        state,
+        play_mode,
+        current_index,
+        playlist_len,
+        current_position,
+        duration,
+        volume: volume.level(),
+        muted: volume.is_muted(),
+        crossfade: crossfade.seconds(),
+        equalizer: equalizer().settings(),
+        rate: playback_rate().settings(),
+        quality: if state == PlaybackState::Stopped {
+            None
+        } else {
+            current_audio_quality().await
+        },
+    }
 }
 
-/// 设置 pipeline 的 uri 和 headers
-async fn set_pipeline_uri_with_headers(
+/// 跳转到指定位置，返回跳转后的位置 (秒)
+///
+/// souphttpsrc 通过 Range 请求实现跳转，服务器不支持时返回错误。
+/// 跳转由 audiomixer 发给所有分支，跳转前先移除预加载和淡出中的分支
+fn seek_pipeline(
     pipeline: &gstreamer::Pipeline,
-    volume_value: f64,
-    url: &str,
+    target: SeekTarget,
+) -> Result<f64, ApplicationError> {
+    if PlaybackState::from(pipeline.current_state()) == PlaybackState::Stopped {
+        return Err(ApplicationError::StateError(
+            "当前没有正在播放的歌曲".to_string(),
+        ));
+    }
+    let mut branches = track_branches();
+    let active = branches
+        .active
+        .as_ref()
+        .ok_or_else(|| ApplicationError::StateError("当前没有正在播放的歌曲".to_string()))?;
+    // 检查当前音频流是否支持跳转
+    if !active.is_seekable() {
+        return Err(ApplicationError::StateError(
+            "当前音频流不支持跳转".to_string(),
+        ));
+    }
+    let position = branches
+        .track_position(pipeline)
+        .map(|t| t as f64 / 1_000_000_000.0)
+        .unwrap_or_default();
+    let duration = active.duration().map(|t| t as f64 / 1_000_000_000.0);
+    let mut target = match target {
+        SeekTarget::Absolute(seconds) => seconds,
+        SeekTarget::Relative(offset) => position + offset,
+    }
+    .max(0.0);
+    if let Some(duration) = duration {
+        target = target.min(duration);
+    }
+    // 时长未知时 target 没有上限，超出范围时返回错误
+    let target_time = gstreamer::ClockTime::try_from_seconds_f64(target)
+        .map_err(|_| ApplicationError::InvalidArgumentError(format!("无效的时间: {target}")))?;
+    branches.prepare_seek(pipeline);
+    // 带上当前的播放速度，跳转后保持变速
+    pipeline
+        .seek(
+            playback_rate().settings().rate,
+            gstreamer::SeekFlags::FLUSH | gstreamer::SeekFlags::ACCURATE,
+            gstreamer::SeekType::Set,
+            target_time,
+            gstreamer::SeekType::None,
+            gstreamer::ClockTime::NONE,
+        )
+        .map_err(|e| ApplicationError::PipelineError(format!("Failed to seek: {e}")))?;
+    branches.seeked(target_time.nseconds());
+    Ok(target)
+}
+
+/// 软删除音乐并从播放列表中移除
+///
+/// 如果删除的是正在播放的歌曲，则继续播放列表中的下一首
+async fn delete_music(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
+    repository: &MusicRepository,
+    bvid: &str,
+    cid: Option<&str>,
+) -> Result<Vec<Music>, ApplicationError> {
+    let records = repository.soft_delete(bvid, cid).await?;
+    if records.is_empty() {
+        return Err(ApplicationError::NotFoundError(format!(
+            "{} 不在音乐库中",
+            music_label(bvid, cid)
+        )));
+    }
+    let mut removed_current = false;
+    let mut musics = Vec::new();
+    for record in records {
+        let music = Music::from(record);
+        // 音乐库中存在但可能不在当前播放列表中
+        if let Some((_, is_current)) = remove_music_from_playlist(&music.bvid, &music.cid).await? {
+            removed_current |= is_current;
+        }
+        musics.push(music);
+    }
+    if removed_current {
+        replay_current_music(pipeline, volume, client).await?;
+    }
+    Ok(musics)
+}
+
+/// 当前播放的歌曲被移出播放列表后，播放新的当前歌曲
+///
+/// 保持之前的播放/暂停状态，播放列表为空时停止播放
+async fn replay_current_music(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
 ) -> Result<(), ApplicationError> {
-    let source = gstreamer::ElementFactory::make("souphttpsrc")
-        .build()
-        .map_err(|_| {
-            ApplicationError::ElementError("Failed to create souphttpsrc element".to_string())
-        })?;
-    source.set_property("location", url);
-
-    let mut headers = gstreamer::Structure::new_empty("headers");
-    headers.set(
-        "User-Agent",
-        "Mozilla/5.0 BiliDroid/..* (bbcallen@gmail.com)",
-    );
-    headers.set("Referer", "https://www.bilibili.com");
-    source.set_property("extra-headers", &headers);
-
-    let decodebin = gstreamer::ElementFactory::make("decodebin")
-        .build()
-        .map_err(|_| {
-            ApplicationError::ElementError("Failed to create decodebin element".to_string())
-        })?;
-    // let initial_volume = 0.10f64; // 音量值
-    // let set_volume = (0.10f64).clamp(0.0, 2.0); // 音量值
-    let volume = gstreamer::ElementFactory::make("volume")
-        .property("volume", volume_value)
-        .name("audio_volume") // 设置名称方便查找
-        .build()
-        .map_err(|e| {
-            tracing::error!("Failed to create volume: {:?}", e);
-            ApplicationError::ElementError("Failed to set volume element".to_string())
+    if playlist_len().await? == 0 {
+        // 播放列表已空，停止播放
+        pipeline.set_state(gstreamer::State::Null).map_err(|_| {
+            ApplicationError::StateError("Failed to set pipeline to Null".to_string())
         })?;
-    // 检查volume元素是否支持音量属性
-    // let props = volume.list_properties();
-    // for prop in props {
-    //     tracing::debug!("Volume element property: {}", prop.name());
-    // }
-    pipeline
-        .add_many([&source, &decodebin, &volume])
-        .map_err(|_| {
-            ApplicationError::PipelineError("Failed to add elements to pipeline".to_string())
+        return Ok(());
+    }
+    let was_playing = pipeline.current_state() == gstreamer::State::Playing;
+    play_music(pipeline, volume, client).await?;
+    if !was_playing {
+        pipeline.set_state(gstreamer::State::Paused).map_err(|_| {
+            ApplicationError::StateError("Failed to set pipeline to Paused".to_string())
         })?;
-    source.link(&decodebin).map_err(|_| {
-        ApplicationError::LinkError("Failed to link source to decodebin".to_string())
-    })?;
+    }
+    Ok(())
+}
 
-    let pipeline_weak = pipeline.downgrade();
-    // 使用一个Weak引用跟踪volume元素
-    let volume_weak = gstreamer::prelude::ObjectExt::downgrade(&volume);
-    decodebin.connect_pad_added(move |_decodebin, src_pad| {
-        // if let Some(pipeline) = pipeline_weak.upgrade() {
-        //     // 创建音频处理链元素
-        //     let audioconvert = gstreamer::ElementFactory::make("audioconvert")
-        //         .build()
-        //         .expect("Failed to create audioconvert element");
-        //     // 音频采样
-        //     let audioresample = gstreamer::ElementFactory::make("audioresample")
-        //         .build()
-        //         .expect("Failed to create audioresample element");
-        //     //
-        //     let autoaudiosink = gstreamer::ElementFactory::make("autoaudiosink")
-        //         .build()
-        //         .expect("Failed to create autoaudiosink element");
-        //     // 创建并设置volume元素 - 在音频链中
-        //     let set_volume = 0.10f64; // 10% 音量
-        //     // 音量控制
-        //     let volume = gstreamer::ElementFactory::make("volume")
-        //         .property("volume", set_volume)
-        //         .build()
-        //         .expect("Failed to create volume element");
-
-        //     pipeline
-        //         .add_many([&audioconvert, &audioresample, &autoaudiosink, &volume])
-        //         .expect("Failed to add elements to pipeline");
-
-        //     audioconvert
-        //         .sync_state_with_parent()
-        //         .expect("Failed to sync_state_with_parent for audioconvert");
-        //     audioresample
-        //         .sync_state_with_parent()
-        //         .expect("Failed to sync_state_with_parent for audioresample");
-        //     autoaudiosink
-        //         .sync_state_with_parent()
-        //         .expect("Failed to sync_state_with_parent for autoaudiosink");
-
-        //     let audio_pad = audioconvert
-        //         .static_pad("sink")
-        //         .expect("Failed to get static pad");
-        //     src_pad.link(&audio_pad).expect("Failed to link pads");
-
-        //     audioconvert
-        //         .link(&audioresample)
-        //         .expect("Failed to link audioconvert to audioresample");
-        //     audioresample
-        //         .link(&autoaudiosink)
-        //         .expect("Failed to link audioresample to autoaudiosink");
-
-        //     tracing::info!("Pipeline elements linked successfully");
-        // } else {
-        //     tracing::error!("Failed to upgrade pipeline reference");
-        // }
-
-        if let (Some(pipeline), Some(volume)) = (pipeline_weak.upgrade(), volume_weak.upgrade()) {
-            // 检查pad是否为音频
-            let caps = src_pad.current_caps();
-            if let Some(caps) = caps {
-                if let Some(structure) = caps.structure(0) {
-                    let name = structure.name();
-                    // tracing::info!("Pad caps name: {}", name);
-
-                    if name.starts_with("audio/") {
-                        // tracing::info!("Audio pad detected, building audio chain");
-                        // 创建audio chain
-                        let audioconvert =
-                            match gstreamer::ElementFactory::make("audioconvert").build() {
-                                Ok(el) => el,
-                                Err(e) => {
-                                    tracing::error!("Failed to create audioconvert: {:?}", e);
-                                    return;
-                                }
-                            };
-
-                        let audioresample =
-                            match gstreamer::ElementFactory::make("audioresample").build() {
-                                Ok(el) => el,
-                                Err(e) => {
-                                    tracing::error!("Failed to create audioresample: {:?}", e);
-                                    return;
-                                }
-                            };
-
-                        let autoaudiosink =
-                            match gstreamer::ElementFactory::make("autoaudiosink").build() {
-                                Ok(el) => el,
-                                Err(e) => {
-                                    tracing::error!("Failed to create autoaudiosink: {:?}", e);
-                                    return;
-                                }
-                            };
+/// 恢复已删除的音乐，属于当前歌单的追加到播放列表末尾
+async fn restore_music(
+    repository: &MusicRepository,
+    playlists: &PlaylistRepository,
+    bvid: &str,
+    cid: Option<&str>,
+) -> Result<Vec<Music>, ApplicationError> {
+    let records = repository.restore(bvid, cid).await?;
+    if records.is_empty() {
+        let existing = repository.find_by_bvid(bvid).await?;
+        return if existing
+            .iter()
+            .any(|record| cid.is_none_or(|cid| record.cid == cid))
+        {
+            Err(ApplicationError::AlreadyExistsError(format!(
+                "{} 未被删除",
+                music_label(bvid, cid)
+            )))
+        } else {
+            Err(ApplicationError::NotFoundError(format!(
+                "{} 不在音乐库中",
+                music_label(bvid, cid)
+            )))
+        };
+    }
+    let active = active_playlist(playlists).await?;
+    let mut in_active = false;
+    for record in &records {
+        in_active |= playlists.contains(active.id, record.id).await?;
+    }
+    // 恢复的歌曲回到它在歌单中原来的位置，重新加载播放列表保持和数据库中的顺序一致
+    if in_active {
+        replace_playlist(Playlist::load_active(playlists).await?).await?;
+    }
+    Ok(records.into_iter().map(Music::from).collect())
+}
 
-                        // 添加到pipeline
-                        if pipeline
-                            .add_many([&audioconvert, &audioresample, &autoaudiosink])
-                            .is_err()
-                        {
-                            tracing::error!("Failed to add audio elements to pipeline");
-                            return;
-                        }
+/// 获取当前激活的歌单
+async fn active_playlist(
+    playlists: &PlaylistRepository,
+) -> Result<PlaylistRecord, ApplicationError> {
+    playlists
+        .active()
+        .await?
+        .ok_or_else(|| ApplicationError::NotFoundError("没有正在播放的歌单".to_string()))
+}
 
-                        // 确保volume已经在pipeline中
-                        if volume.parent().is_none() && pipeline.add(&volume).is_err() {
-                            tracing::error!("Failed to add volume to pipeline");
-                        }
+/// 将新导入的音乐加入当前歌单，并追加到播放列表末尾
+async fn add_musics_to_active_playlist(
+    repository: &MusicRepository,
+    playlists: &PlaylistRepository,
+    musics: Vec<Music>,
+) -> Result<Vec<Music>, ApplicationError> {
+    let active = active_playlist(playlists).await?;
+    for music in &musics {
+        let record = repository
+            .find(&music.bvid, &music.cid)
+            .await?
+            .ok_or_else(|| ApplicationError::NotFoundError(format!("{music:?} 不在音乐库中")))?;
+        // 恢复之前删除的音乐时，歌单中可能已有该条目，已在播放列表中的不再追加
+        if playlists.add_music(active.id, record.id).await? {
+            add_music_to_playlist(music.clone()).await?;
+        }
+    }
+    Ok(musics)
+}
 
-                        // 同步状态
-                        let _ = audioconvert.sync_state_with_parent();
-                        let _ = audioresample.sync_state_with_parent();
-                        let _ = autoaudiosink.sync_state_with_parent();
-                        let _ = volume.sync_state_with_parent();
-
-                        // 获取audioconvert的sink pad
-                        let audio_pad = match audioconvert.static_pad("sink") {
-                            Some(pad) => pad,
-                            None => {
-                                tracing::error!("Failed to get audioconvert sink pad");
-                                return;
-                            }
-                        };
+/// 切换当前播放的歌单
+///
+/// 正在播放的歌曲也在新歌单中时继续播放，否则从新歌单的第一首开始
+async fn switch_playlist(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
+    playlists: &PlaylistRepository,
+    name: &str,
+) -> Result<PlaylistSummary, ApplicationError> {
+    let active = playlists.set_active(name).await?;
+    let musics = playlists
+        .musics(active.id)
+        .await?
+        .into_iter()
+        .map(Music::from)
+        .collect();
+    let kept_current = replace_playlist(Playlist { musics }).await?;
+    // 停止状态下只切换列表，等待下一次播放
+    if !kept_current && pipeline.current_state() != gstreamer::State::Null {
+        replay_current_music(pipeline, volume, client).await?;
+    }
+    playlists.summary(&active.name).await
+}
 
-                        // 链接所有元素
-                        if src_pad.link(&audio_pad).is_err() {
-                            tracing::error!("Failed to link decodebin to audioconvert");
-                            return;
-                        }
+/// 添加音乐到指定歌单，音乐库中没有选中的分P时先导入
+///
+/// 返回添加的音乐以及该歌单是否为当前播放的歌单
+async fn add_to_playlist(
+    client: &reqwest::Client,
+    repository: &MusicRepository,
+    playlists: &PlaylistRepository,
+    request: &AddToPlaylistRequest,
+) -> Result<(Vec<Music>, bool), ApplicationError> {
+    let pages: PageSelection = request.pages.parse()?;
+    let bvid = request.bvid.as_str();
+    let playlist = playlists.get_by_name(&request.name).await?;
+    let mut records: Vec<_> = repository
+        .find_by_bvid(bvid.trim())
+        .await?
+        .into_iter()
+        .filter(|record| !record.is_deleted && pages.contains(record.page as u32))
+        .collect();
+    if records.is_empty() {
+        for music in import_bvid(client, BILIBILI_API_BASE, repository, bvid, None, pages).await? {
+            if let Some(record) = repository.find(&music.bvid, &music.cid).await? {
+                records.push(record);
+            }
+        }
+    }
+    let mut musics = Vec::new();
+    for record in records {
+        if !playlists.add_music(playlist.id, record.id).await? {
+            continue;
+        }
+        let music = Music::from(record);
+        if playlist.is_active {
+            add_music_to_playlist(music.clone()).await?;
+        }
+        musics.push(music);
+    }
+    if musics.is_empty() {
+        return Err(ApplicationError::AlreadyExistsError(format!(
+            "{} 已在歌单 {} 中",
+            bvid.trim(),
+            playlist.name
+        )));
+    }
+    Ok((musics, playlist.is_active))
+}
 
-                        if audioconvert.link(&volume).is_err() {
-                            tracing::error!("Failed to link audioconvert to volume");
-                            return;
-                        }
+/// 从指定歌单中移除音乐，音乐库中的记录保持不变
+///
+/// 返回移除的音乐以及该歌单是否为当前播放的歌单
+async fn remove_from_playlist(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
+    repository: &MusicRepository,
+    playlists: &PlaylistRepository,
+    request: &RemoveFromPlaylistRequest,
+) -> Result<(Vec<Music>, bool), ApplicationError> {
+    let (bvid, cid) = (request.bvid.as_str(), cid_filter(&request.cid));
+    let playlist = playlists.get_by_name(&request.name).await?;
+    let mut removed_current = false;
+    let mut musics = Vec::new();
+    for record in repository.find_by_bvid(bvid.trim()).await? {
+        if cid.is_some_and(|cid| record.cid != cid)
+            || !playlists.remove_music(playlist.id, record.id).await?
+        {
+            continue;
+        }
+        let music = Music::from(record);
+        if playlist.is_active
+            && let Some((_, is_current)) =
+                remove_music_from_playlist(&music.bvid, &music.cid).await?
+        {
+            removed_current |= is_current;
+        }
+        musics.push(music);
+    }
+    if musics.is_empty() {
+        return Err(ApplicationError::NotFoundError(format!(
+            "{} 不在歌单 {} 中",
+            music_label(bvid, cid),
+            playlist.name
+        )));
+    }
+    if removed_current {
+        replay_current_music(pipeline, volume, client).await?;
+    }
+    Ok((musics, playlist.is_active))
+}
 
-                        if volume.link(&audioresample).is_err() {
-                            tracing::error!("Failed to link volume to audioresample");
-                            return;
-                        }
+/// 请求中的 cid 为空时表示整个视频
+fn cid_filter(cid: &str) -> Option<&str> {
+    Some(cid.trim()).filter(|cid| !cid.is_empty())
+}
 
-                        if audioresample.link(&autoaudiosink).is_err() {
-                            tracing::error!("Failed to link audioresample to sink");
-                            return;
-                        }
+// 错误信息中显示的歌曲标识
+fn music_label(bvid: &str, cid: Option<&str>) -> String {
+    match cid {
+        Some(cid) => format!("{} ({})", bvid.trim(), cid),
+        None => bvid.trim().to_string(),
+    }
+}
 
-                        // tracing::info!("Audio chain linked successfully");
+// 错误来自 souphttpsrc 的读取失败时返回出错的地址
+fn failed_http_location(msg: &gstreamer::Message) -> Option<String> {
+    let MessageView::Error(err) = msg.view() else {
+        return None;
+    };
+    err.error().kind::<gstreamer::ResourceError>()?;
+    let source = msg.src()?.downcast_ref::<gstreamer::Element>()?;
+    if source.factory()?.name() != "souphttpsrc" {
+        return None;
+    }
+    Some(source.property::<String>("location"))
+}
 
-                        // 双重检查音量设置
-                        let vol_value: f64 = volume.property("volume");
-                        tracing::info!("Volume element property value: {:.2}", vol_value);
-                    } else {
-                        tracing::info!("Non-audio pad, ignoring");
-                    }
-                }
-            } else {
-                tracing::error!("Failed to upgrade weak references");
-            }
-        }
-    });
+/// 设置 pipeline 的 uri 和 headers
+///
+/// 建立共用的输出链，再把这首歌曲的解码分支接到 audiomixer 上
+async fn set_pipeline_uri_with_headers(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    music: &Music,
+    url: &str,
+) -> Result<(), ApplicationError> {
+    let gain = normalization_gain(music).await;
+    build_output_chain(pipeline, volume)?;
+    let branch = add_track_branch(pipeline, url, 0, gain)?;
+    watch_loudness(&branch, music);
+    track_branches().active = Some(branch);
 
-    // pipeline.set_state(gstreamer::State::Playing).map_err(|_| {
-    //     ApplicationError::StateError("Failed to set pipeline to Playing".to_string())
-    // })?;
     tracing::info!("Setting pipeline to Playing state");
     match pipeline.set_state(gstreamer::State::Playing) {
         Ok(_) => {
@@ -521,3 +1376,177 @@ async fn set_pipeline_uri_with_headers(
         }
     }
 }
+
+// 当前歌曲的播放位置 (秒)
+fn track_position(pipeline: &gstreamer::Pipeline) -> Option<f64> {
+    track_branches()
+        .track_position(pipeline)
+        .map(|t| t as f64 / 1_000_000_000.0)
+}
+
+// 当前歌曲的时长 (秒)
+fn track_duration() -> Option<f64> {
+    track_branches()
+        .active
+        .as_ref()?
+        .duration()
+        .map(|t| t as f64 / 1_000_000_000.0)
+}
+
+/// 预加载下一首歌曲：请求并验证地址，把解码分支接到 audiomixer 上
+///
+/// 分支的开始时间设为当前歌曲结束前 fade 的位置，到时 audiomixer 直接衔接或交叉混合。
+/// 期间 pipeline 重建时放弃这次预加载
+async fn preload_next_track(
+    pipeline: &gstreamer::Pipeline,
+    client: &reqwest::Client,
+    play_mode: PlayMode,
+    fade: Duration,
+) -> Result<(), ApplicationError> {
+    let generation = track_branches().generation;
+    let music = peek_next_music(play_mode).await?;
+    let stream = fetch_and_verify_audio_url(
+        client,
+        BILIBILI_API_BASE,
+        &music.bvid,
+        &music.cid,
+        current_audio_preference(),
+    )
+    .await?;
+    let gain = normalization_gain(&music).await;
+    // 请求地址期间歌曲被移出播放列表时放弃
+    let in_playlist = find_music_in_playlist(&music).await.is_some();
+    let mut branches = track_branches();
+    if !in_playlist || branches.generation != generation || branches.preloaded.is_some() {
+        return Ok(());
+    }
+    let (Some(active), Some(running_time)) =
+        (branches.active.as_ref(), branches.running_time(pipeline))
+    else {
+        return Ok(());
+    };
+    let duration = active.duration().ok_or_else(|| {
+        ApplicationError::StateError("Failed to query current music duration".to_string())
+    })?;
+    // 剩余时间不够淡入淡出时从现在开始
+    let origin = (active.origin + duration - fade.as_nanos() as i64).max(running_time);
+    let branch = add_track_branch(pipeline, &stream.base_url, origin, gain)?;
+    if !fade.is_zero() {
+        branch.set_level(0.0);
+    }
+    watch_loudness(&branch, &music);
+    sync_branch_state(&branch)?;
+    tracing::info!("Preloaded next music: {:?}", music);
+    branches.preloaded = Some(PreloadedTrack {
+        music,
+        stream,
+        branch,
+        fade,
+    });
+    Ok(())
+}
+
+/// 切换到当前索引的歌曲，开启淡入淡出且正在播放时与上一首交叉混合
+async fn skip_to_current_music(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+    client: &reqwest::Client,
+    crossfade: &Crossfade,
+    event_sender: &broadcast::Sender<PlayerEvent>,
+) -> Result<(), ApplicationError> {
+    let fade = crossfade.duration();
+    let can_crossfade = !fade.is_zero()
+        && playback_rate().settings().is_normal()
+        && pipeline.current_state() == gstreamer::State::Playing
+        && track_branches().active.is_some();
+    if !can_crossfade {
+        return play_music(pipeline, volume, client).await;
+    }
+    let generation = track_branches().generation;
+    let music = get_current_music().await?;
+    let stream = fetch_and_verify_audio_url(
+        client,
+        BILIBILI_API_BASE,
+        &music.bvid,
+        &music.cid,
+        current_audio_preference(),
+    )
+    .await?;
+    let gain = normalization_gain(&music).await;
+    {
+        let mut branches = track_branches();
+        // 请求地址期间 pipeline 已经重建，不再混合
+        if branches.generation != generation {
+            return Ok(());
+        }
+        let running_time = branches.running_time(pipeline).ok_or_else(|| {
+            ApplicationError::StateError("Failed to query playback position".to_string())
+        })?;
+        branches.discard_preloaded(pipeline);
+        let branch = add_track_branch(pipeline, &stream.base_url, running_time, gain)?;
+        branch.set_level(0.0);
+        watch_loudness(&branch, &music);
+        sync_branch_state(&branch)?;
+        branches.switch_to(pipeline, branch, fade);
+    }
+    tracing::info!("Crossfade to {:?}", music);
+    *CURRENT_AUDIO_STREAM.lock().await = Some(stream);
+    send_track_changed(event_sender).await;
+    Ok(())
+}
+
+/// 按响度均衡设置计算歌曲的增益，album 模式使用当前歌单的整体响度
+async fn normalization_gain(music: &Music) -> f64 {
+    let normalization = current_normalization();
+    let album = match normalization.mode {
+        NormalizationMode::Album => {
+            let playlist = PLAYLIST.lock().await;
+            playlist
+                .as_ref()
+                .ok()
+                .and_then(|playlist| playlist_loudness(&playlist.musics))
+        }
+        _ => None,
+    };
+    normalization.gain(measured_loudness(music), album)
+}
+
+// 开启响度均衡时测量还没有测量过的歌曲，播放到结尾后保存到音乐库
+fn watch_loudness(branch: &TrackBranch, music: &Music) {
+    if current_normalization().is_enabled() && measured_loudness(music).is_none() {
+        let music = music.clone();
+        branch.measure_loudness(move |loudness| record_loudness(&music, loudness));
+    }
+}
+
+/// 切换到预加载的歌曲后更新当前歌曲和音频流
+///
+/// 预加载之后播放列表可能有变化，重新查找歌曲的位置；歌曲已不在播放列表中时返回 false
+async fn promote_preloaded_track(music: Music, stream: AudioStream) -> bool {
+    let Some(index) = find_music_in_playlist(&music).await else {
+        tracing::warn!("Preloaded music {:?} is no longer in the playlist", music);
+        return false;
+    };
+    if let Err(e) = set_current_music_index(index).await {
+        tracing::error!("Failed to switch to preloaded music: {}", e);
+    }
+    *CURRENT_AUDIO_STREAM.lock().await = Some(stream);
+    tracing::info!("Switched to preloaded music {:?}", music);
+    true
+}
+
+/// 发送当前歌曲的切换事件
+async fn send_track_changed(event_sender: &broadcast::Sender<PlayerEvent>) {
+    if let Ok(music) = get_current_music().await {
+        let index = *CURRENT_MUSIC_INDEX.lock().await;
+        let quality = current_audio_quality().await;
+        send_event(
+            event_sender,
+            PlayerEvent::TrackChanged {
+                index,
+                music,
+                quality,
+            },
+        );
+    }
+}
@@ -1,6 +1,25 @@
+use std::str::FromStr;
+
+use tokio::sync::oneshot;
+
 use crate::{
-    pb::{AddPlaylistRequest, DeletedRequest, PlayBvidRequest, SetModelRequest, SetVolumeRequest},
-    player::state::PlayerStateSnapshot,
+    errors::ApplicationError,
+    fetch::network::VideoFilter,
+    library::{
+        import::{FavoriteImportReport, VideoSource, VideosImportReport},
+        playlist_repository::PlaylistSummary,
+    },
+    pb::{
+        AddPlaylistRequest, AddToPlaylistRequest, DeletedRequest, ImportFavoriteRequest,
+        PlayBvidRequest, RemoveFromPlaylistRequest, RestoreRequest, SetModelRequest,
+        SetVolumeRequest, ShowPlayListRequest, SwitchPlaylistRequest, set_volume_request::Action,
+    },
+    player::{
+        equalizer::EqualizerSettings,
+        play_list::PlaylistPage,
+        rate::RateSettings,
+        state::{Music, PlayerStateSnapshot},
+    },
 };
 
 #[derive(Debug)]
@@ -12,12 +31,76 @@ pub enum PlayerCommand {
     Previous,
     Stop,
     SetModel(SetModelRequest),
-    SetVolume(SetVolumeRequest),
-    AddPlaylist(AddPlaylistRequest),
-    Delete(DeletedRequest),
+    SetVolume(
+        VolumeAction,
+        oneshot::Sender<Result<(u32, bool), ApplicationError>>,
+    ),
+    AddPlaylist(
+        AddPlaylistRequest,
+        oneshot::Sender<Result<Vec<Music>, ApplicationError>>,
+    ),
+    Delete(
+        DeletedRequest,
+        oneshot::Sender<Result<Vec<Music>, ApplicationError>>,
+    ),
+    Restore(
+        RestoreRequest,
+        oneshot::Sender<Result<Vec<Music>, ApplicationError>>,
+    ),
     GetState(tokio::sync::oneshot::Sender<PlayerStateSnapshot>),
-    ShowPlaylist(),
-    Seek(u64),
+    ShowPlaylist(
+        ShowPlayListRequest,
+        oneshot::Sender<Result<PlaylistPage, ApplicationError>>,
+    ),
+    Seek(SeekTarget, oneshot::Sender<Result<f64, ApplicationError>>),
+    SetCrossfade(u32, oneshot::Sender<Result<u32, ApplicationError>>),
+    SetEqualizer(
+        EqualizerAction,
+        oneshot::Sender<Result<EqualizerSettings, ApplicationError>>,
+    ),
+    SetRate(
+        RateSettings,
+        oneshot::Sender<Result<RateSettings, ApplicationError>>,
+    ),
+    SwitchPlaylist(
+        SwitchPlaylistRequest,
+        oneshot::Sender<Result<PlaylistSummary, ApplicationError>>,
+    ),
+    AddToPlaylist(
+        AddToPlaylistRequest,
+        oneshot::Sender<Result<Vec<Music>, ApplicationError>>,
+    ),
+    RemoveFromPlaylist(
+        RemoveFromPlaylistRequest,
+        oneshot::Sender<Result<Vec<Music>, ApplicationError>>,
+    ),
+    // 保存播放会话并停止播放，服务退出前发送
+    ImportFavorite(
+        ImportFavoriteRequest,
+        oneshot::Sender<Result<FavoriteImportReport, ApplicationError>>,
+    ),
+    ImportVideos(
+        VideoSource,
+        VideoFilter,
+        oneshot::Sender<Result<VideosImportReport, ApplicationError>>,
+    ),
+    Shutdown(oneshot::Sender<Result<(), ApplicationError>>),
+}
+impl PlayerCommand {
+    /// 是否会修改播放列表，执行后预加载的下一首可能已经失效
+    pub fn changes_playlist(&self) -> bool {
+        matches!(
+            self,
+            PlayerCommand::AddPlaylist(..)
+                | PlayerCommand::Delete(..)
+                | PlayerCommand::Restore(..)
+                | PlayerCommand::SwitchPlaylist(..)
+                | PlayerCommand::AddToPlaylist(..)
+                | PlayerCommand::RemoveFromPlaylist(..)
+                | PlayerCommand::ImportFavorite(..)
+                | PlayerCommand::ImportVideos(..)
+        )
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Default)]
@@ -35,6 +118,14 @@ impl PlayMode {
             PlayMode::Repeat => "单曲循环".to_string(),
         }
     }
+    /// 播放模式的英文标识，与 from_string 对应，用于保存到数据库
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PlayMode::Normal => "normal",
+            PlayMode::Shuffle => "shuffle",
+            PlayMode::Repeat => "repeat",
+        }
+    }
     pub fn from_string(s: &str) -> Option<Self> {
         match s {
             "shuffle" => Some(PlayMode::Shuffle),
@@ -43,3 +134,108 @@ impl PlayMode {
         }
     }
 }
+impl From<PlayMode> for crate::pb::PlayMode {
+    fn from(mode: PlayMode) -> Self {
+        match mode {
+            PlayMode::Normal => crate::pb::PlayMode::Normal,
+            PlayMode::Shuffle => crate::pb::PlayMode::Shuffle,
+            PlayMode::Repeat => crate::pb::PlayMode::Repeat,
+        }
+    }
+}
+
+/// 音量调整方式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum VolumeAction {
+    Set(u32),    // 设置音量百分比
+    Adjust(i32), // 相对调整音量
+    Mute(bool),  // 静音或取消静音
+}
+impl TryFrom<SetVolumeRequest> for VolumeAction {
+    type Error = ApplicationError;
+
+    fn try_from(request: SetVolumeRequest) -> Result<Self, Self::Error> {
+        match request.action {
+            Some(Action::Level(level)) => Ok(VolumeAction::Set(level)),
+            Some(Action::Delta(delta)) => Ok(VolumeAction::Adjust(delta)),
+            Some(Action::Mute(mute)) => Ok(VolumeAction::Mute(mute)),
+            None => Err(ApplicationError::InvalidArgumentError(
+                "缺少音量参数".to_string(),
+            )),
+        }
+    }
+}
+
+/// 均衡器调整方式
+#[derive(Debug, Clone, PartialEq)]
+pub enum EqualizerAction {
+    Band(usize, f64),         // 调整一个频段的增益 (dB)
+    Apply(EqualizerSettings), // 替换所有频段的增益，如应用预设
+}
+
+/// 跳转目标
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SeekTarget {
+    Absolute(f64), // 跳转到指定位置 (秒)
+    Relative(f64), // 相对当前位置前进或后退 (秒)
+}
+impl FromStr for SeekTarget {
+    type Err = String;
+
+    /// 支持 `83`、`1:23`、`1:02:03` 形式的绝对位置，以及 `+10`、`-5`、`+1:00` 形式的相对偏移
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        let (sign, time) = match s.as_bytes().first() {
+            Some(b'+') => (Some(1.0), &s[1..]),
+            Some(b'-') => (Some(-1.0), &s[1..]),
+            _ => (None, s),
+        };
+        let mut seconds = 0.0;
+        let parts: Vec<&str> = time.split(':').collect();
+        if parts.len() > 3 {
+            return Err(format!("无效的时间: {s}"));
+        }
+        for part in parts {
+            let value: f64 = part.parse().map_err(|_| format!("无效的时间: {s}"))?;
+            if !value.is_finite() || value < 0.0 {
+                return Err(format!("无效的时间: {s}"));
+            }
+            seconds = seconds * 60.0 + value;
+        }
+        Ok(match sign {
+            Some(sign) => SeekTarget::Relative(sign * seconds),
+            None => SeekTarget::Absolute(seconds),
+        })
+    }
+}
+impl TryFrom<crate::pb::SeekRequest> for SeekTarget {
+    type Error = ApplicationError;
+
+    fn try_from(request: crate::pb::SeekRequest) -> Result<Self, Self::Error> {
+        if !request.position.is_finite() {
+            return Err(ApplicationError::InvalidArgumentError(format!(
+                "无效的时间: {}",
+                request.position
+            )));
+        }
+        Ok(if request.relative {
+            SeekTarget::Relative(request.position)
+        } else {
+            SeekTarget::Absolute(request.position)
+        })
+    }
+}
+impl From<SeekTarget> for crate::pb::SeekRequest {
+    fn from(target: SeekTarget) -> Self {
+        match target {
+            SeekTarget::Absolute(position) => crate::pb::SeekRequest {
+                position,
+                relative: false,
+            },
+            SeekTarget::Relative(position) => crate::pb::SeekRequest {
+                position,
+                relative: true,
+            },
+        }
+    }
+}
@@ -0,0 +1,47 @@
+use std::{
+    sync::atomic::{AtomicU32, Ordering},
+    time::Duration,
+};
+
+use crate::errors::ApplicationError;
+
+// 最长的淡入淡出时长 (秒)
+pub const MAX_CROSSFADE_SECONDS: u32 = 12;
+
+/// 切换歌曲时上一首淡出、下一首淡入的重叠时长，为 0 时无缝衔接
+#[derive(Debug, Default)]
+pub struct Crossfade {
+    seconds: AtomicU32, // 重叠时长 (0-12 秒)
+}
+
+impl Crossfade {
+    pub fn new(seconds: u32) -> Result<Self, ApplicationError> {
+        check_seconds(seconds)?;
+        Ok(Self {
+            seconds: AtomicU32::new(seconds),
+        })
+    }
+    /// 获取淡入淡出时长 (秒)
+    pub fn seconds(&self) -> u32 {
+        self.seconds.load(Ordering::Relaxed)
+    }
+    /// 设置淡入淡出时长 (秒)
+    pub fn set(&self, seconds: u32) -> Result<u32, ApplicationError> {
+        check_seconds(seconds)?;
+        self.seconds.store(seconds, Ordering::Relaxed);
+        Ok(seconds)
+    }
+    /// 淡入淡出时长
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs(self.seconds() as u64)
+    }
+}
+
+fn check_seconds(seconds: u32) -> Result<(), ApplicationError> {
+    if seconds > MAX_CROSSFADE_SECONDS {
+        return Err(ApplicationError::InvalidArgumentError(format!(
+            "淡入淡出时长在：0-{MAX_CROSSFADE_SECONDS} 秒"
+        )));
+    }
+    Ok(())
+}
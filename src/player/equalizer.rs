@@ -0,0 +1,164 @@
+use std::sync::{Mutex, MutexGuard};
+
+use gstreamer::{glib::object::ObjectExt, prelude::GstBinExt};
+use once_cell::sync::Lazy;
+
+use crate::errors::ApplicationError;
+
+// pipeline 中均衡器元素的名称
+pub const EQUALIZER_ELEMENT_NAME: &str = "audio_equalizer";
+// equalizer-10bands 的频段数量
+pub const BAND_COUNT: usize = 10;
+// 各频段的中心频率 (Hz)，由 equalizer-10bands 固定
+pub const BAND_FREQUENCIES: [u32; BAND_COUNT] =
+    [29, 59, 119, 237, 474, 947, 1889, 3770, 7523, 15011];
+// 频段增益的范围 (dB)
+pub const MIN_BAND_GAIN: f64 = -24.0;
+pub const MAX_BAND_GAIN: f64 = 12.0;
+
+/// 各频段的增益 (dB)，从低频到高频
+pub type BandGains = [f64; BAND_COUNT];
+
+// 当前的均衡器设置，pipeline 重建输出链时从这里读取
+static EQUALIZER: Lazy<Equalizer> = Lazy::new(Equalizer::default);
+
+/// 均衡器设置
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct EqualizerSettings {
+    pub gains: BandGains,
+    // 使用的预设名称，调整过频段后为 None
+    pub preset: Option<String>,
+}
+
+impl EqualizerSettings {
+    /// 所有频段的增益都为 0 时不影响声音
+    pub fn is_flat(&self) -> bool {
+        self.gains.iter().all(|gain| *gain == 0.0)
+    }
+
+    /// 显示的名称：预设名称，调整过频段时为 "自定义"
+    pub fn label(&self) -> String {
+        match &self.preset {
+            Some(preset) => preset.clone(),
+            None if self.is_flat() => "flat".to_string(),
+            None => "自定义".to_string(),
+        }
+    }
+}
+
+/// 均衡器，切换歌曲时保持不变
+#[derive(Debug, Default)]
+pub struct Equalizer {
+    settings: Mutex<EqualizerSettings>,
+}
+
+impl Equalizer {
+    /// 获取当前设置
+    pub fn settings(&self) -> EqualizerSettings {
+        self.lock().clone()
+    }
+
+    /// 替换所有频段的增益，如应用预设或恢复上次的会话
+    pub fn set(&self, settings: EqualizerSettings) -> Result<EqualizerSettings, ApplicationError> {
+        for gain in &settings.gains {
+            check_gain(*gain)?;
+        }
+        *self.lock() = settings.clone();
+        Ok(settings)
+    }
+
+    /// 调整一个频段的增益，之后不再对应某个预设
+    pub fn set_band(&self, band: usize, gain: f64) -> Result<EqualizerSettings, ApplicationError> {
+        if band >= BAND_COUNT {
+            return Err(ApplicationError::InvalidArgumentError(format!(
+                "频段在：0-{}",
+                BAND_COUNT - 1
+            )));
+        }
+        check_gain(gain)?;
+        let mut settings = self.lock();
+        settings.gains[band] = gain;
+        settings.preset = None;
+        Ok(settings.clone())
+    }
+
+    /// 应用到 pipeline 中的均衡器元素
+    pub fn apply(&self, pipeline: &gstreamer::Pipeline) {
+        if let Some(element) = pipeline.by_name(EQUALIZER_ELEMENT_NAME) {
+            apply_gains(&element, &self.lock().gains);
+        }
+    }
+
+    // 锁中毒时继续使用里面的数据
+    fn lock(&self) -> MutexGuard<'_, EqualizerSettings> {
+        self.settings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// 获取全局的均衡器
+pub fn equalizer() -> &'static Equalizer {
+    &EQUALIZER
+}
+
+/// 设置 equalizer-10bands 元素各频段的增益
+pub fn apply_gains(element: &gstreamer::Element, gains: &BandGains) {
+    for (band, gain) in gains.iter().enumerate() {
+        element.set_property(&format!("band{band}"), gain);
+    }
+}
+
+/// 解析逗号分隔的频段增益，空字符串表示所有频段为 0
+pub fn parse_gains(value: &str) -> Result<BandGains, ApplicationError> {
+    let mut gains = BandGains::default();
+    if value.trim().is_empty() {
+        return Ok(gains);
+    }
+    let values: Vec<&str> = value.split(',').collect();
+    if values.len() != BAND_COUNT {
+        return Err(ApplicationError::InvalidArgumentError(format!(
+            "需要 {BAND_COUNT} 个频段的增益，实际为 {}",
+            values.len()
+        )));
+    }
+    for (gain, value) in gains.iter_mut().zip(values) {
+        *gain = value.trim().parse::<f64>().map_err(|_| {
+            ApplicationError::InvalidArgumentError(format!("无效的增益: {}", value.trim()))
+        })?;
+        check_gain(*gain)?;
+    }
+    Ok(gains)
+}
+
+/// 把频段增益格式化为逗号分隔的字符串，用于保存到数据库
+pub fn format_gains(gains: &BandGains) -> String {
+    gains
+        .iter()
+        .map(|gain| gain.to_string())
+        .collect::<Vec<_>>()
+        .join(",")
+}
+
+/// 从任意数量的增益转换为各频段的增益，数量不对时返回错误
+pub fn band_gains(values: &[f64]) -> Result<BandGains, ApplicationError> {
+    let gains: BandGains = values.try_into().map_err(|_| {
+        ApplicationError::InvalidArgumentError(format!(
+            "需要 {BAND_COUNT} 个频段的增益，实际为 {}",
+            values.len()
+        ))
+    })?;
+    for gain in &gains {
+        check_gain(*gain)?;
+    }
+    Ok(gains)
+}
+
+fn check_gain(gain: f64) -> Result<(), ApplicationError> {
+    if !(MIN_BAND_GAIN..=MAX_BAND_GAIN).contains(&gain) {
+        return Err(ApplicationError::InvalidArgumentError(format!(
+            "频段增益在：{MIN_BAND_GAIN}-{MAX_BAND_GAIN} dB"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,116 @@
+use sqlx::types::chrono::Utc;
+
+use crate::{
+    fetch::quality::AudioQuality,
+    pb::{self, subscribe_events_response::Event},
+    player::{
+        command::PlayMode,
+        state::{Music, PlaybackState},
+    },
+};
+
+// 事件通道容量，订阅者处理过慢时会丢弃最旧的事件
+pub const EVENT_CHANNEL_CAPACITY: usize = 64;
+
+/// 播放列表变更类型
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlaylistAction {
+    Added,
+    Removed,
+    Restored,
+}
+
+/// 播放器事件，通过 broadcast 通道推送给所有订阅者
+#[derive(Debug, Clone, PartialEq)]
+pub enum PlayerEvent {
+    // 开始播放新的歌曲
+    TrackChanged {
+        index: usize,
+        music: Music,
+        quality: Option<AudioQuality>,
+    },
+    // 播放状态变化，缓冲时附带缓冲进度
+    StateChanged {
+        state: PlaybackState,
+        buffering_percent: Option<i32>,
+    },
+    // 音量变化
+    VolumeChanged {
+        volume: u32,
+        muted: bool,
+    },
+    // 播放模式变化
+    ModeChanged(PlayMode),
+    // 播放列表变化
+    PlaylistChanged {
+        action: PlaylistAction,
+        music: Music,
+        playlist_len: usize,
+    },
+    // 切换了当前播放的歌单
+    PlaylistSwitched {
+        name: String,
+        playlist_len: usize,
+    },
+    // 播放出错
+    Error(String),
+}
+
+impl From<PlaylistAction> for pb::PlaylistAction {
+    fn from(action: PlaylistAction) -> Self {
+        match action {
+            PlaylistAction::Added => pb::PlaylistAction::Added,
+            PlaylistAction::Removed => pb::PlaylistAction::Removed,
+            PlaylistAction::Restored => pb::PlaylistAction::Restored,
+        }
+    }
+}
+
+impl From<PlayerEvent> for pb::SubscribeEventsResponse {
+    fn from(event: PlayerEvent) -> Self {
+        let event = match event {
+            PlayerEvent::TrackChanged {
+                index,
+                music,
+                quality,
+            } => Event::TrackChanged(pb::TrackChangedEvent {
+                index: index as u32,
+                music: Some(music.into()),
+                quality: quality.map(Into::into),
+            }),
+            PlayerEvent::StateChanged {
+                state,
+                buffering_percent,
+            } => Event::StateChanged(pb::StateChangedEvent {
+                state: pb::PlaybackState::from(state).into(),
+                buffering_percent,
+            }),
+            PlayerEvent::VolumeChanged { volume, muted } => {
+                Event::VolumeChanged(pb::VolumeChangedEvent { volume, muted })
+            }
+            PlayerEvent::ModeChanged(play_mode) => Event::ModeChanged(pb::ModeChangedEvent {
+                play_mode: pb::PlayMode::from(play_mode).into(),
+            }),
+            PlayerEvent::PlaylistChanged {
+                action,
+                music,
+                playlist_len,
+            } => Event::PlaylistChanged(pb::PlaylistChangedEvent {
+                action: pb::PlaylistAction::from(action).into(),
+                music: Some(music.into()),
+                playlist_len: playlist_len as u32,
+            }),
+            PlayerEvent::PlaylistSwitched { name, playlist_len } => {
+                Event::PlaylistSwitched(pb::PlaylistSwitchedEvent {
+                    name,
+                    playlist_len: playlist_len as u32,
+                })
+            }
+            PlayerEvent::Error(message) => Event::Error(pb::ErrorEvent { message }),
+        };
+        pb::SubscribeEventsResponse {
+            timestamp: Utc::now().timestamp_millis(),
+            event: Some(event),
+        }
+    }
+}
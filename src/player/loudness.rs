@@ -0,0 +1,396 @@
+use std::{
+    collections::{HashMap, VecDeque},
+    f64::consts::PI,
+    fmt,
+    str::FromStr,
+    sync::RwLock,
+};
+
+use once_cell::sync::{Lazy, OnceCell};
+use tokio::sync::mpsc;
+
+use crate::{errors::ApplicationError, library::repository::MusicRepository, player::state::Music};
+
+// 默认的目标响度，与主流流媒体平台一致
+pub const DEFAULT_TARGET_LUFS: f64 = -14.0;
+// 最大提升的增益，避免把很安静的歌曲放大太多
+pub const MAX_GAIN_DB: f64 = 12.0;
+// 低于这个响度的测量块不计入 (绝对门限)
+const ABSOLUTE_GATE_LUFS: f64 = -70.0;
+// 低于平均响度这么多的测量块不计入 (相对门限)
+const RELATIVE_GATE_LU: f64 = 10.0;
+// 每个测量块 400ms，由 4 个 100ms 的子块组成，相邻块重叠 75%
+const SUB_BLOCKS_PER_BLOCK: usize = 4;
+
+// 当前的响度均衡设置，启动时从环境变量 LOUDNESS_NORMALIZATION 和 LOUDNESS_TARGET 读取
+pub static NORMALIZATION: Lazy<RwLock<Normalization>> =
+    Lazy::new(|| RwLock::new(Normalization::default()));
+// 已测量的歌曲响度，按 (bvid, cid) 缓存，启动时从音乐库加载
+static MEASURED: Lazy<RwLock<HashMap<(String, String), Loudness>>> =
+    Lazy::new(|| RwLock::new(HashMap::new()));
+// 把测量结果写入音乐库的通道
+static LOUDNESS_WRITER: OnceCell<mpsc::UnboundedSender<(Music, Loudness)>> = OnceCell::new();
+
+/// 响度均衡方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NormalizationMode {
+    #[default]
+    Off, // 不调整
+    Track, // 每首歌曲调整到目标响度
+    Album, // 整个歌单使用同一个增益，保留歌曲之间的响度差别
+}
+
+impl FromStr for NormalizationMode {
+    type Err = ApplicationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "off" | "none" => Ok(NormalizationMode::Off),
+            "track" => Ok(NormalizationMode::Track),
+            "album" | "playlist" => Ok(NormalizationMode::Album),
+            other => Err(ApplicationError::DataParsingError(format!(
+                "无效的响度均衡方式: {other}，可选 off、track 或 album"
+            ))),
+        }
+    }
+}
+
+impl fmt::Display for NormalizationMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            NormalizationMode::Off => write!(f, "off"),
+            NormalizationMode::Track => write!(f, "track"),
+            NormalizationMode::Album => write!(f, "album"),
+        }
+    }
+}
+
+/// 响度均衡设置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Normalization {
+    pub mode: NormalizationMode,
+    pub target_lufs: f64, // 目标响度 (LUFS)
+}
+
+impl Default for Normalization {
+    fn default() -> Self {
+        Self {
+            mode: NormalizationMode::Off,
+            target_lufs: DEFAULT_TARGET_LUFS,
+        }
+    }
+}
+
+impl Normalization {
+    /// 是否开启了响度均衡，开启后才测量歌曲响度
+    pub fn is_enabled(&self) -> bool {
+        self.mode != NormalizationMode::Off
+    }
+
+    /// 计算歌曲的增益 (线性倍数)
+    ///
+    /// # 参数
+    /// - track: 这首歌曲的响度，没有测量过时为 None
+    /// - album: 歌单的整体响度，album 模式下使用，没有时退回到歌曲的响度
+    pub fn gain(&self, track: Option<Loudness>, album: Option<f64>) -> f64 {
+        let track = track.map(|loudness| loudness.integrated);
+        let loudness = match self.mode {
+            NormalizationMode::Off => None,
+            NormalizationMode::Track => track,
+            NormalizationMode::Album => album.or(track),
+        };
+        match loudness {
+            Some(loudness) => db_to_gain((self.target_lufs - loudness).min(MAX_GAIN_DB)),
+            None => 1.0,
+        }
+    }
+}
+
+/// 从环境变量中读取响度均衡设置，未设置时不调整
+pub fn normalization() -> Result<Normalization, ApplicationError> {
+    dotenvy::dotenv().ok();
+    let mode = match std::env::var("LOUDNESS_NORMALIZATION") {
+        Ok(value) => value.parse()?,
+        Err(_) => NormalizationMode::default(),
+    };
+    let target_lufs = match std::env::var("LOUDNESS_TARGET") {
+        Ok(value) => value
+            .trim()
+            .parse::<f64>()
+            .ok()
+            .filter(|target| target.is_finite() && *target < 0.0)
+            .ok_or_else(|| {
+                ApplicationError::DataParsingError(format!(
+                    "无效的目标响度: {value}，应为负数，如 -14"
+                ))
+            })?,
+        Err(_) => DEFAULT_TARGET_LUFS,
+    };
+    Ok(Normalization { mode, target_lufs })
+}
+
+/// 获取当前的响度均衡设置
+pub fn current_normalization() -> Normalization {
+    NORMALIZATION
+        .read()
+        .map(|normalization| *normalization)
+        .unwrap_or_default()
+}
+
+/// 设置响度均衡，之后播放的歌曲生效
+pub fn set_normalization(normalization: Normalization) {
+    if let Ok(mut current) = NORMALIZATION.write() {
+        *current = normalization;
+    }
+}
+
+/// 一首歌曲的响度测量结果
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Loudness {
+    pub integrated: f64, // 整体响度 (LUFS)
+    pub peak: f64,       // 采样峰值 (线性，1.0 为满刻度)
+}
+
+/// 读取音乐库中已测量的响度，并启动写入测量结果的任务
+pub async fn load_measured_loudness(repository: &MusicRepository) -> Result<(), ApplicationError> {
+    let records = repository.measured_loudness().await?;
+    if let Ok(mut measured) = MEASURED.write() {
+        for record in records {
+            if let Some(loudness) = record.loudness() {
+                measured.insert((record.bvid, record.cid), loudness);
+            }
+        }
+    }
+    let (sender, mut receiver) = mpsc::unbounded_channel::<(Music, Loudness)>();
+    if LOUDNESS_WRITER.set(sender).is_ok() {
+        let repository = repository.clone();
+        tokio::task::spawn(async move {
+            while let Some((music, loudness)) = receiver.recv().await {
+                if let Err(e) = repository.save_loudness(&music, &loudness).await {
+                    tracing::error!("Failed to save loudness of {:?}: {}", music, e);
+                }
+            }
+        });
+    }
+    Ok(())
+}
+
+/// 获取歌曲已测量的响度
+pub fn measured_loudness(music: &Music) -> Option<Loudness> {
+    MEASURED
+        .read()
+        .ok()?
+        .get(&(music.bvid.clone(), music.cid.clone()))
+        .copied()
+}
+
+/// 记录歌曲的测量结果，并写入音乐库
+pub fn record_loudness(music: &Music, loudness: Loudness) {
+    tracing::info!(
+        "Measured loudness of {:?}: {:.1} LUFS, peak {:.3}",
+        music,
+        loudness.integrated,
+        loudness.peak
+    );
+    if let Ok(mut measured) = MEASURED.write() {
+        measured.insert((music.bvid.clone(), music.cid.clone()), loudness);
+    }
+    if let Some(writer) = LOUDNESS_WRITER.get() {
+        let _ = writer.send((music.clone(), loudness));
+    }
+}
+
+/// 歌单的整体响度，按能量平均已测量的歌曲
+pub fn playlist_loudness(musics: &[Music]) -> Option<f64> {
+    let measured: Vec<f64> = musics
+        .iter()
+        .filter_map(measured_loudness)
+        .map(|loudness| loudness.integrated)
+        .collect();
+    average_loudness(&measured)
+}
+
+/// 按能量平均多个响度 (LUFS)
+pub fn average_loudness(values: &[f64]) -> Option<f64> {
+    if values.is_empty() {
+        return None;
+    }
+    let energy = values
+        .iter()
+        .map(|loudness| 10f64.powf(loudness / 10.0))
+        .sum::<f64>()
+        / values.len() as f64;
+    Some(10.0 * energy.log10())
+}
+
+// 分贝换算为线性倍数
+fn db_to_gain(db: f64) -> f64 {
+    10f64.powf(db / 20.0)
+}
+
+// 二阶 IIR 滤波器
+#[derive(Debug, Clone)]
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 3],
+    z1: f64,
+    z2: f64,
+}
+
+impl Biquad {
+    fn new(b: [f64; 3], a: [f64; 3]) -> Self {
+        Self {
+            b,
+            a,
+            z1: 0.0,
+            z2: 0.0,
+        }
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z1;
+        self.z1 = self.b[1] * x - self.a[1] * y + self.z2;
+        self.z2 = self.b[2] * x - self.a[2] * y;
+        y
+    }
+}
+
+// K 计权滤波器：高频搁架滤波 + 高通滤波，系数按采样率计算 (ITU-R BS.1770)
+#[derive(Debug, Clone)]
+struct KWeighting {
+    shelf: Biquad,
+    high_pass: Biquad,
+}
+
+impl KWeighting {
+    fn new(rate: f64) -> Self {
+        let f0 = 1681.974450955533;
+        let gain = 3.999843853973347;
+        let q = 0.7071752369554196;
+        let k = (PI * f0 / rate).tan();
+        let vh = 10f64.powf(gain / 20.0);
+        let vb = vh.powf(0.4996667741545416);
+        let a0 = 1.0 + k / q + k * k;
+        let shelf = Biquad::new(
+            [
+                (vh + vb * k / q + k * k) / a0,
+                2.0 * (k * k - vh) / a0,
+                (vh - vb * k / q + k * k) / a0,
+            ],
+            [1.0, 2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        );
+
+        let f0 = 38.13547087602444;
+        let q = 0.5003270373238773;
+        let k = (PI * f0 / rate).tan();
+        let a0 = 1.0 + k / q + k * k;
+        let high_pass = Biquad::new(
+            [1.0, -2.0, 1.0],
+            [1.0, 2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        );
+        Self { shelf, high_pass }
+    }
+
+    fn process(&mut self, x: f64) -> f64 {
+        self.high_pass.process(self.shelf.process(x))
+    }
+}
+
+/// EBU R128 整体响度测量
+///
+/// 输入交错排列的采样，按 400ms 的块计算能量，经过绝对门限和相对门限后求平均
+#[derive(Debug, Clone)]
+pub struct LoudnessMeter {
+    filters: Vec<KWeighting>,
+    weights: Vec<f64>,
+    sub_block_frames: usize,
+    frames: usize,
+    energy: f64,
+    sub_blocks: VecDeque<f64>,
+    blocks: Vec<f64>,
+    peak: f64,
+}
+
+impl LoudnessMeter {
+    pub fn new(rate: u32, channels: usize) -> Self {
+        // 5.1 声道时 LFE 不计入，环绕声道权重为 1.41
+        let weights = (0..channels)
+            .map(|channel| match (channels, channel) {
+                (6, 3) => 0.0,
+                (6, 4 | 5) => 1.41,
+                _ => 1.0,
+            })
+            .collect();
+        Self {
+            filters: vec![KWeighting::new(rate as f64); channels],
+            weights,
+            sub_block_frames: (rate as usize / 10).max(1),
+            frames: 0,
+            energy: 0.0,
+            sub_blocks: VecDeque::with_capacity(SUB_BLOCKS_PER_BLOCK),
+            blocks: Vec::new(),
+            peak: 0.0,
+        }
+    }
+
+    /// 输入交错排列的采样 (-1.0 到 1.0)
+    pub fn push(&mut self, samples: &[f32]) {
+        let channels = self.filters.len();
+        if channels == 0 {
+            return;
+        }
+        for frame in samples.chunks_exact(channels) {
+            for (channel, sample) in frame.iter().enumerate() {
+                let sample = *sample as f64;
+                self.peak = self.peak.max(sample.abs());
+                let weighted = self.filters[channel].process(sample);
+                self.energy += self.weights[channel] * weighted * weighted;
+            }
+            self.frames += 1;
+            if self.frames == self.sub_block_frames {
+                self.finish_sub_block();
+            }
+        }
+    }
+
+    /// 计算整体响度，有效的测量块不足时返回 None
+    pub fn finish(&self) -> Option<Loudness> {
+        let loudness = |energy: f64| -0.691 + 10.0 * energy.log10();
+        let gated: Vec<f64> = self
+            .blocks
+            .iter()
+            .copied()
+            .filter(|energy| *energy > 0.0 && loudness(*energy) > ABSOLUTE_GATE_LUFS)
+            .collect();
+        if gated.is_empty() {
+            return None;
+        }
+        let threshold = loudness(gated.iter().sum::<f64>() / gated.len() as f64) - RELATIVE_GATE_LU;
+        let gated: Vec<f64> = gated
+            .into_iter()
+            .filter(|energy| loudness(*energy) > threshold)
+            .collect();
+        if gated.is_empty() {
+            return None;
+        }
+        Some(Loudness {
+            integrated: loudness(gated.iter().sum::<f64>() / gated.len() as f64),
+            peak: self.peak,
+        })
+    }
+
+    // 一个 100ms 子块结束，凑满 4 个子块时得到一个测量块
+    fn finish_sub_block(&mut self) {
+        if self.sub_blocks.len() == SUB_BLOCKS_PER_BLOCK {
+            self.sub_blocks.pop_front();
+        }
+        self.sub_blocks.push_back(self.energy);
+        self.energy = 0.0;
+        self.frames = 0;
+        if self.sub_blocks.len() == SUB_BLOCKS_PER_BLOCK {
+            let block = self.sub_blocks.iter().sum::<f64>()
+                / (SUB_BLOCKS_PER_BLOCK * self.sub_block_frames) as f64;
+            self.blocks.push(block);
+        }
+    }
+}
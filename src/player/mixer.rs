@@ -0,0 +1,522 @@
+use std::{
+    f64::consts::FRAC_PI_2,
+    sync::{Arc, Mutex, MutexGuard},
+    time::Duration,
+};
+
+use gstreamer::{
+    glib::object::ObjectExt,
+    prelude::{ElementExt, ElementExtManual, GstBinExt, GstBinExtManual, PadExt, PadExtManual},
+};
+use once_cell::sync::Lazy;
+
+use crate::{
+    errors::ApplicationError,
+    fetch::quality::AudioStream,
+    player::{
+        equalizer::{EQUALIZER_ELEMENT_NAME, apply_gains, equalizer},
+        loudness::{Loudness, LoudnessMeter, current_normalization},
+        rate::playback_rate,
+        state::Music,
+        volume::{VOLUME_ELEMENT_NAME, Volume},
+    },
+};
+
+// 混合各首歌曲解码分支的 audiomixer 元素名称
+pub const TRACK_MIXER_NAME: &str = "track_mixer";
+// 输出链上防止削波的限幅器元素名称
+pub const LIMITER_ELEMENT_NAME: &str = "limiter";
+// 开启响度均衡时限幅器的阈值 (约 -1 dBFS)，超过的部分按 LIMITER_RATIO 压缩
+const LIMITER_THRESHOLD: f64 = 0.89;
+const LIMITER_RATIO: f64 = 0.1;
+// 正在播放、预加载和淡出的解码分支，只在同步代码中短暂持有
+static TRACK_BRANCHES: Lazy<Mutex<TrackBranches>> =
+    Lazy::new(|| Mutex::new(TrackBranches::default()));
+
+/// 一首歌曲的解码分支，通过 audiomixer 上申请的 pad 接到输出链
+pub struct TrackBranch {
+    elements: Vec<gstreamer::Element>,
+    // 分支末端的元素，查询时长和是否支持跳转时只问这首歌曲
+    tail: gstreamer::Element,
+    mixer_pad: gstreamer::Pad,
+    // 响度均衡的增益 (线性倍数)，淡入淡出的音量在此基础上调整
+    gain: f64,
+    // 音频地址，播放中出错时用来找到对应的分支
+    pub location: String,
+    // 歌曲开头对应的 running time (纳秒)，跳转后可能为负
+    pub origin: i64,
+}
+
+impl TrackBranch {
+    /// 设置分支在 audiomixer 中的音量 (0.0-1.0)，用于淡入淡出
+    pub fn set_level(&self, level: f64) {
+        self.mixer_pad.set_property("volume", level * self.gain);
+    }
+
+    /// 测量这首歌曲的响度，完整播放到结尾时调用 on_measured
+    ///
+    /// 在 audioresample 之前测量，不受增益和淡入淡出影响。中途跳转时放弃这次测量
+    pub fn measure_loudness<F>(&self, on_measured: F)
+    where
+        F: Fn(Loudness) + Send + Sync + 'static,
+    {
+        let Some(pad) = self.tail.static_pad("sink") else {
+            tracing::warn!("Failed to get audioresample sink pad for loudness");
+            return;
+        };
+        // 为 None 时还没有收到 caps，或者测量已放弃
+        let meter: Arc<Mutex<Option<LoudnessMeter>>> = Arc::new(Mutex::new(None));
+        pad.add_probe(
+            gstreamer::PadProbeType::BUFFER | gstreamer::PadProbeType::EVENT_DOWNSTREAM,
+            move |_pad, info| {
+                let Ok(mut meter) = meter.lock() else {
+                    return gstreamer::PadProbeReturn::Remove;
+                };
+                if let Some(buffer) = info.buffer() {
+                    if let (Some(meter), Ok(map)) = (meter.as_mut(), buffer.map_readable()) {
+                        let samples: Vec<f32> = map
+                            .chunks_exact(4)
+                            .map(|bytes| {
+                                f32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+                            })
+                            .collect();
+                        meter.push(&samples);
+                    }
+                    return gstreamer::PadProbeReturn::Ok;
+                }
+                let Some(event) = info.event() else {
+                    return gstreamer::PadProbeReturn::Ok;
+                };
+                match event.view() {
+                    gstreamer::EventView::Caps(caps) => {
+                        if meter.is_none() {
+                            *meter = caps.caps().structure(0).and_then(|s| {
+                                let rate = s.get::<i32>("rate").ok()?;
+                                let channels = s.get::<i32>("channels").ok()?;
+                                Some(LoudnessMeter::new(rate as u32, channels as usize))
+                            });
+                        }
+                        gstreamer::PadProbeReturn::Ok
+                    }
+                    gstreamer::EventView::FlushStart(_) => gstreamer::PadProbeReturn::Remove,
+                    gstreamer::EventView::Eos(_) => {
+                        if let Some(loudness) = meter.take().and_then(|meter| meter.finish()) {
+                            on_measured(loudness);
+                        }
+                        gstreamer::PadProbeReturn::Remove
+                    }
+                    _ => gstreamer::PadProbeReturn::Ok,
+                }
+            },
+        );
+    }
+
+    /// 查询歌曲时长 (纳秒)
+    pub fn duration(&self) -> Option<i64> {
+        self.tail
+            .query_duration::<gstreamer::ClockTime>()
+            .map(|t| t.nseconds() as i64)
+    }
+
+    /// 这首歌曲是否支持跳转，audiomixer 本身总是回答不支持
+    pub fn is_seekable(&self) -> bool {
+        let mut query = gstreamer::query::Seeking::new(gstreamer::Format::Time);
+        self.tail.query(&mut query) && query.result().0
+    }
+
+    // 停止并移除分支的元素，释放 audiomixer 上的 pad
+    fn remove(self, pipeline: &gstreamer::Pipeline) {
+        for element in &self.elements {
+            let _ = element.set_state(gstreamer::State::Null);
+        }
+        if pipeline.remove_many(&self.elements).is_err() {
+            tracing::warn!("Failed to remove track branch from pipeline");
+        }
+        if let Some(mixer) = pipeline.by_name(TRACK_MIXER_NAME) {
+            mixer.release_request_pad(&self.mixer_pad);
+        }
+    }
+}
+
+/// 预加载的下一首歌曲
+pub struct PreloadedTrack {
+    pub music: Music,
+    pub stream: AudioStream,
+    pub branch: TrackBranch,
+    // 预加载时的淡入淡出时长，开始时间按它计算
+    pub fade: Duration,
+}
+
+/// 正在淡出的上一首歌曲
+struct Fade {
+    branch: TrackBranch,
+    start: i64,
+    duration: Duration,
+}
+
+#[derive(Default)]
+pub struct TrackBranches {
+    pub active: Option<TrackBranch>,
+    pub preloaded: Option<PreloadedTrack>,
+    fading: Option<Fade>,
+    // 正在请求下一首的地址，避免重复预加载
+    pub preloading: bool,
+    // pipeline 每次重建时加一，预加载完成时已经重建则放弃
+    pub generation: u64,
+    // 最近一次跳转的目标位置 (纳秒)，audiomixer 输出的位置从这里开始
+    segment_start: u64,
+}
+
+impl TrackBranches {
+    /// 清空所有分支，pipeline 重建前调用
+    pub fn reset(&mut self) {
+        self.active = None;
+        self.preloaded = None;
+        self.fading = None;
+        self.segment_start = 0;
+        self.generation += 1;
+    }
+
+    /// 当前的 running time (纳秒)，由 audiomixer 输出的位置换算
+    pub fn running_time(&self, pipeline: &gstreamer::Pipeline) -> Option<i64> {
+        let position = pipeline.query_position::<gstreamer::ClockTime>()?;
+        Some(position.nseconds() as i64 - self.segment_start as i64)
+    }
+
+    /// 当前歌曲的播放位置 (纳秒)
+    pub fn track_position(&self, pipeline: &gstreamer::Pipeline) -> Option<i64> {
+        let active = self.active.as_ref()?;
+        Some((self.running_time(pipeline)? - active.origin).max(0))
+    }
+
+    /// 当前歌曲剩余的时间 (纳秒)，时长未知时返回 None
+    pub fn remaining(&self, pipeline: &gstreamer::Pipeline) -> Option<i64> {
+        let active = self.active.as_ref()?;
+        Some(active.duration()? - self.track_position(pipeline)?)
+    }
+
+    /// 是否可以开始预加载：有正在播放的歌曲，且没有预加载或淡出中的分支
+    pub fn can_preload(&self) -> bool {
+        !self.preloading
+            && self.active.is_some()
+            && self.preloaded.is_none()
+            && self.fading.is_none()
+    }
+
+    /// 换上新的分支，上一首在 fade 时间内淡出，fade 为 0 时直接移除
+    pub fn switch_to(
+        &mut self,
+        pipeline: &gstreamer::Pipeline,
+        branch: TrackBranch,
+        fade: Duration,
+    ) {
+        self.finish_fade(pipeline);
+        let start = branch.origin;
+        if let Some(previous) = self.active.replace(branch) {
+            if fade.is_zero() {
+                previous.remove(pipeline);
+            } else {
+                self.fading = Some(Fade {
+                    branch: previous,
+                    start,
+                    duration: fade,
+                });
+            }
+        }
+    }
+
+    /// 预加载的歌曲到了开始时间时换上它，返回预加载的歌曲信息
+    pub fn promote_preloaded(
+        &mut self,
+        pipeline: &gstreamer::Pipeline,
+        running_time: i64,
+    ) -> Option<(Music, AudioStream)> {
+        if self.preloaded.as_ref()?.branch.origin > running_time {
+            return None;
+        }
+        let track = self.preloaded.take()?;
+        self.switch_to(pipeline, track.branch, track.fade);
+        Some((track.music, track.stream))
+    }
+
+    /// 按当前时间更新淡入淡出的音量，淡出结束后移除上一首的分支
+    ///
+    /// 使用等功率曲线，交叉时总音量保持不变
+    pub fn update_fade(&mut self, pipeline: &gstreamer::Pipeline, running_time: i64) {
+        let Some(fade) = &self.fading else {
+            return;
+        };
+        let progress =
+            ((running_time - fade.start) as f64 / fade.duration.as_nanos() as f64).clamp(0.0, 1.0);
+        if progress >= 1.0 {
+            self.finish_fade(pipeline);
+            return;
+        }
+        fade.branch.set_level((progress * FRAC_PI_2).cos());
+        if let Some(active) = &self.active {
+            active.set_level((progress * FRAC_PI_2).sin());
+        }
+    }
+
+    /// 结束淡入淡出：移除淡出的分支，当前歌曲恢复原音量
+    pub fn finish_fade(&mut self, pipeline: &gstreamer::Pipeline) {
+        if let Some(fade) = self.fading.take() {
+            fade.branch.remove(pipeline);
+        }
+        if let Some(active) = &self.active {
+            active.set_level(1.0);
+        }
+    }
+
+    /// 丢弃预加载的歌曲
+    pub fn discard_preloaded(&mut self, pipeline: &gstreamer::Pipeline) {
+        if let Some(track) = self.preloaded.take() {
+            track.branch.remove(pipeline);
+        }
+    }
+
+    /// 移除地址出错的预加载或淡出分支，返回是否找到了
+    ///
+    /// 出错的分支不会再有数据，留在 audiomixer 上会让其他分支也停下来
+    pub fn discard_failed(&mut self, pipeline: &gstreamer::Pipeline, location: &str) -> bool {
+        if self
+            .preloaded
+            .as_ref()
+            .is_some_and(|track| track.branch.location == location)
+        {
+            self.discard_preloaded(pipeline);
+            return true;
+        }
+        if self
+            .fading
+            .as_ref()
+            .is_some_and(|fade| fade.branch.location == location)
+        {
+            self.finish_fade(pipeline);
+            return true;
+        }
+        false
+    }
+
+    /// 跳转前只保留当前歌曲，audiomixer 会把跳转发给所有分支
+    pub fn prepare_seek(&mut self, pipeline: &gstreamer::Pipeline) {
+        self.discard_preloaded(pipeline);
+        self.finish_fade(pipeline);
+        if let Some(active) = &self.active {
+            active.mixer_pad.set_offset(0);
+        }
+    }
+
+    /// 跳转后 running time 从 0 重新开始，当前歌曲的开头相应前移
+    pub fn seeked(&mut self, target: u64) {
+        self.segment_start = target;
+        if let Some(active) = &mut self.active {
+            active.origin = -(target as i64);
+        }
+    }
+}
+
+/// 获取解码分支的状态，锁中毒时继续使用里面的数据
+pub fn track_branches() -> MutexGuard<'static, TrackBranches> {
+    TRACK_BRANCHES
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+// 创建元素，失败时返回 ElementError
+fn make_element(factory: &str) -> Result<gstreamer::Element, ApplicationError> {
+    gstreamer::ElementFactory::make(factory)
+        .build()
+        .map_err(|_| ApplicationError::ElementError(format!("Failed to create {factory} element")))
+}
+
+/// 建立所有歌曲共用的输出链：audiomixer → audioconvert → equalizer-10bands → audiodynamic → volume → audioresample → autoaudiosink
+///
+/// audiodynamic 作为限幅器，开启响度均衡时防止增益和均衡器提升后削波，关闭时阈值为满刻度。
+/// 变速时保持音调则在 audioconvert 之后加入 scaletempo
+pub fn build_output_chain(
+    pipeline: &gstreamer::Pipeline,
+    volume: &Volume,
+) -> Result<(), ApplicationError> {
+    let mixer = gstreamer::ElementFactory::make("audiomixer")
+        .name(TRACK_MIXER_NAME)
+        .build()
+        .map_err(|_| {
+            ApplicationError::ElementError("Failed to create audiomixer element".to_string())
+        })?;
+    let audioconvert = make_element("audioconvert")?;
+    let equalizer_element = gstreamer::ElementFactory::make("equalizer-10bands")
+        .name(EQUALIZER_ELEMENT_NAME)
+        .build()
+        .map_err(|_| {
+            ApplicationError::ElementError("Failed to create equalizer element".to_string())
+        })?;
+    apply_gains(&equalizer_element, &equalizer().settings().gains);
+    let threshold = if current_normalization().is_enabled() {
+        LIMITER_THRESHOLD
+    } else {
+        1.0
+    };
+    let limiter = gstreamer::ElementFactory::make("audiodynamic")
+        .name(LIMITER_ELEMENT_NAME)
+        .property_from_str("characteristics", "soft-knee")
+        .property_from_str("mode", "compressor")
+        .property("threshold", threshold as f32)
+        .property("ratio", LIMITER_RATIO as f32)
+        .build()
+        .map_err(|_| {
+            ApplicationError::ElementError("Failed to create audiodynamic element".to_string())
+        })?;
+    let volume = gstreamer::ElementFactory::make("volume")
+        .property("volume", volume.gstreamer_volume())
+        .property("mute", volume.is_muted())
+        .name(VOLUME_ELEMENT_NAME) // 设置名称方便查找
+        .build()
+        .map_err(|e| {
+            tracing::error!("Failed to create volume: {:?}", e);
+            ApplicationError::ElementError("Failed to set volume element".to_string())
+        })?;
+    let audioresample = make_element("audioresample")?;
+    let autoaudiosink = make_element("autoaudiosink")?;
+    let scaletempo = if playback_rate().settings().preserve_pitch {
+        Some(make_element("scaletempo")?)
+    } else {
+        None
+    };
+    let mut chain = vec![&mixer, &audioconvert];
+    chain.extend(scaletempo.as_ref());
+    chain.extend([
+        &equalizer_element,
+        &limiter,
+        &volume,
+        &audioresample,
+        &autoaudiosink,
+    ]);
+    pipeline.add_many(&chain).map_err(|_| {
+        ApplicationError::PipelineError("Failed to add elements to pipeline".to_string())
+    })?;
+    gstreamer::Element::link_many(&chain)
+        .map_err(|_| ApplicationError::LinkError("Failed to link output chain".to_string()))?;
+    Ok(())
+}
+
+/// 添加一首歌曲的解码分支：souphttpsrc → decodebin → audioconvert → capsfilter → audioresample → audiomixer
+///
+/// origin 为歌曲开头对应的 running time，设置为 audiomixer pad 的偏移。
+/// gain 为响度均衡的增益，capsfilter 统一为 32 位浮点采样，方便测量响度。
+/// decodebin 的音频 pad 出现后才接到 audioconvert 上
+pub fn add_track_branch(
+    pipeline: &gstreamer::Pipeline,
+    url: &str,
+    origin: i64,
+    gain: f64,
+) -> Result<TrackBranch, ApplicationError> {
+    let mixer = pipeline.by_name(TRACK_MIXER_NAME).ok_or_else(|| {
+        ApplicationError::PipelineError("Failed to find audiomixer element".to_string())
+    })?;
+    let source = make_element("souphttpsrc")?;
+    source.set_property("location", url);
+
+    let mut headers = gstreamer::Structure::new_empty("headers");
+    headers.set(
+        "User-Agent",
+        "Mozilla/5.0 BiliDroid/..* (bbcallen@gmail.com)",
+    );
+    headers.set("Referer", "https://www.bilibili.com");
+    source.set_property("extra-headers", &headers);
+
+    let decodebin = make_element("decodebin")?;
+    let audioconvert = make_element("audioconvert")?;
+    let capsfilter = make_element("capsfilter")?;
+    capsfilter.set_property(
+        "caps",
+        gstreamer::Caps::builder("audio/x-raw")
+            .field("format", "F32LE")
+            .build(),
+    );
+    let audioresample = make_element("audioresample")?;
+    pipeline
+        .add_many([
+            &source,
+            &decodebin,
+            &audioconvert,
+            &capsfilter,
+            &audioresample,
+        ])
+        .map_err(|_| {
+            ApplicationError::PipelineError("Failed to add elements to pipeline".to_string())
+        })?;
+    source.link(&decodebin).map_err(|_| {
+        ApplicationError::LinkError("Failed to link source to decodebin".to_string())
+    })?;
+    gstreamer::Element::link_many([&audioconvert, &capsfilter, &audioresample]).map_err(|_| {
+        ApplicationError::LinkError("Failed to link audioconvert to audioresample".to_string())
+    })?;
+    let mixer_pad = mixer.request_pad_simple("sink_%u").ok_or_else(|| {
+        ApplicationError::LinkError("Failed to request audiomixer sink pad".to_string())
+    })?;
+    mixer_pad.set_offset(origin);
+    mixer_pad.set_property("volume", gain);
+    audioresample
+        .static_pad("src")
+        .ok_or_else(|| {
+            ApplicationError::LinkError("Failed to get audioresample src pad".to_string())
+        })?
+        .link(&mixer_pad)
+        .map_err(|_| {
+            ApplicationError::LinkError("Failed to link audioresample to audiomixer".to_string())
+        })?;
+
+    // 使用一个Weak引用跟踪audioconvert元素
+    let audioconvert_weak = audioconvert.downgrade();
+    decodebin.connect_pad_added(move |_decodebin, src_pad| {
+        let Some(audioconvert) = audioconvert_weak.upgrade() else {
+            tracing::error!("Failed to upgrade weak references");
+            return;
+        };
+        // 检查pad是否为音频
+        let is_audio = src_pad
+            .current_caps()
+            .and_then(|caps| caps.structure(0).map(|s| s.name().starts_with("audio/")))
+            .unwrap_or(false);
+        if !is_audio {
+            tracing::info!("Non-audio pad, ignoring");
+            return;
+        }
+        let Some(audio_pad) = audioconvert.static_pad("sink") else {
+            tracing::error!("Failed to get audioconvert sink pad");
+            return;
+        };
+        if audio_pad.is_linked() {
+            return;
+        }
+        if src_pad.link(&audio_pad).is_err() {
+            tracing::error!("Failed to link decodebin to audioconvert");
+        }
+    });
+
+    Ok(TrackBranch {
+        elements: vec![
+            source,
+            decodebin,
+            audioconvert,
+            capsfilter,
+            audioresample.clone(),
+        ],
+        tail: audioresample,
+        mixer_pad,
+        gain,
+        location: url.to_string(),
+        origin,
+    })
+}
+
+/// 把运行中添加的分支切换到 pipeline 的状态
+pub fn sync_branch_state(branch: &TrackBranch) -> Result<(), ApplicationError> {
+    for element in &branch.elements {
+        element.sync_state_with_parent().map_err(|_| {
+            ApplicationError::StateError("Failed to sync track branch state".to_string())
+        })?;
+    }
+    Ok(())
+}
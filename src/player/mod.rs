@@ -1,4 +1,12 @@
 pub mod audio_player;
 pub mod command;
+pub mod crossfade;
+pub mod equalizer;
+pub mod event;
+pub mod loudness;
+pub mod mixer;
 pub mod play_list;
+pub mod rate;
+pub mod session;
 pub mod state;
+pub mod volume;
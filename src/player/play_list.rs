@@ -1,5 +1,6 @@
 use crate::{
     errors::ApplicationError,
+    library::playlist_repository::PlaylistRepository,
     player::{command::PlayMode, state::Music},
 };
 use once_cell::sync::Lazy;
@@ -12,6 +13,52 @@ pub static PLAYLIST: Lazy<Mutex<Result<Playlist, ApplicationError>>> =
 // 当前播放的音乐索引
 pub static CURRENT_MUSIC_INDEX: Lazy<Mutex<usize>> = Lazy::new(|| Mutex::new(0));
 
+// 默认每页显示的歌曲数量
+pub const DEFAULT_PAGE_SIZE: usize = 10;
+// 每页最多显示的歌曲数量
+pub const MAX_PAGE_SIZE: usize = 100;
+
+/// 播放列表中的一项，用于分页展示
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistEntry {
+    pub index: usize,
+    pub music: Music,
+    pub is_current: bool,
+}
+
+/// 播放列表的一页
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaylistPage {
+    pub entries: Vec<PlaylistEntry>,
+    pub page: usize,
+    pub page_size: usize,
+    pub total: usize,
+    pub total_pages: usize,
+}
+
+impl From<PlaylistPage> for crate::pb::ShowPlayListResponse {
+    fn from(page: PlaylistPage) -> Self {
+        crate::pb::ShowPlayListResponse {
+            success: true,
+            total: page.total as i32,
+            current: page.page as i32,
+            entries: page
+                .entries
+                .into_iter()
+                .map(|entry| crate::pb::PlaylistEntry {
+                    index: entry.index as u32,
+                    music: Some(entry.music.into()),
+                    is_current: entry.is_current,
+                })
+                .collect(),
+            page_size: page.page_size as i32,
+            total_pages: page.total_pages as i32,
+            has_previous: page.page > 1,
+            has_next: page.page < page.total_pages,
+        }
+    }
+}
+
 /// 播放列表
 #[derive(serde::Deserialize, Clone, Debug)]
 pub struct Playlist {
@@ -19,34 +66,17 @@ pub struct Playlist {
 }
 
 impl Playlist {
-    pub async fn add_musics() -> Result<Self, ApplicationError> {
-        let musics = vec![
-            Music {
-                bvid: "BV1oZqqBZEGZ".into(),
-                title: "西楼别序".into(),
-                cid: "34856567673".into(),
-                owner: "夕照影音".into(),
-            },
-            Music {
-                bvid: "BV1Xa411R7uJ".into(),
-                title: "长大成人".into(),
-                cid: "806047205".into(),
-                owner: "忆江南音乐".into(),
-            },
-            Music {
-                bvid: "BV1w34y1K7dv".into(),
-                title: "一花一世界".into(),
-                cid: "1287078024".into(),
-                owner: "荃球音悦台".into(),
-            },
-            Music {
-                bvid: "BV1r7411p7R4".into(),
-                title: "青花瓷".into(),
-                cid: "321818216".into(),
-                owner: "zyl2012_音乐无限".into(),
-            },
-        ];
-        // 加载音乐列表
+    /// 加载当前激活的歌单，没有激活的歌单时返回空列表
+    pub async fn load_active(playlists: &PlaylistRepository) -> Result<Self, ApplicationError> {
+        let Some(active) = playlists.active().await? else {
+            return Ok(Playlist { musics: Vec::new() });
+        };
+        let musics = playlists
+            .musics(active.id)
+            .await?
+            .into_iter()
+            .map(Music::from)
+            .collect();
         Ok(Playlist { musics })
     }
     /// 获取当前播放的音乐
@@ -58,31 +88,40 @@ impl Playlist {
                 ApplicationError::DataParsingError("Music index out of bounds".to_string())
             })
     }
-    /// 移动到下一首音乐
-    pub async fn move_to_next_music(
-        &mut self,
+    /// 按播放模式计算下一首的索引，不改变当前索引
+    pub fn next_index(
+        &self,
+        current_index: usize,
         play_mode: PlayMode,
     ) -> Result<usize, ApplicationError> {
-        // 获取当前播放的音乐索引
-        let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
+        if self.musics.is_empty() {
+            return Err(ApplicationError::NotFoundError(
+                "Playlist is empty".to_string(),
+            ));
+        }
         // 根据播放模式来确定下一首
         match play_mode {
             // 顺序播放
-            PlayMode::Normal => {
-                *current_index = (*current_index + 1) % self.musics.len();
-            }
+            PlayMode::Normal => Ok((current_index + 1) % self.musics.len()),
             // 随机播放
             PlayMode::Shuffle => {
                 let mut rng = rand::rng();
-                *current_index = (0..self.musics.len()).choose(&mut rng).ok_or_else(|| {
+                (0..self.musics.len()).choose(&mut rng).ok_or_else(|| {
                     ApplicationError::DataParsingError("Failed to choose random music".to_string())
-                })?;
+                })
             }
             // 单曲循环
-            PlayMode::Repeat => {
-                // Do nothing, keep the current index
-            }
+            PlayMode::Repeat => Ok(current_index),
         }
+    }
+    /// 移动到下一首音乐
+    pub async fn move_to_next_music(
+        &mut self,
+        play_mode: PlayMode,
+    ) -> Result<usize, ApplicationError> {
+        // 获取当前播放的音乐索引
+        let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
+        *current_index = self.next_index(*current_index, play_mode)?;
         Ok(*current_index)
     }
 
@@ -91,6 +130,11 @@ impl Playlist {
         &mut self,
         play_mode: PlayMode,
     ) -> Result<usize, ApplicationError> {
+        if self.musics.is_empty() {
+            return Err(ApplicationError::NotFoundError(
+                "Playlist is empty".to_string(),
+            ));
+        }
         // 获取当前播放的音乐索引
         let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
         // 根据播放模式来确定下一首
@@ -114,20 +158,106 @@ impl Playlist {
         }
         Ok(*current_index)
     }
-    /// 获取当前播放的音乐索引
-    pub async fn find_music_index(&self, bvid: &str) -> Option<usize> {
-        self.musics.iter().position(|music| music.bvid == bvid)
+    /// 追加音乐到播放列表末尾，不影响当前播放的索引
+    pub fn add_music(&mut self, music: Music) {
+        self.musics.push(music);
+    }
+    /// 从播放列表中移除音乐，并修正当前播放的索引
+    ///
+    /// 返回被移除的音乐，以及它是否为当前播放的音乐
+    pub async fn remove_music(&mut self, bvid: &str, cid: &str) -> Option<(Music, bool)> {
+        let index = self.find_music_index(bvid, Some(cid)).await?;
+        let music = self.musics.remove(index);
+        let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
+        let is_current = index == *current_index;
+        if index < *current_index {
+            // 删除的是前面的歌曲，索引前移保持指向同一首
+            *current_index -= 1;
+        } else if *current_index >= self.musics.len() {
+            // 删除的是最后一首且正在播放，回到列表开头
+            *current_index = 0;
+        }
+        Some((music, is_current))
+    }
+    /// 获取播放列表的一页
+    ///
+    /// # 参数
+    /// - page: 页码从 1 开始，为 0 时返回当前播放歌曲所在的页，超出范围时返回最后一页
+    /// - page_size: 每页数量，为 0 时使用默认值
+    /// - current_index: 当前播放的索引
+    pub fn page(&self, page: usize, page_size: usize, current_index: usize) -> PlaylistPage {
+        let page_size = match page_size {
+            0 => DEFAULT_PAGE_SIZE,
+            size => size.min(MAX_PAGE_SIZE),
+        };
+        let total = self.musics.len();
+        let total_pages = total.div_ceil(page_size).max(1);
+        let page = match page {
+            0 => current_index / page_size + 1,
+            page => page,
+        }
+        .min(total_pages);
+        let entries = self
+            .musics
+            .iter()
+            .enumerate()
+            .skip((page - 1) * page_size)
+            .take(page_size)
+            .map(|(index, music)| PlaylistEntry {
+                index,
+                music: music.clone(),
+                is_current: index == current_index,
+            })
+            .collect();
+        PlaylistPage {
+            entries,
+            page,
+            page_size,
+            total,
+            total_pages,
+        }
+    }
+    /// 查找音乐在播放列表中的索引，cid 为 None 时返回该视频的第一项
+    pub async fn find_music_index(&self, bvid: &str, cid: Option<&str>) -> Option<usize> {
+        self.musics
+            .iter()
+            .position(|music| music.matches(bvid, cid))
     }
 }
-/// 加载播放列表
-pub async fn load_playlist() -> Result<(), ApplicationError> {
-    // 添加音乐
-    let playlist = Playlist::add_musics().await?;
+/// 从当前激活的歌单加载播放列表
+pub async fn load_playlist(playlists: &PlaylistRepository) -> Result<(), ApplicationError> {
+    // 读取歌单
+    let playlist = Playlist::load_active(playlists).await?;
+    tracing::info!(
+        "Loaded {} musics from active playlist",
+        playlist.musics.len()
+    );
     // 加载播放列表
     let mut playlist_lock = PLAYLIST.lock().await;
     *playlist_lock = Ok(playlist); // Replace the old playlist with the new one
     Ok(())
 }
+/// 替换整个播放列表
+///
+/// 当前播放的歌曲也在新列表中时保持播放它，返回 true；否则索引回到开头并返回 false
+pub async fn replace_playlist(playlist: Playlist) -> Result<bool, ApplicationError> {
+    let mut playlist_lock = PLAYLIST.lock().await;
+    let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
+    let current = playlist_lock
+        .as_ref()
+        .ok()
+        .and_then(|old| old.musics.get(*current_index))
+        .map(|music| (music.bvid.clone(), music.cid.clone()));
+    let new_index = current.and_then(|(bvid, cid)| {
+        playlist
+            .musics
+            .iter()
+            .position(|music| music.matches(&bvid, Some(&cid)))
+    });
+    *current_index = new_index.unwrap_or(0);
+    *playlist_lock = Ok(playlist);
+    Ok(new_index.is_some())
+}
 /// 获取当前播放的音乐
 pub async fn get_current_music() -> Result<Music, ApplicationError> {
     let playlist = PLAYLIST.lock().await;
@@ -141,12 +271,60 @@ pub async fn move_to_next_music(play_mode: PlayMode) -> Result<usize, Applicatio
     let playlist = playlist.as_mut().map_err(|e| e.clone())?;
     playlist.move_to_next_music(play_mode).await
 }
+/// 预先取出下一首音乐，用于 gapless 预加载，不改变当前索引
+pub async fn peek_next_music(play_mode: PlayMode) -> Result<Music, ApplicationError> {
+    let playlist = PLAYLIST.lock().await;
+    let playlist = playlist.as_ref().map_err(|e| e.clone())?;
+    let index = playlist.next_index(*CURRENT_MUSIC_INDEX.lock().await, play_mode)?;
+    playlist.get_current_music(index).await
+}
+/// 查找音乐在播放列表中的索引，不在播放列表中时返回 None
+pub async fn find_music_in_playlist(music: &Music) -> Option<usize> {
+    let playlist = PLAYLIST.lock().await;
+    playlist
+        .as_ref()
+        .ok()?
+        .find_music_index(&music.bvid, Some(&music.cid))
+        .await
+}
 /// 移动到上一首音乐
 pub async fn move_to_previous_music(play_mode: PlayMode) -> Result<usize, ApplicationError> {
     let mut playlist = PLAYLIST.lock().await;
     let playlist = playlist.as_mut().map_err(|e| e.clone())?;
     playlist.move_to_previous_music(play_mode).await
 }
+/// 追加音乐到播放列表
+pub async fn add_music_to_playlist(music: Music) -> Result<(), ApplicationError> {
+    let mut playlist = PLAYLIST.lock().await;
+    let playlist = playlist.as_mut().map_err(|e| e.clone())?;
+    playlist.add_music(music);
+    Ok(())
+}
+/// 从播放列表中移除音乐
+pub async fn remove_music_from_playlist(
+    bvid: &str,
+    cid: &str,
+) -> Result<Option<(Music, bool)>, ApplicationError> {
+    let mut playlist = PLAYLIST.lock().await;
+    let playlist = playlist.as_mut().map_err(|e| e.clone())?;
+    Ok(playlist.remove_music(bvid, cid).await)
+}
+/// 获取播放列表的一页
+pub async fn get_playlist_page(
+    page: usize,
+    page_size: usize,
+) -> Result<PlaylistPage, ApplicationError> {
+    let playlist = PLAYLIST.lock().await;
+    let playlist = playlist.as_ref().map_err(|e| e.clone())?;
+    let index = *CURRENT_MUSIC_INDEX.lock().await;
+    Ok(playlist.page(page, page_size, index))
+}
+/// 获取播放列表长度
+pub async fn playlist_len() -> Result<usize, ApplicationError> {
+    let playlist = PLAYLIST.lock().await;
+    let playlist = playlist.as_ref().map_err(|e| e.clone())?;
+    Ok(playlist.musics.len())
+}
 /// 设置当前播放的音乐索引
 pub async fn set_current_music_index(index: usize) -> Result<(), ApplicationError> {
     let mut current_index = CURRENT_MUSIC_INDEX.lock().await;
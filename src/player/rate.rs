@@ -0,0 +1,87 @@
+use std::sync::{Mutex, MutexGuard};
+
+use once_cell::sync::Lazy;
+
+use crate::errors::ApplicationError;
+
+// 播放速度的范围
+pub const MIN_RATE: f64 = 0.5;
+pub const MAX_RATE: f64 = 2.0;
+
+// 当前的播放速度，建立输出链和跳转时从这里读取
+static PLAYBACK_RATE: Lazy<PlaybackRate> = Lazy::new(PlaybackRate::default);
+
+/// 播放速度设置
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct RateSettings {
+    pub rate: f64,            // 播放速度 (0.5-2.0)
+    pub preserve_pitch: bool, // 是否使用 scaletempo 保持音调
+    pub keep: bool,           // 切换歌曲时是否保持速度，否则恢复为 1.0
+}
+
+impl Default for RateSettings {
+    fn default() -> Self {
+        Self {
+            rate: 1.0,
+            preserve_pitch: false,
+            keep: false,
+        }
+    }
+}
+
+impl RateSettings {
+    /// 是否为正常速度
+    pub fn is_normal(&self) -> bool {
+        self.rate == 1.0
+    }
+}
+
+/// 播放速度，通过带速度的跳转应用到 pipeline
+#[derive(Debug, Default)]
+pub struct PlaybackRate {
+    settings: Mutex<RateSettings>,
+}
+
+impl PlaybackRate {
+    /// 获取当前设置
+    pub fn settings(&self) -> RateSettings {
+        *self.lock()
+    }
+
+    /// 设置播放速度
+    pub fn set(&self, settings: RateSettings) -> Result<RateSettings, ApplicationError> {
+        check_rate(settings.rate)?;
+        *self.lock() = settings;
+        Ok(settings)
+    }
+
+    /// 切换到新的歌曲，没有选择保持速度时恢复为正常速度
+    pub fn track_changed(&self) -> RateSettings {
+        let mut settings = self.lock();
+        if !settings.keep {
+            settings.rate = 1.0;
+        }
+        *settings
+    }
+
+    // 锁中毒时继续使用里面的数据
+    fn lock(&self) -> MutexGuard<'_, RateSettings> {
+        self.settings
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// 获取全局的播放速度
+pub fn playback_rate() -> &'static PlaybackRate {
+    &PLAYBACK_RATE
+}
+
+fn check_rate(rate: f64) -> Result<(), ApplicationError> {
+    if !(MIN_RATE..=MAX_RATE).contains(&rate) {
+        return Err(ApplicationError::InvalidArgumentError(format!(
+            "播放速度在：{MIN_RATE}-{MAX_RATE}"
+        )));
+    }
+    Ok(())
+}
@@ -0,0 +1,158 @@
+use std::str::FromStr;
+
+use crate::{
+    errors::ApplicationError,
+    library::session_repository::SessionRecord,
+    player::{
+        command::PlayMode,
+        crossfade::MAX_CROSSFADE_SECONDS,
+        equalizer::{EqualizerSettings, format_gains, parse_gains},
+        state::{Music, PlayerStateSnapshot},
+        volume::MAX_VOLUME,
+    },
+};
+
+/// 启动时恢复播放的方式
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum ResumeMode {
+    #[default]
+    Playing, // 直接继续播放
+    Paused, // 恢复到上次的位置后暂停，等待 play 命令
+}
+
+impl FromStr for ResumeMode {
+    type Err = ApplicationError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "playing" | "play" => Ok(ResumeMode::Playing),
+            "paused" | "pause" => Ok(ResumeMode::Paused),
+            other => Err(ApplicationError::DataParsingError(format!(
+                "无效的恢复方式: {other}，可选 playing 或 paused"
+            ))),
+        }
+    }
+}
+
+/// 从环境变量 RESUME_MODE 中读取启动时的恢复方式，未设置时继续播放
+pub fn resume_mode() -> Result<ResumeMode, ApplicationError> {
+    dotenvy::dotenv().ok();
+    match std::env::var("RESUME_MODE") {
+        Ok(value) => value.parse(),
+        Err(_) => Ok(ResumeMode::default()),
+    }
+}
+
+/// 播放会话，保存到数据库中用于重启后恢复
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlaybackSession {
+    pub play_mode: PlayMode,
+    pub volume: u32,
+    pub muted: bool,
+    pub crossfade: u32,
+    pub equalizer: EqualizerSettings,
+    pub current_index: usize,
+    pub current_bvid: Option<String>,
+    pub current_cid: Option<String>,
+    pub position: Option<f64>,
+}
+
+impl Default for PlaybackSession {
+    fn default() -> Self {
+        Self {
+            play_mode: PlayMode::Normal,
+            volume: 100,
+            muted: false,
+            crossfade: 0,
+            equalizer: EqualizerSettings::default(),
+            current_index: 0,
+            current_bvid: None,
+            current_cid: None,
+            position: None,
+        }
+    }
+}
+
+impl PlaybackSession {
+    /// 在播放列表中重新定位上次播放的歌曲
+    ///
+    /// 优先按 (bvid, cid) 查找，找不到时使用保存的索引；只有找到同一首歌时才恢复播放位置
+    pub fn resolve(&self, musics: &[Music]) -> (usize, Option<f64>) {
+        if let Some(bvid) = &self.current_bvid
+            && let Some(index) = musics
+                .iter()
+                .position(|music| music.matches(bvid, self.current_cid.as_deref()))
+        {
+            return (index, self.position);
+        }
+        if self.current_index < musics.len() {
+            (self.current_index, None)
+        } else {
+            (0, None)
+        }
+    }
+}
+
+impl From<SessionRecord> for PlaybackSession {
+    fn from(record: SessionRecord) -> Self {
+        Self {
+            play_mode: PlayMode::from_string(&record.play_mode).unwrap_or_default(),
+            volume: record.volume.clamp(0, MAX_VOLUME as i64) as u32,
+            muted: record.muted,
+            crossfade: record
+                .crossfade_seconds
+                .clamp(0, MAX_CROSSFADE_SECONDS as i64) as u32,
+            // 保存的增益无效时恢复为所有频段为 0
+            equalizer: parse_gains(&record.equalizer_gains)
+                .map(|gains| EqualizerSettings {
+                    gains,
+                    preset: record.equalizer_preset,
+                })
+                .unwrap_or_default(),
+            current_index: record.current_index.max(0) as usize,
+            current_bvid: record.current_bvid,
+            current_cid: record.current_cid,
+            position: Some(record.position).filter(|position| *position > 0.0),
+        }
+    }
+}
+
+impl From<&PlaybackSession> for SessionRecord {
+    fn from(session: &PlaybackSession) -> Self {
+        Self {
+            current_index: session.current_index as i64,
+            current_bvid: session.current_bvid.clone(),
+            current_cid: session.current_cid.clone(),
+            position: session.position.unwrap_or_default(),
+            play_mode: session.play_mode.as_str().to_string(),
+            volume: session.volume as i64,
+            muted: session.muted,
+            crossfade_seconds: session.crossfade as i64,
+            equalizer_gains: format_gains(&session.equalizer.gains),
+            equalizer_preset: session.equalizer.preset.clone(),
+            updated_at: None,
+        }
+    }
+}
+
+impl From<&PlayerStateSnapshot> for PlaybackSession {
+    fn from(snapshot: &PlayerStateSnapshot) -> Self {
+        Self {
+            play_mode: snapshot.play_mode,
+            volume: snapshot.volume,
+            muted: snapshot.muted,
+            crossfade: snapshot.crossfade,
+            equalizer: snapshot.equalizer.clone(),
+            current_index: snapshot.current_index.unwrap_or_default(),
+            current_bvid: snapshot
+                .current_music
+                .as_ref()
+                .map(|music| music.bvid.clone()),
+            current_cid: snapshot
+                .current_music
+                .as_ref()
+                .map(|music| music.cid.clone()),
+            position: snapshot.current_position,
+        }
+    }
+}
@@ -1,3 +1,8 @@
+use crate::{
+    fetch::quality::AudioQuality,
+    player::{command::PlayMode, equalizer::EqualizerSettings, rate::RateSettings},
+};
+
 // 用来存放音乐数据
 #[derive(serde::Serialize, serde::Deserialize, Clone, PartialEq, Default)]
 pub struct Music {
@@ -6,37 +11,161 @@ pub struct Music {
     pub title: String,
     pub owner: String,
 }
+impl Music {
+    /// 是否为指定的视频或分P，cid 为 None 时匹配该视频的任意分P
+    pub fn matches(&self, bvid: &str, cid: Option<&str>) -> bool {
+        self.bvid == bvid && cid.is_none_or(|cid| self.cid == cid)
+    }
+}
+impl From<Music> for crate::pb::MusicInfo {
+    fn from(music: Music) -> Self {
+        crate::pb::MusicInfo {
+            bvid: music.bvid,
+            cid: music.cid,
+            title: music.title,
+            owner: music.owner,
+        }
+    }
+}
 impl std::fmt::Debug for Music {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(f, "{} - {} - {}", self.title, self.owner, self.bvid)
     }
 }
+/// 播放状态
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum PlaybackState {
+    #[default]
+    Stopped,
+    Playing,
+    Paused,
+    Buffering,
+}
+impl PlaybackState {
+    pub fn get_string(&self) -> String {
+        match self {
+            PlaybackState::Stopped => "已停止".to_string(),
+            PlaybackState::Playing => "播放中".to_string(),
+            PlaybackState::Paused => "已暂停".to_string(),
+            PlaybackState::Buffering => "缓冲中".to_string(),
+        }
+    }
+}
+impl From<gstreamer::State> for PlaybackState {
+    fn from(state: gstreamer::State) -> Self {
+        match state {
+            gstreamer::State::Playing => PlaybackState::Playing,
+            gstreamer::State::Paused => PlaybackState::Paused,
+            _ => PlaybackState::Stopped,
+        }
+    }
+}
+impl From<PlaybackState> for crate::pb::PlaybackState {
+    fn from(state: PlaybackState) -> Self {
+        match state {
+            PlaybackState::Stopped => crate::pb::PlaybackState::Stopped,
+            PlaybackState::Playing => crate::pb::PlaybackState::Playing,
+            PlaybackState::Paused => crate::pb::PlaybackState::Paused,
+            PlaybackState::Buffering => crate::pb::PlaybackState::Buffering,
+        }
+    }
+}
 // 播放器快照，用于返回状态信息
 #[derive(Debug, Clone)]
 pub struct PlayerStateSnapshot {
     pub current_music: Option<Music>,  // 当前播放的音乐
-    pub is_playing: bool,              // 是否正在播放
-    pub play_mode: String,             // 播放模式
+    pub state: PlaybackState,          // 播放状态
+    pub play_mode: PlayMode,           // 播放模式
     pub current_index: Option<usize>,  // 当前播放的索引
     pub playlist_len: usize,           // 播放列表长度
     pub current_position: Option<f64>, // 当前播放位置 (秒)
     pub duration: Option<f64>,         // 当前音乐总时长 (秒)
+    pub volume: u32,                   // 音量百分比
+    pub muted: bool,                   // 是否静音
+    pub crossfade: u32,                // 淡入淡出时长 (秒)
+    pub equalizer: EqualizerSettings,  // 均衡器设置
+    pub rate: RateSettings,            // 播放速度
+    pub quality: Option<AudioQuality>, // 当前歌曲使用的音质
 }
 
 impl std::fmt::Display for PlayerStateSnapshot {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         write!(
             f,
-            "当前播放: {} {:?}/{:?} 播放模式: {} 第{}个/总共{}",
+            "{}: {} {}/{} 播放模式: {} 第{}个/总共{} 音量: {}%{}{}{}{}{}",
+            self.state.get_string(),
             self.current_music
                 .as_ref()
                 .map(|m| m.title.clone())
                 .unwrap_or_default(),
-            self.current_position,
-            self.duration,
-            self.play_mode,
-            self.current_index.unwrap_or_default() + 1,
-            self.playlist_len, // todo()!
+            format_seconds(self.current_position),
+            format_seconds(self.duration),
+            self.play_mode.get_string(),
+            self.current_index.map(|i| i + 1).unwrap_or_default(),
+            self.playlist_len,
+            self.volume,
+            if self.muted { " (静音)" } else { "" },
+            if self.crossfade > 0 {
+                format!(" 淡入淡出: {}秒", self.crossfade)
+            } else {
+                String::new()
+            },
+            if self.equalizer.is_flat() {
+                String::new()
+            } else {
+                format!(" 均衡器: {}", self.equalizer.label())
+            },
+            if self.rate.is_normal() {
+                String::new()
+            } else {
+                format!(
+                    " 速度: {}x{}",
+                    self.rate.rate,
+                    if self.rate.preserve_pitch {
+                        " (保持音调)"
+                    } else {
+                        ""
+                    }
+                )
+            },
+            self.quality
+                .as_ref()
+                .map(|quality| format!(" 音质: {}", quality.label()))
+                .unwrap_or_default(),
         )
     }
 }
+
+impl From<PlayerStateSnapshot> for crate::pb::GetStateResponse {
+    fn from(snapshot: PlayerStateSnapshot) -> Self {
+        crate::pb::GetStateResponse {
+            success: true,
+            message: snapshot.to_string(),
+            current_music: snapshot.current_music.map(Into::into),
+            state: crate::pb::PlaybackState::from(snapshot.state).into(),
+            play_mode: crate::pb::PlayMode::from(snapshot.play_mode).into(),
+            current_index: snapshot.current_index.map(|i| i as u32),
+            playlist_len: snapshot.playlist_len as u32,
+            position: snapshot.current_position,
+            duration: snapshot.duration,
+            volume: snapshot.volume,
+            muted: snapshot.muted,
+            crossfade: snapshot.crossfade,
+            equalizer: snapshot.equalizer.label(),
+            rate: snapshot.rate.rate,
+            preserve_pitch: snapshot.rate.preserve_pitch,
+            quality: snapshot.quality.map(Into::into),
+        }
+    }
+}
+
+/// 将秒数格式化为 mm:ss，未知时显示 --:--
+pub fn format_seconds(seconds: Option<f64>) -> String {
+    match seconds {
+        Some(seconds) if seconds >= 0.0 => {
+            let total = seconds as u64;
+            format!("{:02}:{:02}", total / 60, total % 60)
+        }
+        _ => "--:--".to_string(),
+    }
+}
@@ -0,0 +1,73 @@
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+
+use gstreamer::{glib::object::ObjectExt, prelude::GstBinExt};
+
+use crate::errors::ApplicationError;
+
+// 最大音量百分比
+pub const MAX_VOLUME: u32 = 200;
+// pipeline 中音量元素的名称
+pub const VOLUME_ELEMENT_NAME: &str = "audio_volume";
+
+/// 音量状态，切换歌曲时保持不变
+#[derive(Debug)]
+pub struct Volume {
+    level: AtomicU32,  // 音量百分比 (0-200)
+    muted: AtomicBool, // 是否静音，静音时保留原音量
+}
+
+impl Volume {
+    pub fn new(level: u32) -> Result<Self, ApplicationError> {
+        check_level(level)?;
+        Ok(Self {
+            level: AtomicU32::new(level),
+            muted: AtomicBool::new(false),
+        })
+    }
+    /// 获取音量百分比 (0-200)
+    pub fn level(&self) -> u32 {
+        self.level.load(Ordering::Relaxed)
+    }
+    /// 是否静音
+    pub fn is_muted(&self) -> bool {
+        self.muted.load(Ordering::Relaxed)
+    }
+    /// 设置音量百分比，同时取消静音
+    pub fn set_level(&self, level: u32) -> Result<u32, ApplicationError> {
+        check_level(level)?;
+        self.level.store(level, Ordering::Relaxed);
+        self.muted.store(false, Ordering::Relaxed);
+        Ok(level)
+    }
+    /// 相对调整音量，超出范围时取边界值，同时取消静音
+    pub fn adjust(&self, delta: i32) -> u32 {
+        let level = (self.level() as i64 + delta as i64).clamp(0, MAX_VOLUME as i64) as u32;
+        self.level.store(level, Ordering::Relaxed);
+        self.muted.store(false, Ordering::Relaxed);
+        level
+    }
+    /// 设置静音状态
+    pub fn set_muted(&self, muted: bool) {
+        self.muted.store(muted, Ordering::Relaxed);
+    }
+    /// 获取 GStreamer 音量值 (0.0-2.0)
+    pub fn gstreamer_volume(&self) -> f64 {
+        self.level() as f64 / 100.0
+    }
+    /// 应用到 pipeline 中的音量元素
+    pub fn apply(&self, pipeline: &gstreamer::Pipeline) {
+        if let Some(volume_elem) = pipeline.by_name(VOLUME_ELEMENT_NAME) {
+            volume_elem.set_property("volume", self.gstreamer_volume());
+            volume_elem.set_property("mute", self.is_muted());
+        }
+    }
+}
+
+fn check_level(level: u32) -> Result<(), ApplicationError> {
+    if level > MAX_VOLUME {
+        return Err(ApplicationError::InvalidArgumentError(format!(
+            "音量值在：0-{MAX_VOLUME}"
+        )));
+    }
+    Ok(())
+}
@@ -1,37 +1,81 @@
-use std::sync::Arc;
+use std::{pin::Pin, sync::Arc};
 
 use bili_player::{
+    errors::ApplicationError,
+    fetch::{
+        auth::{
+            AuthSession, BILIBILI_PASSPORT_BASE, Credentials, QrLoginState, credentials_file,
+            fetch_login_status, poll_qr_login, start_qr_login,
+        },
+        network::{BILIBILI_API_BASE, VideoFilter, search_videos},
+        quality::{audio_preference, set_audio_preference},
+    },
+    library::{
+        credential_repository::CredentialRepository,
+        database::{database_url, init_database},
+        equalizer_repository::EqualizerRepository,
+        import::VideoSource,
+        playlist_repository::PlaylistRepository,
+        repository::{MusicQuery, MusicRepository},
+        session_repository::SessionRepository,
+    },
     logger::init_logger,
     pb::{
-        AddPlaylistRequest, AddPlaylistResponse, DeletedRequest, DeletedResponse, GetStateRequest,
-        GetStateResponse, NextRequest, NextResponse, PauseRequest, PauseResponse, PlayBvidRequest,
-        PlayBvidResponse, PlayRequest, PlayResponse, PreviousRequest, PreviousResponse,
-        SetModelRequest, SetModelResponse, SetVolumeRequest, SetVolumeResponse,
-        ShowPlayListRequest, ShowPlayListResponse, StopRequest, StopResponse,
+        self, AddPlaylistRequest, AddPlaylistResponse, AddToPlaylistRequest, AddToPlaylistResponse,
+        ApplyEqualizerPresetRequest, CreatePlaylistRequest, CreatePlaylistResponse,
+        DeleteEqualizerPresetRequest, DeletePlaylistRequest, DeletePlaylistResponse,
+        DeletedRequest, DeletedResponse, EqualizerResponse, FindMusicRequest, FindMusicResponse,
+        GetEqualizerRequest, GetStateRequest, GetStateResponse, ImportFavoriteRequest,
+        ImportFavoriteResponse, ImportSeasonRequest, ImportUploaderRequest, ImportVideosResponse,
+        ListPlaylistsRequest, ListPlaylistsResponse, LoginStatusRequest, LoginStatusResponse,
+        LogoutRequest, LogoutResponse, NextRequest, NextResponse, PauseRequest, PauseResponse,
+        PlayBvidRequest, PlayBvidResponse, PlayRequest, PlayResponse, PreviousRequest,
+        PreviousResponse, QrLoginPollRequest, QrLoginPollResponse, QrLoginStartRequest,
+        QrLoginStartResponse, RemoveFromPlaylistRequest, RemoveFromPlaylistResponse,
+        RenamePlaylistRequest, RenamePlaylistResponse, RestoreRequest, RestoreResponse,
+        SaveEqualizerPresetRequest, SearchRequest, SearchResponse, SeekRequest, SeekResponse,
+        SetCredentialsRequest, SetCrossfadeRequest, SetCrossfadeResponse, SetEqualizerBandRequest,
+        SetModelRequest, SetModelResponse, SetRateRequest, SetRateResponse, SetVolumeRequest,
+        SetVolumeResponse, ShowPlayListRequest, ShowPlayListResponse, StopRequest, StopResponse,
+        SubscribeEventsRequest, SubscribeEventsResponse, SwitchPlaylistRequest,
+        SwitchPlaylistResponse,
         player_service_server::{PlayerService, PlayerServiceServer},
     },
     player::{
         audio_player::AudioPlayer,
-        command::{PlayMode, PlayerCommand},
+        command::{EqualizerAction, PlayMode, PlayerCommand, SeekTarget, VolumeAction},
+        equalizer::{BAND_FREQUENCIES, EqualizerSettings, band_gains, equalizer},
+        event::{EVENT_CHANNEL_CAPACITY, PlayerEvent},
+        loudness::{normalization, set_normalization},
         play_list::load_playlist,
+        rate::RateSettings,
+        session::{PlaybackSession, resume_mode},
+        state::{Music, format_seconds},
     },
 };
-use tokio::sync::{Mutex, mpsc};
+use futures_util::Stream;
+use tokio::sync::{Mutex, broadcast, mpsc, oneshot};
 use tonic::{Request, Response, Status, transport::Server};
 
 /// 创建一个结构体，用来实现 rpc 中的 server
 // #[derive(Default)]
 pub struct PlayerServer {
     pub command_sender: mpsc::Sender<PlayerCommand>,
-}
-impl PlayerServer {
-    pub fn new(command_sender: mpsc::Sender<PlayerCommand>) -> Self {
-        Self { command_sender }
-    }
+    pub event_sender: broadcast::Sender<PlayerEvent>,
+    pub playlists: PlaylistRepository,
+    pub repository: MusicRepository,
+    pub equalizers: EqualizerRepository,
+    // 搜索等不需要经过播放器的请求直接使用，和播放器共用登录状态
+    pub client: reqwest::Client,
+    pub auth: AuthSession,
+    pub credentials: CredentialRepository,
 }
 /// 实现 PlayerService trait
 #[tonic::async_trait]
 impl PlayerService for PlayerServer {
+    type SubscribeEventsStream =
+        Pin<Box<dyn Stream<Item = Result<SubscribeEventsResponse, Status>> + Send>>;
+
     async fn play(&self, _request: Request<PlayRequest>) -> Result<Response<PlayResponse>, Status> {
         let result = PlayResponse {
             success: true,
@@ -124,71 +168,812 @@ impl PlayerService for PlayerServer {
     }
     async fn add_playlist(
         &self,
-        _request: Request<AddPlaylistRequest>,
+        request: Request<AddPlaylistRequest>,
     ) -> Result<Response<AddPlaylistResponse>, Status> {
-        todo!()
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::AddPlaylist(input, sender))
+            .await
+            .map_err(|_| Status::internal("添加歌曲失败"))?;
+        let musics = receiver
+            .await
+            .map_err(|_| Status::internal("添加歌曲失败"))??;
+        let result = AddPlaylistResponse {
+            success: true,
+            message: describe_musics("已添加", &musics),
+            music: musics.first().cloned().map(Into::into),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
     }
     async fn deleted(
         &self,
-        _request: Request<DeletedRequest>,
+        request: Request<DeletedRequest>,
     ) -> Result<Response<DeletedResponse>, Status> {
-        todo!()
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::Delete(input, sender))
+            .await
+            .map_err(|_| Status::internal("删除歌曲失败"))?;
+        let musics = receiver
+            .await
+            .map_err(|_| Status::internal("删除歌曲失败"))??;
+        let result = DeletedResponse {
+            success: true,
+            message: describe_musics("已删除", &musics),
+            music: musics.first().cloned().map(Into::into),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn restore(
+        &self,
+        request: Request<RestoreRequest>,
+    ) -> Result<Response<RestoreResponse>, Status> {
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::Restore(input, sender))
+            .await
+            .map_err(|_| Status::internal("恢复歌曲失败"))?;
+        let musics = receiver
+            .await
+            .map_err(|_| Status::internal("恢复歌曲失败"))??;
+        let result = RestoreResponse {
+            success: true,
+            message: describe_musics("已恢复", &musics),
+            music: musics.first().cloned().map(Into::into),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
     }
     async fn get_state(
         &self,
         _request: Request<GetStateRequest>,
     ) -> Result<Response<GetStateResponse>, Status> {
-        todo!()
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::GetState(sender))
+            .await
+            .map_err(|_| Status::internal("获取播放状态失败"))?;
+        let snapshot = receiver
+            .await
+            .map_err(|_| Status::internal("获取播放状态失败"))?;
+        Ok(Response::new(snapshot.into()))
     }
     async fn show_play_list(
         &self,
-        _request: Request<ShowPlayListRequest>,
+        request: Request<ShowPlayListRequest>,
     ) -> Result<Response<ShowPlayListResponse>, Status> {
-        todo!()
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::ShowPlaylist(input, sender))
+            .await
+            .map_err(|_| Status::internal("获取播放列表失败"))?;
+        let page = receiver
+            .await
+            .map_err(|_| Status::internal("获取播放列表失败"))??;
+        Ok(Response::new(page.into()))
+    }
+    async fn seek(&self, request: Request<SeekRequest>) -> Result<Response<SeekResponse>, Status> {
+        let target = SeekTarget::try_from(request.into_inner())?;
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::Seek(target, sender))
+            .await
+            .map_err(|_| Status::internal("跳转失败"))?;
+        let position = receiver.await.map_err(|_| Status::internal("跳转失败"))??;
+        let result = SeekResponse {
+            success: true,
+            message: format!("已跳转到 {}", format_seconds(Some(position))),
+            position,
+        };
+        Ok(Response::new(result))
+    }
+    async fn set_crossfade(
+        &self,
+        request: Request<SetCrossfadeRequest>,
+    ) -> Result<Response<SetCrossfadeResponse>, Status> {
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::SetCrossfade(input.seconds, sender))
+            .await
+            .map_err(|_| Status::internal("设置淡入淡出失败"))?;
+        let seconds = receiver
+            .await
+            .map_err(|_| Status::internal("设置淡入淡出失败"))??;
+        let message = if seconds == 0 {
+            "已关闭淡入淡出".to_string()
+        } else {
+            format!("淡入淡出: {seconds}秒")
+        };
+        let result = SetCrossfadeResponse {
+            success: true,
+            message,
+            seconds,
+        };
+        Ok(Response::new(result))
+    }
+    async fn set_rate(
+        &self,
+        request: Request<SetRateRequest>,
+    ) -> Result<Response<SetRateResponse>, Status> {
+        let input = request.into_inner();
+        let settings = RateSettings {
+            rate: input.rate,
+            preserve_pitch: input.preserve_pitch,
+            keep: input.keep,
+        };
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::SetRate(settings, sender))
+            .await
+            .map_err(|_| Status::internal("设置播放速度失败"))?;
+        let settings = receiver
+            .await
+            .map_err(|_| Status::internal("设置播放速度失败"))??;
+        let message = format!(
+            "播放速度: {}x{}{}",
+            settings.rate,
+            if settings.preserve_pitch {
+                " 保持音调"
+            } else {
+                ""
+            },
+            if settings.keep {
+                " 切换歌曲时保持"
+            } else {
+                ""
+            }
+        );
+        let result = SetRateResponse {
+            success: true,
+            message,
+            rate: settings.rate,
+            preserve_pitch: settings.preserve_pitch,
+            keep: settings.keep,
+        };
+        Ok(Response::new(result))
+    }
+    async fn get_equalizer(
+        &self,
+        _request: Request<GetEqualizerRequest>,
+    ) -> Result<Response<EqualizerResponse>, Status> {
+        let settings = equalizer().settings();
+        let message = format!("均衡器: {}", settings.label());
+        let result = self.equalizer_response(message, &settings).await?;
+        Ok(Response::new(result))
+    }
+    async fn set_equalizer_band(
+        &self,
+        request: Request<SetEqualizerBandRequest>,
+    ) -> Result<Response<EqualizerResponse>, Status> {
+        let input = request.into_inner();
+        let band = input.band as usize;
+        let settings = self
+            .set_equalizer(EqualizerAction::Band(band, input.gain))
+            .await?;
+        let message = format!("{}Hz: {} dB", BAND_FREQUENCIES[band], input.gain);
+        let result = self.equalizer_response(message, &settings).await?;
+        Ok(Response::new(result))
+    }
+    async fn apply_equalizer_preset(
+        &self,
+        request: Request<ApplyEqualizerPresetRequest>,
+    ) -> Result<Response<EqualizerResponse>, Status> {
+        let input = request.into_inner();
+        let preset = self.equalizers.get_by_name(&input.name).await?;
+        let settings = self
+            .set_equalizer(EqualizerAction::Apply(EqualizerSettings {
+                gains: preset.band_gains()?,
+                preset: Some(preset.name.clone()),
+            }))
+            .await?;
+        let message = format!("已应用均衡器预设: {}", preset.name);
+        let result = self.equalizer_response(message, &settings).await?;
+        Ok(Response::new(result))
+    }
+    async fn save_equalizer_preset(
+        &self,
+        request: Request<SaveEqualizerPresetRequest>,
+    ) -> Result<Response<EqualizerResponse>, Status> {
+        let input = request.into_inner();
+        let record = if input.gains.is_empty() {
+            // 保存当前的设置，之后当前设置对应这个预设
+            let current = equalizer().settings();
+            let record = self.equalizers.save(&input.name, &current.gains).await?;
+            self.set_equalizer(EqualizerAction::Apply(EqualizerSettings {
+                gains: current.gains,
+                preset: Some(record.name.clone()),
+            }))
+            .await?;
+            record
+        } else {
+            let gains = band_gains(&input.gains)?;
+            self.equalizers.save(&input.name, &gains).await?
+        };
+        let message = format!("已保存均衡器预设: {}", record.name);
+        let result = self
+            .equalizer_response(message, &equalizer().settings())
+            .await?;
+        Ok(Response::new(result))
+    }
+    async fn delete_equalizer_preset(
+        &self,
+        request: Request<DeleteEqualizerPresetRequest>,
+    ) -> Result<Response<EqualizerResponse>, Status> {
+        let input = request.into_inner();
+        let record = self.equalizers.delete(&input.name).await?;
+        // 删除正在使用的预设时保留增益，改为自定义
+        let mut settings = equalizer().settings();
+        if settings.preset.as_deref() == Some(record.name.as_str()) {
+            settings.preset = None;
+            settings = self.set_equalizer(EqualizerAction::Apply(settings)).await?;
+        }
+        let message = format!("已删除均衡器预设: {}", record.name);
+        let result = self.equalizer_response(message, &settings).await?;
+        Ok(Response::new(result))
+    }
+    async fn subscribe_events(
+        &self,
+        _request: Request<SubscribeEventsRequest>,
+    ) -> Result<Response<Self::SubscribeEventsStream>, Status> {
+        let receiver = self.event_sender.subscribe();
+        let stream = futures_util::stream::unfold(receiver, |mut receiver| async move {
+            loop {
+                match receiver.recv().await {
+                    Ok(event) => return Some((Ok(event.into()), receiver)),
+                    // 订阅者处理过慢，跳过丢失的事件继续推送
+                    Err(broadcast::error::RecvError::Lagged(skipped)) => {
+                        tracing::warn!("Event subscriber lagged, skipped {} events", skipped);
+                    }
+                    Err(broadcast::error::RecvError::Closed) => return None,
+                }
+            }
+        });
+        Ok(Response::new(Box::pin(stream)))
     }
     async fn set_volume(
         &self,
-        _request: Request<SetVolumeRequest>,
+        request: Request<SetVolumeRequest>,
     ) -> Result<Response<SetVolumeResponse>, Status> {
-        todo!()
+        let action = VolumeAction::try_from(request.into_inner())?;
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::SetVolume(action, sender))
+            .await
+            .map_err(|_| Status::internal("设置音量失败"))?;
+        let (volume, muted) = receiver
+            .await
+            .map_err(|_| Status::internal("设置音量失败"))??;
+        let message = if muted {
+            format!("已静音 (音量: {volume}%)")
+        } else {
+            format!("音量: {volume}%")
+        };
+        let result = SetVolumeResponse {
+            success: true,
+            message,
+            volume,
+            muted,
+        };
+        Ok(Response::new(result))
+    }
+    async fn create_playlist(
+        &self,
+        request: Request<CreatePlaylistRequest>,
+    ) -> Result<Response<CreatePlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let record = self.playlists.create(&input.name).await?;
+        let summary = self.playlists.summary(&record.name).await?;
+        let result = CreatePlaylistResponse {
+            success: true,
+            message: format!("已创建歌单: {}", summary.name),
+            playlist: Some(summary.into()),
+        };
+        Ok(Response::new(result))
+    }
+    async fn rename_playlist(
+        &self,
+        request: Request<RenamePlaylistRequest>,
+    ) -> Result<Response<RenamePlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let record = self.playlists.rename(&input.name, &input.new_name).await?;
+        let summary = self.playlists.summary(&record.name).await?;
+        let result = RenamePlaylistResponse {
+            success: true,
+            message: format!("已将歌单 {} 重命名为 {}", input.name.trim(), summary.name),
+            playlist: Some(summary.into()),
+        };
+        Ok(Response::new(result))
+    }
+    async fn delete_playlist(
+        &self,
+        request: Request<DeletePlaylistRequest>,
+    ) -> Result<Response<DeletePlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let record = self.playlists.delete(&input.name).await?;
+        let result = DeletePlaylistResponse {
+            success: true,
+            message: format!("已删除歌单: {}", record.name),
+        };
+        Ok(Response::new(result))
+    }
+    async fn list_playlists(
+        &self,
+        _request: Request<ListPlaylistsRequest>,
+    ) -> Result<Response<ListPlaylistsResponse>, Status> {
+        let playlists = self.playlists.list().await?;
+        let result = ListPlaylistsResponse {
+            success: true,
+            playlists: playlists.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn switch_playlist(
+        &self,
+        request: Request<SwitchPlaylistRequest>,
+    ) -> Result<Response<SwitchPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::SwitchPlaylist(input, sender))
+            .await
+            .map_err(|_| Status::internal("切换歌单失败"))?;
+        let summary = receiver
+            .await
+            .map_err(|_| Status::internal("切换歌单失败"))??;
+        let result = SwitchPlaylistResponse {
+            success: true,
+            message: format!(
+                "已切换到歌单: {} ({} 首)",
+                summary.name, summary.track_count
+            ),
+            playlist: Some(summary.into()),
+        };
+        Ok(Response::new(result))
+    }
+    async fn add_to_playlist(
+        &self,
+        request: Request<AddToPlaylistRequest>,
+    ) -> Result<Response<AddToPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let name = input.name.trim().to_string();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::AddToPlaylist(input, sender))
+            .await
+            .map_err(|_| Status::internal("添加到歌单失败"))?;
+        let musics = receiver
+            .await
+            .map_err(|_| Status::internal("添加到歌单失败"))??;
+        let result = AddToPlaylistResponse {
+            success: true,
+            message: describe_musics(&format!("已添加到歌单 {name}"), &musics),
+            music: musics.first().cloned().map(Into::into),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn remove_from_playlist(
+        &self,
+        request: Request<RemoveFromPlaylistRequest>,
+    ) -> Result<Response<RemoveFromPlaylistResponse>, Status> {
+        let input = request.into_inner();
+        let name = input.name.trim().to_string();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::RemoveFromPlaylist(input, sender))
+            .await
+            .map_err(|_| Status::internal("从歌单移除失败"))?;
+        let musics = receiver
+            .await
+            .map_err(|_| Status::internal("从歌单移除失败"))??;
+        let result = RemoveFromPlaylistResponse {
+            success: true,
+            message: describe_musics(&format!("已从歌单 {name} 移除"), &musics),
+            music: musics.first().cloned().map(Into::into),
+            musics: musics.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn import_favorite(
+        &self,
+        request: Request<ImportFavoriteRequest>,
+    ) -> Result<Response<ImportFavoriteResponse>, Status> {
+        let input = request.into_inner();
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::ImportFavorite(input, sender))
+            .await
+            .map_err(|_| Status::internal("导入收藏夹失败"))?;
+        let report = receiver
+            .await
+            .map_err(|_| Status::internal("导入收藏夹失败"))??;
+        let result = ImportFavoriteResponse {
+            success: true,
+            message: format!(
+                "已导入收藏夹 {} 到歌单 {}: 新增 {} 首，已存在 {} 个，失效 {} 个，失败 {} 个",
+                report.folder.title,
+                report.playlist.name,
+                report.added.len(),
+                report.existing,
+                report.unavailable.len(),
+                report.failed.len()
+            ),
+            playlist: Some(report.playlist.into()),
+            added: report.added.into_iter().map(Into::into).collect(),
+            existing: report.existing as u32,
+            removed_upstream: report
+                .removed_upstream
+                .into_iter()
+                .map(Into::into)
+                .collect(),
+            unavailable: report.unavailable,
+            failed: report.failed,
+        };
+        Ok(Response::new(result))
+    }
+    async fn import_season(
+        &self,
+        request: Request<ImportSeasonRequest>,
+    ) -> Result<Response<ImportVideosResponse>, Status> {
+        let input = request.into_inner();
+        let source = VideoSource::Season {
+            mid: input.mid,
+            season_id: input.season_id,
+        };
+        let filter = input.filter.map(Into::into).unwrap_or_default();
+        self.import_videos(source, filter).await
+    }
+    async fn import_uploader(
+        &self,
+        request: Request<ImportUploaderRequest>,
+    ) -> Result<Response<ImportVideosResponse>, Status> {
+        let input = request.into_inner();
+        let source = VideoSource::Uploader {
+            mid: input.mid,
+            max_pages: input.max_pages,
+        };
+        let filter = input.filter.map(Into::into).unwrap_or_default();
+        self.import_videos(source, filter).await
+    }
+    async fn search(
+        &self,
+        request: Request<SearchRequest>,
+    ) -> Result<Response<SearchResponse>, Status> {
+        let input = request.into_inner();
+        let page =
+            search_videos(&self.client, BILIBILI_API_BASE, &input.keyword, input.page).await?;
+        let result = SearchResponse {
+            success: true,
+            message: format!(
+                "{} 共 {} 个结果，第 {}/{} 页",
+                input.keyword.trim(),
+                page.num_results,
+                page.page,
+                page.num_pages
+            ),
+            results: page.results.into_iter().map(Into::into).collect(),
+            page: page.page,
+            num_pages: page.num_pages,
+            num_results: page.num_results,
+        };
+        Ok(Response::new(result))
+    }
+    async fn find_music(
+        &self,
+        request: Request<FindMusicRequest>,
+    ) -> Result<Response<FindMusicResponse>, Status> {
+        let query = MusicQuery::from(request.into_inner());
+        let found = self.repository.search(&query).await?;
+        let result = FindMusicResponse {
+            success: true,
+            message: format!("找到 {} 首歌曲", found.len()),
+            musics: found.into_iter().map(Into::into).collect(),
+        };
+        Ok(Response::new(result))
+    }
+    async fn get_login_status(
+        &self,
+        _request: Request<LoginStatusRequest>,
+    ) -> Result<Response<LoginStatusResponse>, Status> {
+        let status = fetch_login_status(&self.client, BILIBILI_API_BASE).await?;
+        Ok(Response::new(status.into()))
+    }
+    async fn set_credentials(
+        &self,
+        request: Request<SetCredentialsRequest>,
+    ) -> Result<Response<LoginStatusResponse>, Status> {
+        let credentials: Credentials = request
+            .into_inner()
+            .cookie
+            .parse()
+            .map_err(|e: ApplicationError| Status::invalid_argument(e.to_string()))?;
+        let status = self.login(credentials).await?;
+        Ok(Response::new(status))
+    }
+    async fn qr_login_start(
+        &self,
+        _request: Request<QrLoginStartRequest>,
+    ) -> Result<Response<QrLoginStartResponse>, Status> {
+        let qr_login = start_qr_login(&self.client, BILIBILI_PASSPORT_BASE).await?;
+        let result = QrLoginStartResponse {
+            url: qr_login.url,
+            qrcode_key: qr_login.qrcode_key,
+        };
+        Ok(Response::new(result))
+    }
+    async fn qr_login_poll(
+        &self,
+        request: Request<QrLoginPollRequest>,
+    ) -> Result<Response<QrLoginPollResponse>, Status> {
+        let qrcode_key = request.into_inner().qrcode_key;
+        let state = poll_qr_login(&self.client, BILIBILI_PASSPORT_BASE, &qrcode_key).await?;
+        let result = match state {
+            QrLoginState::Waiting => QrLoginPollResponse {
+                state: pb::QrLoginState::QrWaiting.into(),
+                status: None,
+            },
+            QrLoginState::Scanned => QrLoginPollResponse {
+                state: pb::QrLoginState::QrScanned.into(),
+                status: None,
+            },
+            QrLoginState::Expired => QrLoginPollResponse {
+                state: pb::QrLoginState::QrExpired.into(),
+                status: None,
+            },
+            QrLoginState::Confirmed(credentials) => QrLoginPollResponse {
+                state: pb::QrLoginState::QrConfirmed.into(),
+                status: Some(self.login(credentials).await?),
+            },
+        };
+        Ok(Response::new(result))
+    }
+    async fn logout(
+        &self,
+        _request: Request<LogoutRequest>,
+    ) -> Result<Response<LogoutResponse>, Status> {
+        self.auth.logout();
+        self.credentials.clear().await?;
+        tracing::info!("Logged out");
+        let result = LogoutResponse {
+            success: true,
+            message: "已退出登录".into(),
+        };
+        Ok(Response::new(result))
+    }
+}
+impl PlayerServer {
+    /// 使用新的凭据登录，凭据有效时保存，无效时恢复之前的登录状态
+    async fn login(&self, credentials: Credentials) -> Result<LoginStatusResponse, Status> {
+        let previous = self.auth.credentials();
+        self.auth.login(credentials.clone());
+        let status = fetch_login_status(&self.client, BILIBILI_API_BASE).await;
+        let status = match status {
+            Ok(status) if status.is_login => status,
+            result => {
+                match previous {
+                    Some(previous) => self.auth.login(previous),
+                    None => self.auth.logout(),
+                }
+                result?;
+                return Err(Status::unauthenticated("登录凭据无效或已过期"));
+            }
+        };
+        self.credentials.save(&credentials).await?;
+        tracing::info!("Logged in as {} ({})", status.uname, status.mid);
+        Ok(status.into())
+    }
+}
+impl PlayerServer {
+    /// 通过播放器调整均衡器，立即应用到当前播放
+    async fn set_equalizer(&self, action: EqualizerAction) -> Result<EqualizerSettings, Status> {
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::SetEqualizer(action, sender))
+            .await
+            .map_err(|_| Status::internal("设置均衡器失败"))?;
+        let settings = receiver
+            .await
+            .map_err(|_| Status::internal("设置均衡器失败"))??;
+        Ok(settings)
+    }
+
+    /// 生成均衡器的响应，包含当前各频段的增益和所有预设
+    async fn equalizer_response(
+        &self,
+        message: String,
+        settings: &EqualizerSettings,
+    ) -> Result<EqualizerResponse, Status> {
+        let presets = self
+            .equalizers
+            .list()
+            .await?
+            .into_iter()
+            .map(pb::EqualizerPreset::try_from)
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(EqualizerResponse {
+            success: true,
+            message,
+            bands: BAND_FREQUENCIES
+                .iter()
+                .zip(settings.gains)
+                .map(|(frequency, gain)| pb::EqualizerBand {
+                    frequency: *frequency,
+                    gain,
+                })
+                .collect(),
+            preset: settings.label(),
+            presets,
+        })
+    }
+}
+impl PlayerServer {
+    /// 导入合集或 UP 主投稿，并追加到当前歌单
+    async fn import_videos(
+        &self,
+        source: VideoSource,
+        filter: VideoFilter,
+    ) -> Result<Response<ImportVideosResponse>, Status> {
+        let (sender, receiver) = oneshot::channel();
+        self.command_sender
+            .send(PlayerCommand::ImportVideos(source, filter, sender))
+            .await
+            .map_err(|_| Status::internal("批量导入失败"))?;
+        let report = receiver
+            .await
+            .map_err(|_| Status::internal("批量导入失败"))??;
+        let result = ImportVideosResponse {
+            success: true,
+            message: format!(
+                "已从{}导入 {} 首，已存在 {} 个，失败 {} 个",
+                report.source,
+                report.added.len(),
+                report.existing,
+                report.failed.len()
+            ),
+            added: report.added.into_iter().map(Into::into).collect(),
+            existing: report.existing as u32,
+            failed: report.failed,
+        };
+        Ok(Response::new(result))
+    }
+}
+/// 生成操作结果的提示信息，多首歌曲时逐行列出
+fn describe_musics(action: &str, musics: &[Music]) -> String {
+    match musics {
+        [music] => format!("{action}: {} - {}", music.title, music.owner),
+        _ => {
+            let lines: Vec<String> = musics
+                .iter()
+                .map(|music| format!("  {} - {}", music.title, music.owner))
+                .collect();
+            format!("{action} {} 首:\n{}", musics.len(), lines.join("\n"))
+        }
+    }
+}
+/// 启动时检查保存的登录凭据是否已过期
+async fn report_login_status(client: reqwest::Client) {
+    match fetch_login_status(&client, BILIBILI_API_BASE).await {
+        Ok(status) if status.is_login => {
+            tracing::info!("Logged in as {} ({})", status.uname, status.mid)
+        }
+        Ok(_) => tracing::warn!("Bilibili login has expired, please login again"),
+        Err(e) => tracing::warn!("Failed to check login status: {}", e),
     }
 }
 #[tokio::main]
 async fn main() -> anyhow::Result<()> {
     // 初始化日志
     init_logger("info").await?;
-    // 定义播放模式为列表循环播放
-    let play_mode = PlayMode::Normal;
-    // 定义初始播放索引为0
-    let initial_track_index = 0;
-    // 加载播放列表
-    load_playlist().await?;
+    // 启动时继续播放还是暂停
+    let resume_mode = resume_mode()?;
+    // 选择音频流的音质偏好
+    let preference = audio_preference()?;
+    tracing::info!("Audio quality preference: {}", preference);
+    set_audio_preference(preference);
+    // 响度均衡设置
+    let normalization = normalization()?;
+    tracing::info!(
+        "Loudness normalization: {} (target {} LUFS)",
+        normalization.mode,
+        normalization.target_lufs
+    );
+    set_normalization(normalization);
+    // 连接数据库并执行迁移
+    let pool = init_database(&database_url()?).await?;
+    let repository = MusicRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool.clone());
+    let sessions = SessionRepository::new(pool.clone());
+    let equalizers = EqualizerRepository::new(pool.clone());
+    let credentials = CredentialRepository::new(pool);
+    // 登录状态，优先使用 BILI_COOKIE_FILE 指定的凭据文件，否则使用上次登录保存的凭据
+    let auth = AuthSession::new(BILIBILI_API_BASE)?;
+    let client = auth.client()?;
+    let saved_credentials = match credentials_file() {
+        Some(path) => Some(Credentials::from_file(&path)?),
+        None => credentials.load().await?,
+    };
+    match saved_credentials {
+        Some(saved_credentials) => {
+            auth.login(saved_credentials);
+            tokio::task::spawn(report_login_status(client.clone()));
+        }
+        None => tracing::info!("Not logged in, using guest audio quality"),
+    }
+    // 从当前歌单加载播放列表
+    load_playlist(&playlists).await?;
+    // 读取上一次的播放会话，没有时使用默认值
+    let session = sessions
+        .load()
+        .await?
+        .map(PlaybackSession::from)
+        .unwrap_or_default();
+    tracing::info!("Restoring session: {:?}", session);
     // 创建播放命令发送和接收的通道
     let (player_command_send, player_command_recv) = mpsc::channel::<PlayerCommand>(1);
+    // 创建播放器事件的广播通道
+    let (player_event_send, _) = broadcast::channel::<PlayerEvent>(EVENT_CHANNEL_CAPACITY);
     // 创建播放服务
     let audio_player = AudioPlayer::new(
-        play_mode,
-        100,
-        initial_track_index,
+        &session,
+        repository.clone(),
+        playlists.clone(),
+        sessions,
+        client.clone(),
         Arc::new(Mutex::new(player_command_recv)),
+        player_event_send.clone(),
     )
     .await?;
     // 启动播放服务
     tokio::task::spawn({
         let audio_player = audio_player.clone();
         async move {
-            audio_player.play_playlist().await.unwrap();
+            // 播放列表为空等情况下播放器保持停止，命令仍然可以处理，添加歌曲后再播放
+            if let Err(e) = audio_player.play_playlist(resume_mode).await {
+                tracing::error!("Failed to start playback: {}", e);
+            }
         }
     });
     // grpc 服务地址
     let addr = "[::1]:50052".parse().unwrap();
     // 创建grpc服务
-    let svc = PlayerServer::new(player_command_send);
+    let svc = PlayerServer {
+        command_sender: player_command_send.clone(),
+        event_sender: player_event_send,
+        playlists,
+        repository,
+        equalizers,
+        client,
+        auth,
+        credentials,
+    };
     tracing::info!("UserServiceServer listening on {addr}");
-    // 启动服务
+    // 启动服务，收到 Ctrl+C 后停止
     Server::builder()
         .add_service(PlayerServiceServer::new(svc))
-        .serve(addr)
+        .serve_with_shutdown(addr, async {
+            tokio::signal::ctrl_c().await.ok();
+            tracing::info!("Shutdown signal received");
+        })
         .await?;
+    // 退出前保存播放会话
+    let (sender, receiver) = oneshot::channel();
+    if player_command_send
+        .send(PlayerCommand::Shutdown(sender))
+        .await
+        .is_ok()
+    {
+        match receiver.await {
+            Ok(Ok(())) => tracing::info!("Session saved"),
+            Ok(Err(e)) => tracing::error!("Failed to save session: {}", e),
+            Err(_) => tracing::error!("Player stopped before saving session"),
+        }
+    }
     Ok(())
 }
@@ -24,3 +24,11 @@ pub fn get_local_naive_datetime() -> NaiveDateTime {
     let now_with_tz = Utc::now().with_timezone(&east8); // 带时区的时间 DateTime<FixedOffset>
     now_with_tz.naive_local() // 转为本地时间的 NaiveDateTime
 }
+
+/// 当前的 Unix 时间戳 (秒)
+pub fn unix_now() -> u64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or_default()
+}
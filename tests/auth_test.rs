@@ -0,0 +1,191 @@
+mod common;
+
+use bili_player::{
+    errors::ApplicationError,
+    fetch::{
+        auth::{
+            AuthSession, Credentials, QrLoginState, fetch_login_status, poll_qr_login, render_qr,
+            start_qr_login,
+        },
+        network::fetch_favorite_page,
+    },
+    library::credential_repository::CredentialRepository,
+};
+use common::{memory_pool, ok};
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::json;
+
+fn credentials() -> Credentials {
+    Credentials {
+        sessdata: "abc%2C123".into(),
+        bili_jct: Some("csrf".into()),
+        dede_user_id: Some("42".into()),
+    }
+}
+
+// 模拟扫码状态接口，不同的 key 返回不同的状态
+async fn mock_qr_poll(server: &mut ServerGuard, key: &str, code: i64, url: &str) {
+    server
+        .mock("GET", "/x/passport-login/web/qrcode/poll")
+        .match_query(Matcher::UrlEncoded("qrcode_key".into(), key.into()))
+        .with_body(ok(json!({
+            "url": url,
+            "refresh_token": "",
+            "timestamp": 0,
+            "code": code,
+            "message": "",
+        })))
+        .create_async()
+        .await;
+}
+
+#[test]
+fn test_parse_credentials() {
+    let parsed: Credentials = "Cookie: buvid3=x; SESSDATA=abc%2C123; bili_jct=csrf; DedeUserID=42"
+        .parse()
+        .unwrap();
+    assert_eq!(parsed, credentials());
+    // 每行一个 key=value，没有 bili_jct 时也可以登录
+    let parsed: Credentials = "SESSDATA=abc\n\nDedeUserID=42\n".parse().unwrap();
+    assert_eq!(parsed.sessdata, "abc");
+    assert_eq!(parsed.bili_jct, None);
+    assert!(matches!(
+        "bili_jct=csrf".parse::<Credentials>(),
+        Err(ApplicationError::DataParsingError(_))
+    ));
+}
+
+#[test]
+fn test_credentials_from_file() {
+    let path = std::env::temp_dir().join(format!("bili_cookie_{}.txt", std::process::id()));
+    std::fs::write(&path, "SESSDATA=abc%2C123; bili_jct=csrf; DedeUserID=42\n").unwrap();
+    let loaded = Credentials::from_file(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(loaded, credentials());
+    assert!(matches!(
+        Credentials::from_file(&path),
+        Err(ApplicationError::IoError(_))
+    ));
+}
+
+#[tokio::test]
+async fn test_session_attaches_cookies() {
+    let mut server = Server::new_async().await;
+    let logged_in = server
+        .mock("GET", "/x/web-interface/nav")
+        .match_header(
+            "cookie",
+            Matcher::AllOf(vec![
+                Matcher::Regex("SESSDATA=abc%2C123".into()),
+                Matcher::Regex("bili_jct=csrf".into()),
+            ]),
+        )
+        .with_body(ok(
+            json!({ "isLogin": true, "mid": 42, "uname": "测试用户" }),
+        ))
+        .expect(1)
+        .create_async()
+        .await;
+    let guest = server
+        .mock("GET", "/x/web-interface/nav")
+        .match_header("cookie", Matcher::Missing)
+        .with_body(
+            json!({ "code": -101, "message": "账号未登录", "data": { "isLogin": false } })
+                .to_string(),
+        )
+        .expect(2)
+        .create_async()
+        .await;
+    let auth = AuthSession::new(&server.url()).unwrap();
+    let client = auth.client().unwrap();
+
+    let status = fetch_login_status(&client, &server.url()).await.unwrap();
+    assert!(!status.is_login);
+
+    // 登录后同一个 client 的请求带上 cookie
+    auth.login(credentials());
+    let status = fetch_login_status(&client, &server.url()).await.unwrap();
+    assert!(status.is_login);
+    assert_eq!(status.uname, "测试用户");
+    assert_eq!(auth.credentials(), Some(credentials()));
+
+    // 退出后不再带 cookie
+    auth.logout();
+    let status = fetch_login_status(&client, &server.url()).await.unwrap();
+    assert!(!status.is_login);
+    assert_eq!(auth.credentials(), None);
+    logged_in.assert_async().await;
+    guest.assert_async().await;
+}
+
+#[tokio::test]
+async fn test_expired_login_is_reported() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/x/v3/fav/resource/list")
+        .match_query(Matcher::Any)
+        .with_body(json!({ "code": -101, "message": "账号未登录", "data": null }).to_string())
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let result = fetch_favorite_page(&client, &server.url(), 1, 1).await;
+    assert!(matches!(result, Err(ApplicationError::AuthError(_))));
+}
+
+#[tokio::test]
+async fn test_qr_login() {
+    let mut server = Server::new_async().await;
+    server
+        .mock("GET", "/x/passport-login/web/qrcode/generate")
+        .with_body(ok(json!({
+            "url": "https://account.bilibili.com/h5/account-h5/auth/scan-web?qrcode_key=key01",
+            "qrcode_key": "key01",
+        })))
+        .create_async()
+        .await;
+    mock_qr_poll(&mut server, "waiting", 86101, "").await;
+    mock_qr_poll(&mut server, "scanned", 86090, "").await;
+    mock_qr_poll(&mut server, "expired", 86038, "").await;
+    mock_qr_poll(
+        &mut server,
+        "key01",
+        0,
+        "https://passport.biligame.com/x/passport-mng/crossDomain?DedeUserID=42&DedeUserID__ckMd5=x&Expires=1&SESSDATA=abc%2C123&bili_jct=csrf&gourl=https%3A%2F%2Fwww.bilibili.com",
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let qr_login = start_qr_login(&client, &server.url()).await.unwrap();
+    assert_eq!(qr_login.qrcode_key, "key01");
+    assert!(!render_qr(&qr_login.url).unwrap().trim().is_empty());
+
+    let poll = |key: &'static str| {
+        let client = client.clone();
+        let url = server.url();
+        async move { poll_qr_login(&client, &url, key).await.unwrap() }
+    };
+    assert_eq!(poll("waiting").await, QrLoginState::Waiting);
+    assert_eq!(poll("scanned").await, QrLoginState::Scanned);
+    assert_eq!(poll("expired").await, QrLoginState::Expired);
+    // SESSDATA 保持编码后的形式
+    let QrLoginState::Confirmed(confirmed) = poll("key01").await else {
+        panic!("login should be confirmed");
+    };
+    assert_eq!(confirmed.sessdata, "abc%2C123");
+    assert_eq!(confirmed.bili_jct.as_deref(), Some("csrf"));
+    assert_eq!(confirmed.dede_user_id.as_deref(), Some("42"));
+}
+
+#[tokio::test]
+async fn test_credential_repository() {
+    let repository = CredentialRepository::new(memory_pool().await);
+    assert_eq!(repository.load().await.unwrap(), None);
+    repository.save(&credentials()).await.unwrap();
+    let updated = Credentials {
+        sessdata: "new".into(),
+        ..credentials()
+    };
+    repository.save(&updated).await.unwrap();
+    assert_eq!(repository.load().await.unwrap(), Some(updated));
+    repository.clear().await.unwrap();
+    assert_eq!(repository.load().await.unwrap(), None);
+}
@@ -0,0 +1,40 @@
+use bili_player::{errors::ApplicationError, pb::SeekRequest, player::command::SeekTarget};
+
+#[test]
+fn test_parse_seek_target() {
+    assert_eq!("83".parse(), Ok(SeekTarget::Absolute(83.0)));
+    assert_eq!("1:23".parse(), Ok(SeekTarget::Absolute(83.0)));
+    assert_eq!("1:02:03".parse(), Ok(SeekTarget::Absolute(3723.0)));
+    assert_eq!("+10".parse(), Ok(SeekTarget::Relative(10.0)));
+    assert_eq!("-5".parse(), Ok(SeekTarget::Relative(-5.0)));
+    assert_eq!("+1:00".parse(), Ok(SeekTarget::Relative(60.0)));
+    assert_eq!("2.5".parse(), Ok(SeekTarget::Absolute(2.5)));
+
+    assert!("".parse::<SeekTarget>().is_err());
+    assert!("abc".parse::<SeekTarget>().is_err());
+    assert!("1:2:3:4".parse::<SeekTarget>().is_err());
+    assert!("--5".parse::<SeekTarget>().is_err());
+}
+
+#[test]
+fn test_seek_request_to_target() {
+    let request = SeekRequest {
+        position: -5.0,
+        relative: true,
+    };
+    assert_eq!(
+        SeekTarget::try_from(request).unwrap(),
+        SeekTarget::Relative(-5.0)
+    );
+
+    for position in [f64::NAN, f64::INFINITY, f64::NEG_INFINITY] {
+        let request = SeekRequest {
+            position,
+            relative: false,
+        };
+        assert!(matches!(
+            SeekTarget::try_from(request),
+            Err(ApplicationError::InvalidArgumentError(_))
+        ));
+    }
+}
@@ -0,0 +1,41 @@
+// 各个测试文件共用的辅助函数，不是每个测试文件都会用到全部
+#![allow(dead_code)]
+
+use bili_player::library::database::MIGRATOR;
+use mockito::ServerGuard;
+use serde_json::{Value, json};
+use sqlx::{SqlitePool, sqlite::SqlitePoolOptions};
+
+/// 创建一个已经执行过迁移的内存数据库
+pub async fn memory_pool() -> SqlitePool {
+    // 内存数据库每个连接都是独立的，只保留一个连接
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite::memory:")
+        .await
+        .unwrap();
+    MIGRATOR.run(&pool).await.unwrap();
+    pool
+}
+
+/// 成功的接口响应
+pub fn ok(data: Value) -> String {
+    json!({ "code": 0, "message": "0", "data": data }).to_string()
+}
+
+/// 模拟 nav 接口返回的 WBI 密钥，需要签名的接口会先请求它
+///
+/// 密钥按接口地址缓存，复用的模拟服务器不一定会再次请求，所以不检查请求次数
+pub async fn mock_wbi_keys(server: &mut ServerGuard) {
+    server
+        .mock("GET", "/x/web-interface/nav")
+        .with_body(
+            json!({ "code": -101, "message": "账号未登录", "data": { "isLogin": false, "wbi_img": {
+                "img_url": "https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png",
+                "sub_url": "https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png",
+            } } })
+            .to_string(),
+        )
+        .create_async()
+        .await;
+}
@@ -0,0 +1,22 @@
+use std::time::Duration;
+
+use bili_player::{
+    errors::ApplicationError,
+    player::crossfade::{Crossfade, MAX_CROSSFADE_SECONDS},
+};
+
+#[test]
+fn test_crossfade_range() {
+    assert!(matches!(
+        Crossfade::new(MAX_CROSSFADE_SECONDS + 1),
+        Err(ApplicationError::InvalidArgumentError(_))
+    ));
+    let crossfade = Crossfade::new(0).unwrap();
+    assert!(crossfade.duration().is_zero());
+
+    assert_eq!(crossfade.set(5).unwrap(), 5);
+    assert_eq!(crossfade.duration(), Duration::from_secs(5));
+    // 超出范围时保持原来的值
+    assert!(crossfade.set(13).is_err());
+    assert_eq!(crossfade.seconds(), 5);
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use bili_player::{
+    library::equalizer_repository::EqualizerRepository,
+    player::equalizer::{
+        BAND_COUNT, Equalizer, EqualizerSettings, band_gains, format_gains, parse_gains,
+    },
+};
+use common::memory_pool;
+
+async fn memory_equalizers() -> EqualizerRepository {
+    EqualizerRepository::new(memory_pool().await)
+}
+
+#[test]
+fn test_equalizer_bands() {
+    let equalizer = Equalizer::default();
+    assert!(equalizer.settings().is_flat());
+    assert_eq!(equalizer.settings().label(), "flat");
+
+    let settings = equalizer.set_band(0, 6.0).unwrap();
+    assert_eq!(settings.gains[0], 6.0);
+    assert_eq!(settings.label(), "自定义");
+    // 超出范围的频段和增益
+    assert!(equalizer.set_band(BAND_COUNT, 0.0).is_err());
+    assert!(equalizer.set_band(1, 12.5).is_err());
+    assert!(equalizer.set_band(1, -30.0).is_err());
+    assert_eq!(equalizer.settings(), settings);
+
+    // 应用预设后显示预设名称，再调整频段时变为自定义
+    let preset = EqualizerSettings {
+        gains: [1.0; BAND_COUNT],
+        preset: Some("vocal".into()),
+    };
+    assert_eq!(equalizer.set(preset.clone()).unwrap(), preset);
+    assert_eq!(equalizer.settings().label(), "vocal");
+    assert_eq!(equalizer.set_band(9, -3.0).unwrap().preset, None);
+}
+
+#[test]
+fn test_parse_gains() {
+    let gains = [6.0, 4.5, 2.0, 0.0, 0.0, 0.0, -1.0, -2.0, -3.5, -24.0];
+    assert_eq!(parse_gains(&format_gains(&gains)).unwrap(), gains);
+    assert_eq!(parse_gains("").unwrap(), [0.0; BAND_COUNT]);
+    assert_eq!(parse_gains(" 1, 2,3,4,5,6,7,8,9,10 ").unwrap()[1], 2.0);
+    assert!(parse_gains("1,2,3").is_err());
+    assert!(parse_gains("a,0,0,0,0,0,0,0,0,0").is_err());
+    assert!(parse_gains("13,0,0,0,0,0,0,0,0,0").is_err());
+
+    assert_eq!(band_gains(&gains).unwrap(), gains);
+    assert!(band_gains(&[0.0; 3]).is_err());
+}
+
+#[tokio::test]
+async fn test_presets() {
+    let equalizers = memory_equalizers().await;
+    // 内置预设
+    let names: Vec<_> = equalizers
+        .list()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|preset| preset.name)
+        .collect();
+    assert_eq!(names, ["flat", "bass-boost", "vocal", "classical"]);
+    let bass = equalizers.get_by_name("bass-boost").await.unwrap();
+    assert!(bass.is_builtin);
+    assert!(bass.band_gains().unwrap()[0] > 0.0);
+
+    // 保存自定义预设，同名时覆盖
+    let gains = [3.0; BAND_COUNT];
+    let record = equalizers.save(" mine ", &gains).await.unwrap();
+    assert_eq!(record.name, "mine");
+    assert!(!record.is_builtin);
+    let record = equalizers.save("mine", &[-3.0; BAND_COUNT]).await.unwrap();
+    assert_eq!(record.band_gains().unwrap(), [-3.0; BAND_COUNT]);
+    assert_eq!(equalizers.list().await.unwrap().len(), 5);
+
+    // 内置预设不能修改和删除
+    assert!(equalizers.save("flat", &gains).await.is_err());
+    assert!(equalizers.delete("flat").await.is_err());
+    assert!(equalizers.save("  ", &gains).await.is_err());
+
+    assert_eq!(equalizers.delete("mine").await.unwrap().name, "mine");
+    assert!(equalizers.find_by_name("mine").await.unwrap().is_none());
+    assert!(equalizers.delete("mine").await.is_err());
+}
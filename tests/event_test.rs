@@ -0,0 +1,30 @@
+use bili_player::{
+    pb::{self, SubscribeEventsResponse, subscribe_events_response::Event},
+    player::{event::PlayerEvent, state::PlaybackState},
+};
+
+#[test]
+fn test_event_into_response() {
+    let response = SubscribeEventsResponse::from(PlayerEvent::StateChanged {
+        state: PlaybackState::Buffering,
+        buffering_percent: Some(42),
+    });
+    assert!(response.timestamp > 0);
+    let Some(Event::StateChanged(event)) = response.event else {
+        panic!("unexpected event: {:?}", response.event);
+    };
+    assert_eq!(event.state(), pb::PlaybackState::Buffering);
+    assert_eq!(event.buffering_percent, Some(42));
+
+    let response = SubscribeEventsResponse::from(PlayerEvent::VolumeChanged {
+        volume: 30,
+        muted: true,
+    });
+    assert_eq!(
+        response.event,
+        Some(Event::VolumeChanged(pb::VolumeChangedEvent {
+            volume: 30,
+            muted: true
+        }))
+    );
+}
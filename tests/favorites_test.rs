@@ -0,0 +1,197 @@
+mod common;
+
+use bili_player::{
+    errors::ApplicationError,
+    fetch::network::{fetch_favorite_folder, fetch_video_data},
+    library::{
+        import::import_favorite_folder, playlist_repository::PlaylistRepository,
+        repository::MusicRepository,
+    },
+};
+use common::{memory_pool, mock_wbi_keys, ok};
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::{Value, json};
+
+const MEDIA_ID: i64 = 1234;
+
+fn media(bvid: &str, title: &str, attr: i64) -> Value {
+    json!({
+        "id": bvid.len() as i64,
+        "type": 2,
+        "title": title,
+        "bvid": bvid,
+        "upper": { "name": "up主" },
+        "duration": 200,
+        "attr": attr,
+    })
+}
+
+// 模拟收藏夹接口，每一项为一页
+async fn mock_favorite_pages(server: &mut ServerGuard, pages: &[Vec<Value>]) {
+    for (index, medias) in pages.iter().enumerate() {
+        let data = json!({
+            "info": {
+                "id": MEDIA_ID,
+                "title": "我的收藏",
+                "media_count": 3,
+                "upper": { "name": "tester" },
+            },
+            "medias": medias,
+            "has_more": index + 1 < pages.len(),
+        });
+        server
+            .mock("GET", "/x/v3/fav/resource/list")
+            .match_query(Matcher::AllOf(vec![
+                Matcher::UrlEncoded("media_id".into(), MEDIA_ID.to_string()),
+                Matcher::UrlEncoded("pn".into(), (index + 1).to_string()),
+            ]))
+            .with_body(ok(data))
+            .create_async()
+            .await;
+    }
+}
+
+// 模拟视频信息接口
+async fn mock_video(server: &mut ServerGuard, bvid: &str, title: &str, cid: i64) {
+    let data = json!({
+        "bvid": bvid,
+        "title": title,
+        "cid": cid,
+        "owner": { "name": "up主" },
+        "pages": [{ "cid": cid, "page": 1, "part": title, "duration": 200 }],
+    });
+    server
+        .mock("GET", "/x/web-interface/wbi/view")
+        .match_query(Matcher::UrlEncoded("bvid".into(), bvid.into()))
+        .with_body(ok(data))
+        .create_async()
+        .await;
+}
+
+#[tokio::test]
+async fn test_fetch_favorite_folder_pages() {
+    let mut server = Server::new_async().await;
+    mock_favorite_pages(
+        &mut server,
+        &[
+            vec![media("BVfav0001", "一", 0), media("BVfav0002", "二", 9)],
+            vec![media("BVfav0003", "三", 0)],
+        ],
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let folder = fetch_favorite_folder(&client, &server.url(), MEDIA_ID)
+        .await
+        .unwrap();
+    assert_eq!(folder.info.title, "我的收藏");
+    let bvids: Vec<_> = folder.medias.iter().map(|m| m.bvid.as_str()).collect();
+    assert_eq!(bvids, ["BVfav0001", "BVfav0002", "BVfav0003"]);
+    assert!(!folder.medias[1].is_available());
+}
+
+#[tokio::test]
+async fn test_fetch_error_code() {
+    let mut server = Server::new_async().await;
+    mock_wbi_keys(&mut server).await;
+    server
+        .mock("GET", "/x/web-interface/wbi/view")
+        .match_query(Matcher::Any)
+        .with_body(json!({ "code": -404, "message": "啥都木有", "data": null }).to_string())
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let result = fetch_video_data(&client, &server.url(), "BVmissing").await;
+    assert!(matches!(result, Err(ApplicationError::NotFoundError(_))));
+}
+
+#[tokio::test]
+async fn test_import_favorite_folder_twice() {
+    let pool = memory_pool().await;
+    let repository = MusicRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool);
+    let client = reqwest::Client::new();
+
+    // 第一次导入：两个可用视频，一个失效视频
+    let mut server = Server::new_async().await;
+    mock_wbi_keys(&mut server).await;
+    mock_favorite_pages(
+        &mut server,
+        &[
+            vec![media("BVfav0001", "一", 0), media("BVfav0002", "二", 0)],
+            vec![media("BVfav0003", "三", 9)],
+        ],
+    )
+    .await;
+    mock_video(&mut server, "BVfav0001", "一", 101).await;
+    mock_video(&mut server, "BVfav0002", "二", 102).await;
+    let report = import_favorite_folder(
+        &client,
+        &server.url(),
+        &repository,
+        &playlists,
+        MEDIA_ID,
+        None,
+    )
+    .await
+    .unwrap();
+    assert_eq!(report.playlist.name, "我的收藏");
+    assert_eq!(report.playlist.track_count, 2);
+    assert_eq!(report.added.len(), 2);
+    assert_eq!(report.added[0].cid, "101");
+    assert_eq!(report.existing, 0);
+    assert_eq!(report.unavailable.len(), 1);
+    assert!(report.removed_upstream.is_empty());
+
+    // 第二次导入：收藏夹移除了一个视频，新增了一个视频，获取信息失败的视频单独报告
+    let mut server = Server::new_async().await;
+    mock_wbi_keys(&mut server).await;
+    mock_favorite_pages(
+        &mut server,
+        &[vec![
+            media("BVfav0002", "二", 0),
+            media("BVfav0004", "四", 0),
+            media("BVfav0005", "五", 0),
+        ]],
+    )
+    .await;
+    mock_video(&mut server, "BVfav0004", "四", 104).await;
+    server
+        .mock("GET", "/x/web-interface/wbi/view")
+        .match_query(Matcher::UrlEncoded("bvid".into(), "BVfav0005".into()))
+        .with_body(json!({ "code": 62002, "message": "稿件不可见", "data": null }).to_string())
+        .create_async()
+        .await;
+    // 自定义名称不影响已关联的歌单
+    let report = import_favorite_folder(
+        &client,
+        &server.url(),
+        &repository,
+        &playlists,
+        MEDIA_ID,
+        Some("其他名称"),
+    )
+    .await
+    .unwrap();
+    assert_eq!(report.playlist.name, "我的收藏");
+    assert_eq!(report.existing, 1);
+    let added: Vec<_> = report.added.iter().map(|m| m.bvid.as_str()).collect();
+    assert_eq!(added, ["BVfav0004"]);
+    let removed: Vec<_> = report
+        .removed_upstream
+        .iter()
+        .map(|m| m.bvid.as_str())
+        .collect();
+    assert_eq!(removed, ["BVfav0001"]);
+    assert_eq!(report.failed.len(), 1);
+    // 收藏夹中已移除的歌曲只报告，不从歌单中移除
+    assert_eq!(report.playlist.track_count, 3);
+    let record = playlists.find_by_favorite(MEDIA_ID).await.unwrap().unwrap();
+    let bvids: Vec<_> = playlists
+        .musics(record.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|m| m.bvid)
+        .collect();
+    assert_eq!(bvids, ["BVfav0001", "BVfav0002", "BVfav0004"]);
+}
@@ -0,0 +1,49 @@
+use bili_player::{fetch::network::VideoPage, library::import::PageSelection};
+
+fn parts(count: u32) -> Vec<VideoPage> {
+    (1..=count)
+        .map(|page| VideoPage {
+            cid: 1000 + page as i64,
+            page,
+            part: format!("P{page}"),
+            duration: 180,
+        })
+        .collect()
+}
+
+#[test]
+fn test_parse_page_selection() {
+    assert_eq!("".parse::<PageSelection>().unwrap(), PageSelection::First);
+    assert_eq!("all".parse::<PageSelection>().unwrap(), PageSelection::All);
+    assert_eq!(
+        "3".parse::<PageSelection>().unwrap(),
+        PageSelection::Single(3)
+    );
+    assert_eq!(
+        " 2-5 ".parse::<PageSelection>().unwrap(),
+        PageSelection::Range(2, 5)
+    );
+    assert!("0".parse::<PageSelection>().is_err());
+    assert!("5-2".parse::<PageSelection>().is_err());
+    assert!("abc".parse::<PageSelection>().is_err());
+}
+
+#[test]
+fn test_select_pages() {
+    let parts = parts(4);
+    let cids = |selection: PageSelection| -> Vec<i64> {
+        selection
+            .select(&parts)
+            .unwrap()
+            .into_iter()
+            .map(|part| part.cid)
+            .collect()
+    };
+    assert_eq!(cids(PageSelection::First), [1001]);
+    assert_eq!(cids(PageSelection::Single(3)), [1003]);
+    assert_eq!(cids(PageSelection::Range(2, 9)), [1002, 1003, 1004]);
+    assert_eq!(cids(PageSelection::All), [1001, 1002, 1003, 1004]);
+    // 超出范围时返回错误
+    assert!(PageSelection::Single(5).select(&parts).is_err());
+    assert!(PageSelection::Range(5, 6).select(&parts).is_err());
+}
@@ -0,0 +1,398 @@
+mod common;
+
+use bili_player::{
+    library::{
+        playlist_repository::PlaylistRepository,
+        repository::{MusicQuery, MusicRepository},
+    },
+    player::{loudness::Loudness, state::Music},
+};
+use common::memory_pool;
+
+async fn memory_repository() -> MusicRepository {
+    MusicRepository::new(memory_pool().await)
+}
+
+fn music(bvid: &str, title: &str) -> Music {
+    Music {
+        bvid: bvid.into(),
+        cid: "100".into(),
+        title: title.into(),
+        owner: "tester".into(),
+    }
+}
+
+#[tokio::test]
+async fn test_create_and_find() {
+    let repository = memory_repository().await;
+    let record = repository
+        .create(&music("BVtest0001", "测试"), 1)
+        .await
+        .unwrap();
+    assert_eq!(record.song_name, "测试");
+    assert!(!record.is_deleted);
+
+    let found = repository.find("BVtest0001", "100").await.unwrap();
+    assert_eq!(found, Some(record.clone()));
+    assert_eq!(
+        repository.find_by_bvid("BVtest0001").await.unwrap(),
+        vec![record]
+    );
+    assert!(
+        repository
+            .find_by_bvid("BVmissing")
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    // (bvid, cid) 唯一
+    assert!(
+        repository
+            .create(&music("BVtest0001", "重复"), 1)
+            .await
+            .is_err()
+    );
+}
+
+#[tokio::test]
+async fn test_update_and_soft_delete() {
+    let repository = memory_repository().await;
+    repository
+        .create(&music("BVtest0002", "旧标题"), 1)
+        .await
+        .unwrap();
+    assert!(
+        repository
+            .update(&music("BVtest0002", "新标题"))
+            .await
+            .unwrap()
+    );
+    let record = repository.find("BVtest0002", "100").await.unwrap().unwrap();
+    assert_eq!(record.song_name, "新标题");
+
+    assert_eq!(
+        repository
+            .soft_delete("BVtest0002", None)
+            .await
+            .unwrap()
+            .len(),
+        1
+    );
+    assert!(
+        repository
+            .soft_delete("BVtest0002", None)
+            .await
+            .unwrap()
+            .is_empty()
+    );
+    let listed = repository.list().await.unwrap();
+    assert!(listed.iter().all(|r| r.bvid != "BVtest0002"));
+    // 软删除的记录仍然保留
+    let record = repository.find("BVtest0002", "100").await.unwrap().unwrap();
+    assert!(record.is_deleted);
+}
+
+#[tokio::test]
+async fn test_list_keeps_insert_order() {
+    let repository = memory_repository().await;
+    let before = repository.list().await.unwrap().len();
+    repository
+        .create(&music("BVtest0003", "a"), 1)
+        .await
+        .unwrap();
+    repository
+        .create(&music("BVtest0004", "b"), 1)
+        .await
+        .unwrap();
+    let listed = repository.list().await.unwrap();
+    assert_eq!(listed.len(), before + 2);
+    let bvids: Vec<_> = listed[before..].iter().map(|r| r.bvid.as_str()).collect();
+    assert_eq!(bvids, ["BVtest0003", "BVtest0004"]);
+}
+
+#[tokio::test]
+async fn test_save_loudness() {
+    let repository = memory_repository().await;
+    let song = music("BVtest0005", "响度");
+    let record = repository.create(&song, 1).await.unwrap();
+    assert_eq!(record.loudness(), None);
+
+    let loudness = Loudness {
+        integrated: -9.5,
+        peak: 0.98,
+    };
+    assert!(repository.save_loudness(&song, &loudness).await.unwrap());
+    let record = repository.find("BVtest0005", "100").await.unwrap().unwrap();
+    assert_eq!(record.loudness(), Some(loudness));
+    let measured = repository.measured_loudness().await.unwrap();
+    assert_eq!(measured.len(), 1);
+    assert_eq!(measured[0].bvid, "BVtest0005");
+    // 不在音乐库中的歌曲
+    assert!(
+        !repository
+            .save_loudness(&music("BVmissing", "x"), &loudness)
+            .await
+            .unwrap()
+    );
+}
+
+#[tokio::test]
+async fn test_default_playlist() {
+    let playlists = PlaylistRepository::new(memory_pool().await);
+    let active = playlists.active().await.unwrap().unwrap();
+    assert_eq!(active.name, "默认");
+    // 迁移时已有的音乐都放入默认歌单
+    let summaries = playlists.list().await.unwrap();
+    assert_eq!(summaries.len(), 1);
+    assert_eq!(summaries[0].track_count, 4);
+}
+
+#[tokio::test]
+async fn test_playlist_crud_and_switch() {
+    let playlists = PlaylistRepository::new(memory_pool().await);
+    let created = playlists.create("通勤").await.unwrap();
+    assert!(!created.is_active);
+    assert!(playlists.create(" 通勤 ").await.is_err());
+    assert!(playlists.create("  ").await.is_err());
+
+    let renamed = playlists.rename("通勤", "工作").await.unwrap();
+    assert_eq!(renamed.id, created.id);
+    assert!(playlists.find_by_name("通勤").await.unwrap().is_none());
+    assert!(playlists.rename("工作", "默认").await.is_err());
+
+    let switched = playlists.set_active("工作").await.unwrap();
+    assert!(switched.is_active);
+    let active = playlists.active().await.unwrap().unwrap();
+    assert_eq!(active.id, created.id);
+    // 正在播放的歌单不能删除
+    assert!(playlists.delete("工作").await.is_err());
+    playlists.delete("默认").await.unwrap();
+    assert_eq!(playlists.list().await.unwrap().len(), 1);
+}
+
+#[tokio::test]
+async fn test_playlist_items_order() {
+    let pool = memory_pool().await;
+    let repository = MusicRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool);
+    let playlist = playlists.create("测试").await.unwrap();
+    let first = repository
+        .create(&music("BVtest0001", "第一首"), 1)
+        .await
+        .unwrap();
+    let second = repository
+        .create(&music("BVtest0002", "第二首"), 1)
+        .await
+        .unwrap();
+
+    assert!(playlists.add_music(playlist.id, second.id).await.unwrap());
+    assert!(playlists.add_music(playlist.id, first.id).await.unwrap());
+    // 重复添加不会产生新的条目
+    assert!(!playlists.add_music(playlist.id, first.id).await.unwrap());
+    let bvids: Vec<_> = playlists
+        .musics(playlist.id)
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|record| record.bvid)
+        .collect();
+    assert_eq!(bvids, ["BVtest0002", "BVtest0001"]);
+
+    // 软删除的音乐不出现在歌单中，恢复后回到原来的位置
+    repository.soft_delete("BVtest0002", None).await.unwrap();
+    assert_eq!(playlists.musics(playlist.id).await.unwrap().len(), 1);
+    assert_eq!(playlists.summary("测试").await.unwrap().track_count, 1);
+    repository.restore("BVtest0002", None).await.unwrap();
+    assert_eq!(
+        playlists.musics(playlist.id).await.unwrap()[0].id,
+        second.id
+    );
+
+    assert!(
+        playlists
+            .remove_music(playlist.id, second.id)
+            .await
+            .unwrap()
+    );
+    assert!(
+        !playlists
+            .remove_music(playlist.id, second.id)
+            .await
+            .unwrap()
+    );
+    assert!(!playlists.contains(playlist.id, second.id).await.unwrap());
+    assert!(playlists.contains(playlist.id, first.id).await.unwrap());
+}
+
+#[tokio::test]
+async fn test_multi_part_identity() {
+    let repository = memory_repository().await;
+    let part = |cid: &str, title: &str| Music {
+        cid: cid.into(),
+        ..music("BVtest0005", title)
+    };
+    repository.create(&part("501", "第一P"), 1).await.unwrap();
+    repository.create(&part("502", "第二P"), 2).await.unwrap();
+    repository.create(&part("503", "第三P"), 3).await.unwrap();
+    // 同一个视频的不同分P可以共存，同一P不能重复
+    assert!(repository.create(&part("502", "重复"), 2).await.is_err());
+    let pages: Vec<_> = repository
+        .find_by_bvid("BVtest0005")
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|record| record.page)
+        .collect();
+    assert_eq!(pages, [1, 2, 3]);
+
+    // 只删除指定的分P
+    let deleted = repository
+        .soft_delete("BVtest0005", Some("502"))
+        .await
+        .unwrap();
+    assert_eq!(deleted.len(), 1);
+    assert_eq!(deleted[0].song_name, "第二P");
+    // 删除整个视频时只影响未删除的分P
+    let deleted = repository.soft_delete("BVtest0005", None).await.unwrap();
+    assert_eq!(deleted.len(), 2);
+    let restored = repository.restore("BVtest0005", None).await.unwrap();
+    let titles: Vec<_> = restored.iter().map(|r| r.song_name.as_str()).collect();
+    assert_eq!(titles, ["第一P", "第二P", "第三P"]);
+}
+
+#[tokio::test]
+async fn test_search_filters() {
+    let pool = memory_pool().await;
+    let repository = MusicRepository::new(pool.clone());
+    let playlists = PlaylistRepository::new(pool);
+    let owned = |bvid: &str, title: &str, owner: &str| Music {
+        owner: owner.into(),
+        ..music(bvid, title)
+    };
+    let first = repository
+        .create(&owned("BVfind0001", "Hello World", "Alice"), 1)
+        .await
+        .unwrap();
+    repository
+        .create(&owned("BVfind0002", "hello_kitty 100%", "Bob"), 1)
+        .await
+        .unwrap();
+    repository
+        .create(&owned("BVfind0003", "Goodbye", "alice"), 1)
+        .await
+        .unwrap();
+    let active = playlists.active().await.unwrap().unwrap();
+    playlists.add_music(active.id, first.id).await.unwrap();
+    let search = |query: MusicQuery| {
+        let repository = repository.clone();
+        async move {
+            repository
+                .search(&query)
+                .await
+                .unwrap()
+                .into_iter()
+                .map(|found| (found.record.bvid, found.in_active_playlist))
+                .collect::<Vec<_>>()
+        }
+    };
+
+    // 不区分大小写的子串匹配
+    let found = search(MusicQuery {
+        title: Some("HELLO".into()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(
+        found,
+        [("BVfind0001".into(), true), ("BVfind0002".into(), false)]
+    );
+    // 多个条件同时满足
+    let found = search(MusicQuery {
+        title: Some("o".into()),
+        owner: Some("ALICE".into()),
+        bvid: Some("find".into()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(found.len(), 2);
+    // % 和 _ 按字面匹配
+    let found = search(MusicQuery {
+        title: Some("_kitty 100%".into()),
+        ..Default::default()
+    })
+    .await;
+    assert_eq!(found, [("BVfind0002".into(), false)]);
+    let found = search(MusicQuery {
+        title: Some("o_d".into()),
+        ..Default::default()
+    })
+    .await;
+    assert!(found.is_empty());
+    // 默认不包含已删除的歌曲
+    repository.soft_delete("BVfind0003", None).await.unwrap();
+    let query = MusicQuery {
+        owner: Some("alice".into()),
+        ..Default::default()
+    };
+    assert_eq!(search(query.clone()).await.len(), 1);
+    let found = search(MusicQuery {
+        include_deleted: true,
+        ..query
+    })
+    .await;
+    assert_eq!(found.len(), 2);
+}
+
+#[tokio::test]
+async fn test_search_full_text() {
+    let repository = memory_repository().await;
+    repository
+        .create(&music("BVfts00001", "【钢琴】周杰伦 晴天 完整版"), 1)
+        .await
+        .unwrap();
+    repository
+        .create(&music("BVfts00002", "晴天娃娃 儿歌"), 1)
+        .await
+        .unwrap();
+    let full_text = |title: &str| MusicQuery {
+        title: Some(title.into()),
+        ..Default::default()
+    };
+    let bvids = |found: Vec<bili_player::library::repository::FoundMusicRecord>| {
+        found
+            .into_iter()
+            .map(|found| found.record.bvid)
+            .collect::<Vec<_>>()
+    };
+    let found = repository.search(&full_text("周杰伦 晴天")).await.unwrap();
+    assert_eq!(bvids(found), ["BVfts00001"]);
+    // 少于 3 个字符时按子串匹配
+    let found = repository.search(&full_text("晴天")).await.unwrap();
+    assert_eq!(bvids(found), ["BVfts00001", "BVfts00002"]);
+    // FTS5 语法字符按字面处理
+    let found = repository.search(&full_text("\"AND*")).await.unwrap();
+    assert!(found.is_empty());
+    // 只有作者包含关键字时不匹配
+    let mut by_author = music("BVfts00003", "稻香");
+    by_author.owner = "周杰伦 晴天".into();
+    repository.create(&by_author, 1).await.unwrap();
+    let found = repository.search(&full_text("周杰伦 晴天")).await.unwrap();
+    assert_eq!(bvids(found), ["BVfts00001"]);
+    // 作者同样通过全文索引匹配
+    let by_owner = MusicQuery {
+        owner: Some("周杰伦".into()),
+        ..Default::default()
+    };
+    let found = repository.search(&by_owner).await.unwrap();
+    assert_eq!(bvids(found), ["BVfts00003"]);
+
+    // 修改标题后索引同步更新
+    repository
+        .update(&music("BVfts00002", "七里香 翻唱"))
+        .await
+        .unwrap();
+    let found = repository.search(&full_text("晴天娃娃")).await.unwrap();
+    assert!(found.is_empty());
+    let found = repository.search(&full_text("七里香")).await.unwrap();
+    assert_eq!(bvids(found), ["BVfts00002"]);
+}
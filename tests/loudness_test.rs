@@ -0,0 +1,115 @@
+use std::f64::consts::PI;
+
+use bili_player::player::loudness::{
+    Loudness, LoudnessMeter, Normalization, NormalizationMode, average_loudness,
+};
+
+const RATE: u32 = 48000;
+
+// 生成交错排列的立体声正弦波
+fn sine(frequency: f64, amplitude: f64, seconds: f64) -> Vec<f32> {
+    let frames = (RATE as f64 * seconds) as usize;
+    (0..frames)
+        .flat_map(|i| {
+            let sample = (amplitude * (2.0 * PI * frequency * i as f64 / RATE as f64).sin()) as f32;
+            [sample, sample]
+        })
+        .collect()
+}
+
+fn measure(samples: &[f32]) -> Option<Loudness> {
+    let mut meter = LoudnessMeter::new(RATE, 2);
+    // 分多次输入，与一次输入的结果相同
+    for chunk in samples.chunks(4410) {
+        meter.push(chunk);
+    }
+    meter.finish()
+}
+
+#[test]
+fn test_meter_sine() {
+    // EBU Tech 3341：-23 dBFS 的 1kHz 立体声正弦波响度为 -23 LUFS
+    let amplitude = 10f64.powf(-23.0 / 20.0);
+    let loudness = measure(&sine(1000.0, amplitude, 20.0)).unwrap();
+    assert!((loudness.integrated + 23.0).abs() < 0.1, "{loudness:?}");
+    assert!((loudness.peak - amplitude).abs() < 1e-3);
+
+    // 不足一个测量块
+    assert!(measure(&sine(1000.0, amplitude, 0.3)).is_none());
+    // 静音不计入
+    assert!(measure(&vec![0.0; RATE as usize * 4]).is_none());
+}
+
+#[test]
+fn test_meter_gating() {
+    // 安静的部分低于相对门限，不拉低整体响度
+    let loud = 10f64.powf(-20.0 / 20.0);
+    let quiet = 10f64.powf(-50.0 / 20.0);
+    let mut samples = sine(1000.0, loud, 10.0);
+    samples.extend(sine(1000.0, quiet, 10.0));
+    let loudness = measure(&samples).unwrap();
+    assert!((loudness.integrated + 20.0).abs() < 0.2, "{loudness:?}");
+}
+
+#[test]
+fn test_normalization_gain() {
+    let track = Some(Loudness {
+        integrated: -8.0,
+        peak: 1.0,
+    });
+    let off = Normalization::default();
+    assert_eq!(off.gain(track, Some(-20.0)), 1.0);
+
+    let normalization = Normalization {
+        mode: NormalizationMode::Track,
+        target_lufs: -14.0,
+    };
+    // 降低 6 dB
+    assert!((normalization.gain(track, Some(-20.0)) - 0.501).abs() < 1e-3);
+    // 没有测量过时不调整
+    assert_eq!(normalization.gain(None, None), 1.0);
+    // 提升不超过 12 dB
+    let quiet = Some(Loudness {
+        integrated: -40.0,
+        peak: 0.1,
+    });
+    assert!((normalization.gain(quiet, None) - 3.981).abs() < 1e-3);
+
+    let album = Normalization {
+        mode: NormalizationMode::Album,
+        ..normalization
+    };
+    // 使用歌单的整体响度，没有时退回到歌曲的响度
+    assert!((album.gain(track, Some(-20.0)) - 1.995).abs() < 1e-3);
+    assert_eq!(album.gain(track, None), normalization.gain(track, None));
+}
+
+#[test]
+fn test_average_loudness() {
+    assert_eq!(average_loudness(&[]), None);
+    assert!((average_loudness(&[-14.0, -14.0]).unwrap() + 14.0).abs() < 1e-9);
+    // 按能量平均，响的歌曲占主导
+    assert!((average_loudness(&[-10.0, -30.0]).unwrap() + 12.97).abs() < 0.01);
+}
+
+#[test]
+fn test_normalization_mode() {
+    assert_eq!(
+        "track".parse::<NormalizationMode>().unwrap(),
+        NormalizationMode::Track
+    );
+    assert_eq!(
+        "Album".parse::<NormalizationMode>().unwrap(),
+        NormalizationMode::Album
+    );
+    assert_eq!(
+        "playlist".parse::<NormalizationMode>().unwrap(),
+        NormalizationMode::Album
+    );
+    assert_eq!(
+        "off".parse::<NormalizationMode>().unwrap(),
+        NormalizationMode::Off
+    );
+    assert!("loud".parse::<NormalizationMode>().is_err());
+    assert_eq!(NormalizationMode::Album.to_string(), "album");
+}
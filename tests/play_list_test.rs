@@ -0,0 +1,104 @@
+use bili_player::player::{
+    command::PlayMode,
+    play_list::{CURRENT_MUSIC_INDEX, Playlist},
+    state::Music,
+};
+
+fn playlist(bvids: &[&str]) -> Playlist {
+    Playlist {
+        musics: bvids
+            .iter()
+            .map(|bvid| Music {
+                bvid: bvid.to_string(),
+                ..Default::default()
+            })
+            .collect(),
+    }
+}
+
+async fn current_index() -> usize {
+    *CURRENT_MUSIC_INDEX.lock().await
+}
+
+async fn set_index(index: usize) {
+    *CURRENT_MUSIC_INDEX.lock().await = index;
+}
+
+// 当前索引是全局变量，所有场景放在同一个测试中顺序执行
+#[tokio::test]
+async fn test_remove_music_adjusts_current_index() {
+    // 删除当前歌曲之前的歌曲，索引前移
+    let mut list = playlist(&["a", "b", "c"]);
+    set_index(2).await;
+    let (music, is_current) = list.remove_music("a", "").await.unwrap();
+    assert_eq!(music.bvid, "a");
+    assert!(!is_current);
+    assert_eq!(current_index().await, 1);
+
+    // 删除当前歌曲之后的歌曲，索引不变
+    let mut list = playlist(&["a", "b", "c"]);
+    set_index(0).await;
+    assert!(!list.remove_music("c", "").await.unwrap().1);
+    assert_eq!(current_index().await, 0);
+
+    // 删除正在播放的歌曲，索引指向下一首
+    let mut list = playlist(&["a", "b", "c"]);
+    set_index(1).await;
+    assert!(list.remove_music("b", "").await.unwrap().1);
+    assert_eq!(current_index().await, 1);
+    assert_eq!(list.musics[1].bvid, "c");
+
+    // 删除正在播放的最后一首，回到开头
+    let mut list = playlist(&["a", "b", "c"]);
+    set_index(2).await;
+    assert!(list.remove_music("c", "").await.unwrap().1);
+    assert_eq!(current_index().await, 0);
+
+    // 不存在的歌曲
+    assert!(list.remove_music("x", "").await.is_none());
+    assert_eq!(list.musics.len(), 2);
+}
+
+#[test]
+fn test_page() {
+    let list = playlist(&["a", "b", "c", "d", "e"]);
+
+    let page = list.page(1, 2, 2);
+    assert_eq!(page.total, 5);
+    assert_eq!(page.total_pages, 3);
+    assert_eq!(page.entries.len(), 2);
+    assert_eq!(page.entries[0].index, 0);
+    assert!(page.entries.iter().all(|e| !e.is_current));
+
+    // 页码为 0 时显示当前歌曲所在的页
+    let page = list.page(0, 2, 2);
+    assert_eq!(page.page, 2);
+    assert!(page.entries[0].is_current);
+    assert_eq!(page.entries[0].music.bvid, "c");
+
+    // 超出范围时返回最后一页
+    let page = list.page(9, 2, 0);
+    assert_eq!(page.page, 3);
+    assert_eq!(page.entries.len(), 1);
+
+    // 默认每页数量
+    let page = list.page(1, 0, 0);
+    assert_eq!(page.page_size, 10);
+    assert_eq!(page.total_pages, 1);
+
+    // 空播放列表
+    let page = playlist(&[]).page(0, 0, 0);
+    assert_eq!(page.page, 1);
+    assert!(page.entries.is_empty());
+}
+
+#[test]
+fn test_next_index() {
+    let list = playlist(&["a", "b", "c"]);
+    assert_eq!(list.next_index(0, PlayMode::Normal).unwrap(), 1);
+    // 最后一首之后回到开头
+    assert_eq!(list.next_index(2, PlayMode::Normal).unwrap(), 0);
+    assert_eq!(list.next_index(1, PlayMode::Repeat).unwrap(), 1);
+    assert!(list.next_index(0, PlayMode::Shuffle).unwrap() < 3);
+    assert!(playlist(&[]).next_index(0, PlayMode::Normal).is_err());
+}
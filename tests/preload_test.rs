@@ -0,0 +1,62 @@
+use bili_player::player::{
+    command::{PlayMode, PlayerCommand},
+    play_list::{
+        Playlist, find_music_in_playlist, peek_next_music, remove_music_from_playlist,
+        replace_playlist, set_current_music_index,
+    },
+    state::Music,
+};
+use tokio::sync::oneshot;
+
+fn music(bvid: &str) -> Music {
+    Music {
+        bvid: bvid.to_string(),
+        cid: "100".to_string(),
+        ..Default::default()
+    }
+}
+
+// 播放列表是全局变量，所有场景放在同一个测试中顺序执行
+#[tokio::test]
+async fn test_preloaded_music_after_playlist_changes() {
+    replace_playlist(Playlist {
+        musics: vec![music("a"), music("b"), music("c")],
+    })
+    .await
+    .unwrap();
+    set_current_music_index(0).await.unwrap();
+
+    // 预加载下一首时它还在播放列表中
+    let preloaded = peek_next_music(PlayMode::Normal).await.unwrap();
+    assert_eq!(preloaded.bvid, "b");
+    assert_eq!(find_music_in_playlist(&preloaded).await, Some(1));
+
+    // 移出播放列表后预加载的歌曲失效，下一首变成 c
+    remove_music_from_playlist("b", "100")
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(find_music_in_playlist(&preloaded).await, None);
+    assert_eq!(peek_next_music(PlayMode::Normal).await.unwrap().bvid, "c");
+
+    // 换了整个播放列表后不在其中的歌曲也会失效
+    let preloaded = peek_next_music(PlayMode::Normal).await.unwrap();
+    replace_playlist(Playlist {
+        musics: vec![music("a"), music("d")],
+    })
+    .await
+    .unwrap();
+    assert_eq!(find_music_in_playlist(&preloaded).await, None);
+    assert_eq!(peek_next_music(PlayMode::Normal).await.unwrap().bvid, "d");
+}
+
+#[test]
+fn test_commands_changing_playlist() {
+    let (sender, _receiver) = oneshot::channel();
+    assert!(PlayerCommand::Delete(Default::default(), sender).changes_playlist());
+    let (sender, _receiver) = oneshot::channel();
+    assert!(PlayerCommand::RemoveFromPlaylist(Default::default(), sender).changes_playlist());
+    let (sender, _receiver) = oneshot::channel();
+    assert!(!PlayerCommand::GetState(sender).changes_playlist());
+    assert!(!PlayerCommand::Next.changes_playlist());
+}
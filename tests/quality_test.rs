@@ -0,0 +1,260 @@
+mod common;
+
+use bili_player::fetch::{
+    network::{fetch_audio_stream, fetch_audio_streams},
+    quality::{AudioKind, AudioPreference, AudioQuality, AudioStream, url_deadline},
+    verify::fetch_and_verify_audio_url,
+};
+use common::ok;
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+fn stream(id: i64, kind: AudioKind, bandwidth: u64) -> AudioStream {
+    AudioStream {
+        quality: AudioQuality {
+            id,
+            kind,
+            bandwidth,
+            codecs: "mp4a.40.2".into(),
+        },
+        base_url: format!("https://example.com/{id}.m4s"),
+        backup_urls: Vec::new(),
+    }
+}
+
+fn streams() -> Vec<AudioStream> {
+    vec![
+        stream(30232, AudioKind::Normal, 132_000),
+        stream(30280, AudioKind::Normal, 319_000),
+        stream(30216, AudioKind::Normal, 67_000),
+        stream(30250, AudioKind::Dolby, 448_000),
+        stream(30251, AudioKind::Flac, 1_500_000),
+    ]
+}
+
+fn selected(preference: AudioPreference, streams: &[AudioStream]) -> i64 {
+    preference.select(streams).unwrap().quality.id
+}
+
+#[test]
+fn test_preference_parse() {
+    let parse = |s: &str| s.parse::<AudioPreference>().unwrap();
+    assert_eq!(parse(""), AudioPreference::Highest);
+    assert_eq!(parse("Highest"), AudioPreference::Highest);
+    assert_eq!(parse("flac"), AudioPreference::PreferFlac);
+    assert_eq!(parse("dolby"), AudioPreference::PreferDolby);
+    assert_eq!(parse("132k"), AudioPreference::Cap(132));
+    assert_eq!(parse("cap:192K"), AudioPreference::Cap(192));
+    assert_eq!(parse("64"), AudioPreference::Cap(64));
+    assert!("0k".parse::<AudioPreference>().is_err());
+    assert!("best-effort".parse::<AudioPreference>().is_err());
+    assert_eq!(AudioPreference::Cap(132).to_string(), "132k");
+}
+
+#[test]
+fn test_preference_select() {
+    let streams = streams();
+    assert_eq!(selected(AudioPreference::Highest, &streams), 30251);
+    assert_eq!(selected(AudioPreference::PreferFlac, &streams), 30251);
+    assert_eq!(selected(AudioPreference::PreferDolby, &streams), 30250);
+    // 码率上限按标称码率比较，不受实际码率的浮动影响
+    assert_eq!(selected(AudioPreference::Cap(132), &streams), 30232);
+    assert_eq!(selected(AudioPreference::Cap(500), &streams), 30280);
+    // 都超过上限时使用最低的
+    assert_eq!(selected(AudioPreference::Cap(32), &streams), 30216);
+
+    // 没有无损和杜比时使用最高的普通音质
+    let normal = &streams[..3];
+    assert_eq!(selected(AudioPreference::PreferFlac, normal), 30280);
+    assert_eq!(selected(AudioPreference::PreferDolby, normal), 30280);
+    assert!(AudioPreference::Highest.select(&[]).is_none());
+}
+
+#[test]
+fn test_quality_label() {
+    let streams = streams();
+    let labels: Vec<_> = streams.iter().map(|s| s.quality.label()).collect();
+    assert_eq!(labels, ["132K", "192K", "64K", "杜比全景声", "Hi-Res 无损"]);
+    assert_eq!(stream(1, AudioKind::Normal, 96_000).quality.label(), "96K");
+}
+
+#[tokio::test]
+async fn test_fetch_audio_streams() {
+    let mut server = Server::new_async().await;
+    let audio = |id: i64, bandwidth: u64, codecs: &str| {
+        let url = format!("https://upos.example.com/{id}.m4s");
+        json!({
+            "id": id,
+            "baseUrl": url,
+            "base_url": url,
+            "backupUrl": [format!("https://backup.example.com/{id}.m4s")],
+            "backup_url": [format!("https://backup.example.com/{id}.m4s")],
+            "bandwidth": bandwidth,
+            "codecs": codecs,
+        })
+    };
+    let data = json!({
+        "dash": {
+            "audio": [audio(30280, 319_000, "mp4a.40.2"), audio(30216, 67_000, "mp4a.40.2")],
+            "dolby": { "type": 1, "audio": [audio(30250, 448_000, "ec-3")] },
+            "flac": { "display": true, "audio": audio(30251, 1_500_000, "fLaC") },
+        }
+    });
+    server
+        .mock("GET", "/x/player/playurl")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("bvid".into(), "BVaudio01".into()),
+            Matcher::UrlEncoded("cid".into(), "123".into()),
+        ]))
+        .with_body(ok(data))
+        .expect(2)
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let streams = fetch_audio_streams(&client, &server.url(), "BVaudio01", "123")
+        .await
+        .unwrap();
+    let kinds: Vec<_> = streams.iter().map(|s| s.quality.kind).collect();
+    assert_eq!(
+        kinds,
+        [
+            AudioKind::Normal,
+            AudioKind::Normal,
+            AudioKind::Dolby,
+            AudioKind::Flac
+        ]
+    );
+    assert_eq!(streams[2].quality.codecs, "ec-3");
+    assert_eq!(
+        streams[0].backup_urls,
+        ["https://backup.example.com/30280.m4s"]
+    );
+
+    let stream = fetch_audio_stream(
+        &client,
+        &server.url(),
+        "BVaudio01",
+        "123",
+        AudioPreference::Cap(192),
+    )
+    .await
+    .unwrap();
+    assert_eq!(stream.quality.id, 30280);
+    assert_eq!(stream.base_url, "https://upos.example.com/30280.m4s");
+}
+
+#[tokio::test]
+async fn test_fetch_audio_streams_without_dolby_and_flac() {
+    let mut server = Server::new_async().await;
+    let data = json!({
+        "dash": {
+            "audio": [{ "id": 30232, "baseUrl": "https://upos.example.com/a.m4s", "bandwidth": 132_000, "codecs": "mp4a.40.2" }],
+            "dolby": { "type": 0, "audio": null },
+            "flac": null,
+        }
+    });
+    server
+        .mock("GET", "/x/player/playurl")
+        .match_query(Matcher::Any)
+        .with_body(ok(data))
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let streams = fetch_audio_streams(&client, &server.url(), "BVaudio02", "1")
+        .await
+        .unwrap();
+    assert_eq!(streams.len(), 1);
+    assert!(streams[0].backup_urls.is_empty());
+}
+
+#[test]
+fn test_stream_failover() {
+    let mut stream = stream(30280, AudioKind::Normal, 319_000);
+    stream.backup_urls = vec![
+        "https://b1.example.com".into(),
+        "https://b2.example.com".into(),
+    ];
+    assert_eq!(stream.urls().count(), 3);
+    assert_eq!(stream.failover(), Some("https://b1.example.com"));
+    assert_eq!(stream.backup_urls, ["https://b2.example.com"]);
+    assert_eq!(stream.failover(), Some("https://b2.example.com"));
+    assert_eq!(stream.failover(), None);
+    assert_eq!(stream.base_url, "https://b2.example.com");
+}
+
+#[tokio::test]
+async fn test_verify_tries_backup_urls() {
+    let mut server = Server::new_async().await;
+    let url = server.url();
+    let data = json!({
+        "dash": {
+            "audio": [{
+                "id": 30280,
+                "baseUrl": format!("{url}/primary.m4s"),
+                "backupUrl": [
+                    format!("{url}/backup1.m4s"),
+                    format!("{url}/backup2.m4s"),
+                    format!("{url}/backup3.m4s"),
+                ],
+                "bandwidth": 319_000,
+                "codecs": "mp4a.40.2",
+            }],
+        }
+    });
+    // 接口只请求一次，主地址不可用时依次验证备用地址
+    let api = server
+        .mock("GET", "/x/player/playurl")
+        .match_query(Matcher::Any)
+        .with_body(ok(data))
+        .expect(1)
+        .create_async()
+        .await;
+    let primary = server
+        .mock("GET", "/primary.m4s")
+        .with_status(404)
+        .expect(1)
+        .create_async()
+        .await;
+    let backup1 = server
+        .mock("GET", "/backup1.m4s")
+        .with_status(403)
+        .expect(1)
+        .create_async()
+        .await;
+    let backup2 = server
+        .mock("GET", "/backup2.m4s")
+        .match_header("range", "bytes=0-1024")
+        .with_status(206)
+        .expect(1)
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let stream =
+        fetch_and_verify_audio_url(&client, &url, "BVaudio03", "1", AudioPreference::Highest)
+            .await
+            .unwrap();
+    api.assert_async().await;
+    primary.assert_async().await;
+    backup1.assert_async().await;
+    backup2.assert_async().await;
+    // 可用的地址作为主地址，剩下未验证的备用地址保留给播放中切换
+    assert_eq!(stream.base_url, format!("{url}/backup2.m4s"));
+    assert_eq!(stream.backup_urls, [format!("{url}/backup3.m4s")]);
+}
+
+#[test]
+fn test_url_deadline() {
+    let url =
+        "https://upos-sz-mirror.bilivideo.com/a.m4s?e=ig8euxZM&deadline=1760000000&gen=playurlv2";
+    assert_eq!(url_deadline(url), Some(1_760_000_000));
+    assert_eq!(url_deadline("https://example.com/a.m4s?e=1"), None);
+    assert_eq!(url_deadline("not a url"), None);
+
+    let mut stream = stream(30280, AudioKind::Normal, 319_000);
+    assert!(!stream.expires_within(u64::MAX, 60));
+    stream.base_url = url.into();
+    assert_eq!(stream.deadline(), Some(1_760_000_000));
+    assert!(!stream.expires_within(1_759_999_000, 60));
+    assert!(stream.expires_within(1_759_999_950, 60));
+    assert!(stream.expires_within(1_760_000_001, 0));
+}
@@ -0,0 +1,46 @@
+use bili_player::player::rate::{PlaybackRate, RateSettings};
+
+#[test]
+fn test_rate_range() {
+    let rate = PlaybackRate::default();
+    assert!(rate.settings().is_normal());
+    for value in [0.5, 0.75, 1.0, 2.0] {
+        let settings = RateSettings {
+            rate: value,
+            ..Default::default()
+        };
+        assert_eq!(rate.set(settings).unwrap().rate, value);
+    }
+    for value in [0.0, 0.49, 2.01, -1.0, f64::NAN] {
+        let settings = RateSettings {
+            rate: value,
+            ..Default::default()
+        };
+        assert!(rate.set(settings).is_err());
+    }
+    // 设置失败时保持之前的速度
+    assert_eq!(rate.settings().rate, 2.0);
+}
+
+#[test]
+fn test_rate_track_changed() {
+    let rate = PlaybackRate::default();
+    rate.set(RateSettings {
+        rate: 0.75,
+        preserve_pitch: true,
+        keep: false,
+    })
+    .unwrap();
+    // 没有选择保持时切换歌曲恢复为正常速度，保持音调的设置不变
+    let settings = rate.track_changed();
+    assert!(settings.is_normal());
+    assert!(settings.preserve_pitch);
+
+    rate.set(RateSettings {
+        rate: 1.5,
+        preserve_pitch: false,
+        keep: true,
+    })
+    .unwrap();
+    assert_eq!(rate.track_changed().rate, 1.5);
+}
@@ -0,0 +1,88 @@
+mod common;
+
+use bili_player::{
+    errors::ApplicationError,
+    fetch::network::{clean_title, search_videos},
+};
+use common::{mock_wbi_keys, ok};
+use mockito::{Matcher, Server};
+use serde_json::json;
+
+#[test]
+fn test_clean_title() {
+    assert_eq!(
+        clean_title("<em class=\"keyword\">晴天</em> 钢琴版"),
+        "晴天 钢琴版"
+    );
+    assert_eq!(clean_title("A &amp; B &quot;live&quot;"), "A & B \"live\"");
+    assert_eq!(clean_title("&amp;lt;"), "&lt;");
+    assert_eq!(clean_title("没有标签"), "没有标签");
+}
+
+#[tokio::test]
+async fn test_search_videos() {
+    let mut server = Server::new_async().await;
+    let data = json!({
+        "page": 2,
+        "pagesize": 20,
+        "numResults": 1000,
+        "numPages": 50,
+        "result": [
+            {
+                "type": "video",
+                "bvid": "BVsearch01",
+                "title": "<em class=\"keyword\">晴天</em> cover",
+                "author": "歌手",
+                "duration": "4:29",
+                "play": 12345,
+            },
+            {
+                "type": "video",
+                "bvid": "BVsearch02",
+                "title": "晴天 live",
+                "author": "乐队",
+                "duration": "61:02",
+                "play": "--",
+            },
+        ],
+    });
+    // 搜索接口需要 WBI 签名
+    mock_wbi_keys(&mut server).await;
+    let mock = server
+        .mock("GET", "/x/web-interface/wbi/search/type")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("search_type".into(), "video".into()),
+            Matcher::UrlEncoded("keyword".into(), "晴天".into()),
+            Matcher::UrlEncoded("page".into(), "2".into()),
+            Matcher::Regex("wts=[0-9]+".into()),
+            Matcher::Regex("w_rid=[0-9a-f]{32}".into()),
+        ]))
+        .match_header("referer", "https://www.bilibili.com")
+        .with_body(ok(data))
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let page = search_videos(&client, &server.url(), " 晴天 ", 2)
+        .await
+        .unwrap();
+    mock.assert_async().await;
+    assert_eq!(page.page, 2);
+    assert_eq!(page.num_pages, 50);
+    assert_eq!(page.num_results, 1000);
+    assert_eq!(page.results.len(), 2);
+    assert_eq!(page.results[0].title, "晴天 cover");
+    assert_eq!(page.results[0].duration, 269);
+    assert_eq!(page.results[0].play, 12345);
+    assert_eq!(page.results[1].duration, 3662);
+    assert_eq!(page.results[1].play, 0);
+}
+
+#[tokio::test]
+async fn test_search_empty_keyword() {
+    let client = reqwest::Client::new();
+    let result = search_videos(&client, "http://127.0.0.1:1", "  ", 1).await;
+    assert!(matches!(
+        result,
+        Err(ApplicationError::InvalidArgumentError(_))
+    ));
+}
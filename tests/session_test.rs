@@ -0,0 +1,101 @@
+mod common;
+
+use bili_player::{
+    library::session_repository::{SessionRecord, SessionRepository},
+    player::{
+        command::PlayMode,
+        equalizer::EqualizerSettings,
+        session::{PlaybackSession, ResumeMode},
+        state::Music,
+    },
+};
+use common::memory_pool;
+
+async fn memory_sessions() -> SessionRepository {
+    SessionRepository::new(memory_pool().await)
+}
+
+fn music(bvid: &str) -> Music {
+    Music {
+        bvid: bvid.into(),
+        cid: "100".into(),
+        title: bvid.into(),
+        owner: "tester".into(),
+    }
+}
+
+#[tokio::test]
+async fn test_save_and_load() {
+    let sessions = memory_sessions().await;
+    assert!(sessions.load().await.unwrap().is_none());
+
+    let session = PlaybackSession {
+        play_mode: PlayMode::Shuffle,
+        volume: 80,
+        muted: true,
+        crossfade: 6,
+        equalizer: EqualizerSettings {
+            gains: [6.0, 4.5, 2.0, 0.0, 0.0, 0.0, -1.0, -2.0, -3.5, -4.0],
+            preset: Some("custom".into()),
+        },
+        current_index: 2,
+        current_bvid: Some("BVtest0003".into()),
+        current_cid: Some("100".into()),
+        position: Some(83.5),
+    };
+    sessions.save(&SessionRecord::from(&session)).await.unwrap();
+    let loaded = PlaybackSession::from(sessions.load().await.unwrap().unwrap());
+    assert_eq!(loaded, session);
+
+    // 再次保存时覆盖之前的记录
+    let session = PlaybackSession::default();
+    sessions.save(&SessionRecord::from(&session)).await.unwrap();
+    let loaded = PlaybackSession::from(sessions.load().await.unwrap().unwrap());
+    assert_eq!(loaded, session);
+}
+
+#[test]
+fn test_resolve() {
+    let musics = [
+        music("BVtest0001"),
+        music("BVtest0002"),
+        music("BVtest0003"),
+    ];
+    let session = PlaybackSession {
+        current_index: 0,
+        current_bvid: Some("BVtest0003".into()),
+        position: Some(30.0),
+        ..Default::default()
+    };
+    // 按 bvid 定位，恢复播放位置
+    assert_eq!(session.resolve(&musics), (2, Some(30.0)));
+    // 多P视频按 cid 定位到同一P
+    let session = PlaybackSession {
+        current_cid: Some("200".into()),
+        ..session
+    };
+    assert_eq!(session.resolve(&musics), (0, None));
+    let session = PlaybackSession {
+        current_cid: Some("100".into()),
+        ..session
+    };
+    assert_eq!(session.resolve(&musics), (2, Some(30.0)));
+    // 歌曲已不在列表中时使用保存的索引，从头播放
+    assert_eq!(session.resolve(&musics[..2]), (0, None));
+    let session = PlaybackSession {
+        current_index: 5,
+        ..session
+    };
+    assert_eq!(session.resolve(&musics[..2]), (0, None));
+    assert_eq!(session.resolve(&[]), (0, None));
+}
+
+#[test]
+fn test_resume_mode() {
+    assert_eq!("paused".parse::<ResumeMode>().unwrap(), ResumeMode::Paused);
+    assert_eq!(
+        " Playing ".parse::<ResumeMode>().unwrap(),
+        ResumeMode::Playing
+    );
+    assert!("later".parse::<ResumeMode>().is_err());
+}
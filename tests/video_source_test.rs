@@ -0,0 +1,220 @@
+mod common;
+
+use bili_player::{
+    fetch::network::{
+        VideoFilter, VideoSummary, fetch_season_videos, fetch_uploader_videos, parse_length,
+    },
+    library::{
+        import::{VideoSource, import_videos},
+        repository::MusicRepository,
+    },
+};
+use common::{memory_pool, mock_wbi_keys, ok};
+use mockito::{Matcher, Server, ServerGuard};
+use serde_json::{Value, json};
+
+const MID: i64 = 42;
+const SEASON_ID: i64 = 7;
+
+async fn memory_repository() -> MusicRepository {
+    MusicRepository::new(memory_pool().await)
+}
+
+fn video(title: &str, duration: u64) -> VideoSummary {
+    VideoSummary {
+        bvid: "BVtest".into(),
+        title: title.into(),
+        author: String::new(),
+        duration,
+    }
+}
+
+// 模拟合集接口，total 决定是否还有下一页
+async fn mock_season_page(server: &mut ServerGuard, page_num: u32, archives: Value, total: u32) {
+    let data = json!({
+        "aids": [],
+        "archives": archives,
+        "meta": { "season_id": SEASON_ID, "name": "翻唱合集", "total": total },
+        "page": { "page_num": page_num, "page_size": 2, "total": total },
+    });
+    server
+        .mock("GET", "/x/polymer/web-space/seasons_archives_list")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("season_id".into(), SEASON_ID.to_string()),
+            Matcher::UrlEncoded("page_num".into(), page_num.to_string()),
+        ]))
+        .with_body(ok(data))
+        .create_async()
+        .await;
+}
+
+async fn mock_video(server: &mut ServerGuard, bvid: &str, title: &str, cid: i64) {
+    let data = json!({
+        "bvid": bvid,
+        "title": title,
+        "cid": cid,
+        "owner": { "name": "up主" },
+        "pages": [{ "cid": cid, "page": 1, "part": title, "duration": 200 }],
+    });
+    server
+        .mock("GET", "/x/web-interface/wbi/view")
+        .match_query(Matcher::UrlEncoded("bvid".into(), bvid.into()))
+        .with_body(ok(data))
+        .create_async()
+        .await;
+}
+
+#[test]
+fn test_parse_length() {
+    assert_eq!(parse_length("3:45"), 225);
+    assert_eq!(parse_length("1:02:03"), 3723);
+    assert_eq!(parse_length("59"), 59);
+    assert_eq!(parse_length(""), 0);
+    assert_eq!(parse_length("abc"), 0);
+}
+
+#[test]
+fn test_video_filter() {
+    assert!(VideoFilter::default().matches(&video("任何视频", 10)));
+    let filter = VideoFilter {
+        keyword: Some("Cover".into()),
+        min_duration: Some(60),
+        max_duration: Some(600),
+    };
+    assert!(filter.matches(&video("【翻唱】cover 晴天", 240)));
+    assert!(!filter.matches(&video("日常 vlog", 240)));
+    assert!(!filter.matches(&video("cover 片段", 30)));
+    assert!(!filter.matches(&video("cover 直播回放", 7200)));
+    // 空关键字不过滤
+    let filter = VideoFilter {
+        keyword: Some("  ".into()),
+        ..Default::default()
+    };
+    assert!(filter.matches(&video("日常 vlog", 240)));
+}
+
+#[tokio::test]
+async fn test_fetch_season_videos_pages_and_filter() {
+    let mut server = Server::new_async().await;
+    mock_season_page(
+        &mut server,
+        1,
+        json!([
+            { "bvid": "BVseason01", "title": "晴天 翻唱", "duration": 250 },
+            { "bvid": "BVseason02", "title": "花絮", "duration": 40 },
+        ]),
+        3,
+    )
+    .await;
+    mock_season_page(
+        &mut server,
+        2,
+        json!([{ "bvid": "BVseason03", "title": "七里香 翻唱", "duration": 280 }]),
+        3,
+    )
+    .await;
+    let client = reqwest::Client::new();
+    let filter = VideoFilter {
+        min_duration: Some(60),
+        ..Default::default()
+    };
+    let (meta, videos) = fetch_season_videos(&client, &server.url(), MID, SEASON_ID, &filter)
+        .await
+        .unwrap();
+    assert_eq!(meta.name, "翻唱合集");
+    let bvids: Vec<_> = videos.iter().map(|v| v.bvid.as_str()).collect();
+    assert_eq!(bvids, ["BVseason01", "BVseason03"]);
+}
+
+#[tokio::test]
+async fn test_fetch_uploader_videos_max_pages() {
+    let mut server = Server::new_async().await;
+    let data = json!({
+        "list": { "vlist": [
+            { "bvid": "BVup0001", "title": "新歌", "author": "歌手", "length": "04:01" },
+            { "bvid": "BVup0002", "title": "新歌 MV", "author": "歌手", "length": "1:04:01" },
+        ] },
+        "page": { "pn": 1, "ps": 2, "count": 10 },
+    });
+    // 投稿接口需要 WBI 签名
+    mock_wbi_keys(&mut server).await;
+    let first_page = server
+        .mock("GET", "/x/space/wbi/arc/search")
+        .match_query(Matcher::AllOf(vec![
+            Matcher::UrlEncoded("mid".into(), MID.to_string()),
+            Matcher::UrlEncoded("pn".into(), "1".into()),
+            Matcher::UrlEncoded("keyword".into(), "新歌".into()),
+            Matcher::Regex("wts=[0-9]+".into()),
+            Matcher::Regex("w_rid=[0-9a-f]{32}".into()),
+        ]))
+        .with_body(ok(data))
+        .expect(1)
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let filter = VideoFilter {
+        keyword: Some("新歌".into()),
+        max_duration: Some(600),
+        ..Default::default()
+    };
+    // 只请求一页，第二页没有模拟，请求了会失败
+    let videos = fetch_uploader_videos(&client, &server.url(), MID, &filter, 1)
+        .await
+        .unwrap();
+    first_page.assert_async().await;
+    assert_eq!(videos.len(), 1);
+    assert_eq!(videos[0].bvid, "BVup0001");
+    assert_eq!(videos[0].duration, 241);
+    assert_eq!(videos[0].author, "歌手");
+}
+
+#[tokio::test]
+async fn test_import_season_videos() {
+    let repository = memory_repository().await;
+    let mut server = Server::new_async().await;
+    mock_wbi_keys(&mut server).await;
+    mock_season_page(
+        &mut server,
+        1,
+        json!([
+            { "bvid": "BVseason01", "title": "晴天 翻唱", "duration": 250 },
+            { "bvid": "BVseason02", "title": "稻香 翻唱", "duration": 230 },
+        ]),
+        2,
+    )
+    .await;
+    mock_video(&mut server, "BVseason01", "晴天 翻唱", 501).await;
+    server
+        .mock("GET", "/x/web-interface/wbi/view")
+        .match_query(Matcher::UrlEncoded("bvid".into(), "BVseason02".into()))
+        .with_body(json!({ "code": -404, "message": "啥都木有", "data": null }).to_string())
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let source = VideoSource::Season {
+        mid: MID,
+        season_id: SEASON_ID,
+    };
+    let filter = VideoFilter::default();
+    let report = import_videos(&client, &server.url(), &repository, &source, &filter)
+        .await
+        .unwrap();
+    assert_eq!(report.source, "合集 翻唱合集");
+    assert_eq!(report.added.len(), 1);
+    assert_eq!(report.added[0].cid, "501");
+    assert_eq!(report.failed.len(), 1);
+    assert!(
+        repository
+            .find("BVseason01", "501")
+            .await
+            .unwrap()
+            .is_some()
+    );
+
+    // 再次导入时已在音乐库中的视频不重复添加
+    let report = import_videos(&client, &server.url(), &repository, &source, &filter)
+        .await
+        .unwrap();
+    assert!(report.added.is_empty());
+    assert_eq!(report.existing, 1);
+}
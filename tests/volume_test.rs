@@ -0,0 +1,34 @@
+use bili_player::player::volume::{MAX_VOLUME, Volume};
+
+#[test]
+fn test_volume_level() {
+    assert!(Volume::new(MAX_VOLUME + 1).is_err());
+    let volume = Volume::new(100).unwrap();
+    assert_eq!(volume.level(), 100);
+    assert_eq!(volume.gstreamer_volume(), 1.0);
+
+    assert!(volume.set_level(201).is_err());
+    assert_eq!(volume.level(), 100);
+    assert_eq!(volume.set_level(40).unwrap(), 40);
+
+    // 相对调整不会超出范围
+    assert_eq!(volume.adjust(-50), 0);
+    assert_eq!(volume.adjust(250), MAX_VOLUME);
+    assert_eq!(volume.adjust(-10), 190);
+}
+
+#[test]
+fn test_volume_mute_keeps_level() {
+    let volume = Volume::new(80).unwrap();
+    volume.set_muted(true);
+    assert!(volume.is_muted());
+    assert_eq!(volume.level(), 80);
+    volume.set_muted(false);
+    assert_eq!(volume.level(), 80);
+
+    // 调整音量时取消静音
+    volume.set_muted(true);
+    volume.adjust(10);
+    assert!(!volume.is_muted());
+    assert_eq!(volume.level(), 90);
+}
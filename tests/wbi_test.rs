@@ -0,0 +1,85 @@
+use bili_player::fetch::wbi::{WbiKeys, build_url, encode_query, sign_query};
+use mockito::Server;
+use serde_json::json;
+
+// 公开文档中的示例密钥
+fn keys() -> WbiKeys {
+    WbiKeys::from_urls(
+        "https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png",
+        "https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png",
+    )
+    .unwrap()
+}
+
+#[test]
+fn test_mixin_key() {
+    let keys = keys();
+    assert_eq!(keys.img_key, "7cd084941338484aae1ad9425b84077c");
+    assert_eq!(keys.sub_key, "4932caff0ff746eab6f01bf08b70ac45");
+    assert_eq!(keys.mixin_key(), "ea1db124af3c7062474693fa704f4ff8");
+    assert!(WbiKeys::from_urls("https://i0.hdslb.com/bfs/wbi/", "x.png").is_none());
+}
+
+#[test]
+fn test_sign_query_known_vector() {
+    let params = [
+        ("foo", "114".to_string()),
+        ("bar", "514".to_string()),
+        ("zab", "1919810".to_string()),
+    ];
+    assert_eq!(
+        sign_query(&params, &keys().mixin_key(), 1702204169),
+        "bar=514&foo=114&wts=1702204169&zab=1919810&w_rid=8f6f2b5b3d485fe1886cec6a0be8c5d4"
+    );
+}
+
+#[test]
+fn test_sign_query_encodes_and_filters() {
+    // 值中的 !'()* 签名前去掉，空格和中文按 encodeURIComponent 编码
+    let params = [
+        ("mid", "42".to_string()),
+        ("keyword", "晴天 (live)!".to_string()),
+    ];
+    assert_eq!(
+        sign_query(&params, &keys().mixin_key(), 1702204169),
+        "keyword=%E6%99%B4%E5%A4%A9%20live&mid=42&wts=1702204169&w_rid=d8f15df6c6c993e867e719db4b61219e"
+    );
+    // 不签名时保持参数顺序
+    assert_eq!(
+        encode_query(&[("b", "a/b".to_string()), ("a", "~x".to_string())]),
+        "b=a%2Fb&a=~x"
+    );
+}
+
+#[tokio::test]
+async fn test_build_url_caches_keys() {
+    let mut server = Server::new_async().await;
+    let nav = server
+        .mock("GET", "/x/web-interface/nav")
+        .with_body(
+            json!({ "code": 0, "message": "0", "data": { "isLogin": true, "wbi_img": {
+                "img_url": "https://i0.hdslb.com/bfs/wbi/7cd084941338484aae1ad9425b84077c.png",
+                "sub_url": "https://i0.hdslb.com/bfs/wbi/4932caff0ff746eab6f01bf08b70ac45.png",
+            } } })
+            .to_string(),
+        )
+        .expect(1)
+        .create_async()
+        .await;
+    let client = reqwest::Client::new();
+    let base = server.url();
+    let params = [("mid", "42".to_string())];
+    for _ in 0..2 {
+        let url = build_url(&client, &base, "/x/space/wbi/arc/search", &params)
+            .await
+            .unwrap();
+        assert!(url.starts_with(&format!("{base}/x/space/wbi/arc/search?mid=42&wts=")));
+        assert!(url.contains("&w_rid="));
+    }
+    // 不需要签名的接口不请求密钥
+    let url = build_url(&client, &base, "/x/player/playurl", &params)
+        .await
+        .unwrap();
+    assert_eq!(url, format!("{base}/x/player/playurl?mid=42"));
+    nav.assert_async().await;
+}